@@ -168,7 +168,7 @@ class BountyAgent:
                             solution_hash = self.contract.hash_solution(solution).hex()
                             
                             print(f"\nAttesting solution (ID: {solution_id})...")
-                            attestation = asyncio.run(self.contract.attest_solution(solution_id, solution))
+                            attestation = asyncio.run(self.contract.attest_solution(solution_id, selected.get('id') or 0, solution))
                             print(f"Attestation prepared: {attestation['solution_hash'][:16]}...")
                             
                             bounty_id = selected.get('id')
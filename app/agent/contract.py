@@ -1,9 +1,11 @@
+import asyncio
 import hashlib
 from typing import Optional, Dict, List
 from pathlib import Path
 from solders.pubkey import Pubkey
 from solders.keypair import Keypair as SoldersKeypair
 from anchorpy import Program, Provider, Wallet, Idl
+from Crypto.Hash import keccak
 from solana.rpc.async_api import AsyncClient
 from solana.rpc.commitment import Confirmed
 import os
@@ -23,10 +25,11 @@ class BountyForgeClient:
         import random
         return random.randint(1, 2**63 - 1)
     
-    def prepare_attestation(self, solution_id: int, solution: str) -> Dict:
+    def prepare_attestation(self, solution_id: int, bounty_id: int, solution: str) -> Dict:
         solution_hash = self.hash_solution(solution)
         return {
             "solution_id": solution_id,
+            "bounty_id": bounty_id,
             "solution_hash": solution_hash.hex(),
             "solution": solution
         }
@@ -54,6 +57,24 @@ class BountyForgeClient:
         seeds = [b"rep", bytes(agent_pubkey)]
         return Pubkey.find_program_address_sync(seeds, program_id)
     
+    def _derive_config_pda(self, program_id: Pubkey) -> tuple[Pubkey, int]:
+        return Pubkey.find_program_address_sync([b"config"], program_id)
+    
+    def _derive_submission_pda(self, bounty_id: int, agent_pubkey: Pubkey, program_id: Pubkey) -> tuple[Pubkey, int]:
+        bounty_id_buffer = bounty_id.to_bytes(8, 'little')
+        seeds = [b"submission", bounty_id_buffer, bytes(agent_pubkey)]
+        return Pubkey.find_program_address_sync(seeds, program_id)
+    
+    def _derive_commitment_pda(self, bounty_id: int, agent_pubkey: Pubkey, program_id: Pubkey) -> tuple[Pubkey, int]:
+        bounty_id_buffer = bounty_id.to_bytes(8, 'little')
+        seeds = [b"commitment", bounty_id_buffer, bytes(agent_pubkey)]
+        return Pubkey.find_program_address_sync(seeds, program_id)
+    
+    def _derive_stake_pda(self, bounty_id: int, agent_pubkey: Pubkey, program_id: Pubkey) -> tuple[Pubkey, int]:
+        bounty_id_buffer = bounty_id.to_bytes(8, 'little')
+        seeds = [b"stake", bounty_id_buffer, bytes(agent_pubkey)]
+        return Pubkey.find_program_address_sync(seeds, program_id)
+    
     async def _get_program_and_provider(self):
         rpc_url = os.getenv("SOLANA_RPC_URL", "https://api.devnet.solana.com")
         client = AsyncClient(rpc_url)
@@ -106,10 +127,10 @@ class BountyForgeClient:
         
         return program, provider, client
     
-    async def attest_solution(self, solution_id: int, solution: str) -> Dict:
+    async def attest_solution(self, solution_id: int, bounty_id: int, solution: str) -> Dict:
         if not self.wallet_keypair:
             print("Wallet keypair not set, using mock attestation")
-            return self.prepare_attestation(solution_id, solution)
+            return self.prepare_attestation(solution_id, bounty_id, solution)
         
         try:
             solution_hash = self.hash_solution(solution)
@@ -125,9 +146,11 @@ class BountyForgeClient:
             attest_method = getattr(program.methods, "attest_solution")
             tx = await attest_method(
                 solution_id,
+                bounty_id,
                 list(solution_hash)
             ).accounts({
                 "agent": agent_pubkey,
+                "config": self._derive_config_pda(program_id)[0],
                 "attestation": attestation_pda,
                 "system_program": Pubkey.from_string("11111111111111111111111111111111")
             }).rpc(commitment="confirmed")
@@ -136,6 +159,7 @@ class BountyForgeClient:
             
             return {
                 "solution_id": solution_id,
+                "bounty_id": bounty_id,
                 "solution_hash": solution_hash.hex(),
                 "transaction": str(tx),
                 "attestation_pda": str(attestation_pda)
@@ -143,7 +167,7 @@ class BountyForgeClient:
         except Exception as e:
             print(f"Error attesting solution on-chain: {e}")
             print("   Falling back to mock attestation")
-            return self.prepare_attestation(solution_id, solution)
+            return self.prepare_attestation(solution_id, bounty_id, solution)
     
     async def submit_solution(self, bounty_id: int, solution_id: int, solution: str) -> Dict:
         if not self.wallet_keypair:
@@ -166,17 +190,47 @@ class BountyForgeClient:
             print(f"   Attestation PDA: {attestation_pda}")
             print(f"   Reputation PDA: {reputation_pda}")
             
+            config_pda, _ = self._derive_config_pda(program_id)
+            commitment_pda, _ = self._derive_commitment_pda(bounty_id, agent_pubkey, program_id)
+            system_program = Pubkey.from_string("11111111111111111111111111111111")
+            
+            # Commit to keccak(solution_hash || agent || nonce) first so the hash
+            # can't be front-run once it is revealed
+            nonce = os.urandom(32)
+            commitment = keccak.new(digest_bits=256, data=solution_hash + bytes(agent_pubkey) + nonce).digest()
+            
+            commit_method = getattr(program.methods, "commit_solution")
+            await commit_method(
+                list(commitment)
+            ).accounts({
+                "agent": agent_pubkey,
+                "config": config_pda,
+                "bounty": bounty_pda,
+                "commitment": commitment_pda,
+                "system_program": system_program
+            }).rpc(commitment="confirmed")
+            
+            config = await program.account["Config"].fetch(config_pda)
+            if config.reveal_delay > 0:
+                await asyncio.sleep(config.reveal_delay)
+            
             accounts = {
                 "agent": agent_pubkey,
+                "config": config_pda,
                 "bounty": bounty_pda,
                 "attestation": attestation_pda,
+                "commitment": commitment_pda,
                 "reputation": reputation_pda,
-                "system_program": Pubkey.from_string("11111111111111111111111111111111")
+                "submission": self._derive_submission_pda(bounty_id, agent_pubkey, program_id)[0],
+                "stake": self._derive_stake_pda(bounty_id, agent_pubkey, program_id)[0],
+                "system_program": system_program
             }
             
-            submit_method = getattr(program.methods, "submit_solution")
-            tx = await submit_method(
-                list(solution_hash)
+            reveal_method = getattr(program.methods, "reveal_solution")
+            tx = await reveal_method(
+                list(solution_hash),
+                list(nonce),
+                []
             ).accounts(accounts).rpc(commitment="confirmed")
             
             print(f"Solution submitted on-chain! Transaction: {tx}")
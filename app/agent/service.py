@@ -118,7 +118,7 @@ class AgentService:
                             solution_hash = agent.contract.hash_solution(solution).hex()
                             
                             self.write_log("info", f"Attesting solution (ID: {solution_id})...")
-                            attestation = asyncio.run(agent.contract.attest_solution(solution_id, solution))
+                            attestation = asyncio.run(agent.contract.attest_solution(solution_id, selected.get('id') or 0, solution))
                             self.write_log("success", f"Attestation prepared: {attestation['solution_hash'][:16]}...")
                             
                             bounty_id = selected.get('id')
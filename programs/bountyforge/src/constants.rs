@@ -1 +1,41 @@
 pub const ANCHOR_DISCRIMINATOR: usize = 8;
+pub const BPS_DENOMINATOR: u64 = 10_000;
+pub const SOL_DECIMALS: u8 = 9; // lamports per SOL as a power of ten
+
+pub const MAX_TITLE_LEN: usize = 64;
+pub const MAX_DESCRIPTION_URI_LEN: usize = 200;
+pub const MAX_TAGS: usize = 8;
+pub const MAX_HANDLE_LEN: usize = 32;
+pub const MAX_METADATA_URI_LEN: usize = 200;
+pub const MAX_MILESTONES: usize = 8;
+pub const MAX_HISTORY_ENTRIES: usize = 32;
+pub const MIN_DIFFICULTY: u8 = 1;
+pub const MAX_DIFFICULTY: u8 = 5;
+
+pub const MAX_BATCH_SETTLE: usize = 5;
+pub const MAX_BATCH_VERIFY: usize = 10;
+pub const MAX_BATCH_REPUTATIONS: usize = 32; // keeps the ReputationsRead event well under the log limit
+pub const MAX_WINNERS: usize = 3;
+pub const MAX_SPLIT_CHILDREN: usize = 4;
+pub const MAX_REQUIRED_ATTESTATIONS: u8 = 5;
+pub const MAX_WITHDRAW_WHITELIST: usize = 4;
+pub const MAX_MODERATORS: usize = 5;
+pub const MAX_SOLUTION_KIND: u8 = 3; // 0 text, 1 url, 2 ipfs-cid, 3 git-commit
+pub const MAX_ENCRYPTION_SCHEME: u8 = 2; // 0 public, 1 x25519-xsalsa20-poly1305, 2 x25519-aes-256-gcm
+
+// weights blending a reputation into rank_weight, see Reputation::compute_rank_weight
+pub const RANK_SCORE_WEIGHT: u64 = 1_000;
+pub const RANK_SUCCESS_WEIGHT: u64 = 100;
+pub const RANK_EARNINGS_DIVISOR: u64 = 1_000_000; // one point per million base units earned
+
+// bumped whenever fields are appended to ReputationSnapshot
+pub const REPUTATION_SNAPSHOT_VERSION: u8 = 2;
+
+// successful bounties needed for each reputation badge, in minting order
+pub const BADGE_THRESHOLDS: [u64; 3] = [10, 50, 100];
+
+// longest stake_reputation lock, in seconds
+pub const MAX_REPUTATION_LOCK: i64 = 365 * 24 * 60 * 60;
+
+// longest schedule a bounty's payout may vest over, in seconds
+pub const MAX_VESTING_SECONDS: i64 = 4 * 365 * 24 * 60 * 60;
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::merkle;
+use crate::state::{Bounty, BountyStatus, Config, Reputation, ReputationTier};
+
+/// Every guard an agent must clear to reveal on a bounty, one flag per guard
+/// so front-ends can explain why submitting is blocked. Shared by
+/// `reveal_solution` and `can_submit` so the two cannot drift apart.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SubmitEligibility {
+    pub bounty_open: bool,
+    pub not_flagged: bool,
+    pub before_deadline: bool,
+    pub whitelisted: bool,
+    pub cooldown_elapsed: bool,
+    pub tier_met: bool,
+    pub score_met: bool,
+    pub below_submission_cap: bool,
+}
+
+impl SubmitEligibility {
+    /// Evaluates the guards for `agent` at `now`, decaying `reputation` first
+    /// as a reveal would. `reputation` is `None` for an agent without one yet.
+    pub fn evaluate(
+        bounty: &Bounty,
+        reputation: Option<&Reputation>,
+        config: &Config,
+        agent: &Pubkey,
+        proof: &[[u8; 32]],
+        now: i64,
+    ) -> Self {
+        let (tier, score, last_submission_at, active_submissions) = reputation
+            .map(|reputation| {
+                let mut reputation = reputation.clone();
+                reputation.apply_decay(now, config.reputation_half_life);
+                (
+                    reputation.tier(),
+                    reputation.score,
+                    reputation.last_submission_at,
+                    reputation.active_submissions,
+                )
+            })
+            .unwrap_or((ReputationTier::Novice, 0, 0, 0));
+
+        Self {
+            bounty_open: bounty.status == BountyStatus::Open,
+            not_flagged: !bounty.flagged,
+            before_deadline: now <= bounty.deadline,
+            whitelisted: bounty.whitelist_root == [0u8; 32]
+                || merkle::verify(proof, &bounty.whitelist_root, merkle::leaf(agent)),
+            cooldown_elapsed: now.saturating_sub(last_submission_at) >= config.submission_cooldown,
+            tier_met: tier >= bounty.min_tier,
+            score_met: score >= bounty.min_reputation_score,
+            below_submission_cap: config.max_concurrent_submissions == 0
+                || active_submissions < config.max_concurrent_submissions,
+        }
+    }
+
+    /// Fails with the error of the first guard that is not met.
+    pub fn require(&self) -> Result<()> {
+        require!(self.bounty_open, BountyForgeError::BountyNotOpen);
+        require!(self.not_flagged, BountyForgeError::BountyFlagged);
+        require!(self.before_deadline, BountyForgeError::BountyExpired);
+        require!(self.whitelisted, BountyForgeError::AgentNotWhitelisted);
+        require!(
+            self.cooldown_elapsed,
+            BountyForgeError::SubmissionCooldownActive
+        );
+        require!(self.tier_met, BountyForgeError::ReputationTierTooLow);
+        require!(self.score_met, BountyForgeError::InsufficientReputation);
+        require!(
+            self.below_submission_cap,
+            BountyForgeError::TooManyActiveSubmissions
+        );
+
+        Ok(())
+    }
+}
@@ -24,4 +24,272 @@ pub enum BountyForgeError {
     OracleVerificationFailed,
     #[msg("Oracle data is stale")]
     OracleDataStale,
+    #[msg("Bounty deadline has not passed yet")]
+    BountyNotExpired,
+    #[msg("Bounty deadline must be in the future")]
+    InvalidDeadline,
+    #[msg("Only the bounty creator can perform this action")]
+    UnauthorizedCreator,
+    #[msg("Attestation has not been verified")]
+    AttestationNotVerified,
+    #[msg("Attestation is already verified")]
+    AlreadyVerified,
+    #[msg("Signer is not the authorized verifier")]
+    UnauthorizedVerifier,
+    #[msg("Agent did not submit the solution for this bounty")]
+    AgentMismatch,
+    #[msg("Fee cannot exceed 10000 basis points")]
+    FeeTooHigh,
+    #[msg("Arithmetic overflow")]
+    MathOverflow,
+    #[msg("Bounty has not reached a final status")]
+    BountyNotFinalized,
+    #[msg("Bounty is not pending settlement")]
+    BountyNotPendingSettlement,
+    #[msg("Dispute window has not elapsed yet")]
+    SettlementLocked,
+    #[msg("Dispute window has already closed")]
+    DisputeWindowClosed,
+    #[msg("Dispute window cannot be negative")]
+    InvalidDisputeWindow,
+    #[msg("Agent reputation tier is below the bounty minimum")]
+    ReputationTierTooLow,
+    #[msg("Agent reputation score is below the bounty minimum")]
+    InsufficientReputation,
+    #[msg("Token account mint does not match the bounty reward mint")]
+    RewardMintMismatch,
+    #[msg("SPL bounties require the reward mint and token accounts")]
+    MissingTokenAccount,
+    #[msg("Treasury account is required to collect the fee")]
+    MissingTreasuryAccount,
+    #[msg("Escrow would drop below its rent-exempt minimum")]
+    EscrowBelowRentExempt,
+    #[msg("Bounty title, description URI, tags or agent profile metadata exceed the maximum length")]
+    MetadataTooLong,
+    #[msg("Attestation was made for a different bounty")]
+    AttestationBountyMismatch,
+    #[msg("Signer is not the config admin")]
+    UnauthorizedAdmin,
+    #[msg("Program is paused")]
+    ProgramPaused,
+    #[msg("Reveal delay cannot be negative")]
+    InvalidRevealDelay,
+    #[msg("Reveal delay has not elapsed since the commitment")]
+    RevealTooEarly,
+    #[msg("Revealed solution does not match the commitment")]
+    CommitmentMismatch,
+    #[msg("Submission timeout cannot be negative")]
+    InvalidSubmissionTimeout,
+    #[msg("Submission timeout has not elapsed yet")]
+    SubmissionTimeoutNotElapsed,
+    #[msg("Bounty has more milestones than allowed")]
+    TooManyMilestones,
+    #[msg("Milestone amounts must add up to the reward")]
+    MilestoneSumMismatch,
+    #[msg("Milestone index is out of range")]
+    InvalidMilestone,
+    #[msg("Milestone has already been released")]
+    MilestoneAlreadyReleased,
+    #[msg("Bounty still has unreleased milestones")]
+    MilestonesOutstanding,
+    #[msg("Bounty has already released milestones to its agent")]
+    MilestonesPartiallyReleased,
+    #[msg("Batch must hold between one and the maximum number of entries")]
+    InvalidBatchSize,
+    #[msg("Submission does not belong to the bounty and agent")]
+    SubmissionMismatch,
+    #[msg("Account must be writable")]
+    AccountNotWritable,
+    #[msg("Grace period cannot be negative")]
+    InvalidGracePeriod,
+    #[msg("Grace period after the deadline has not elapsed yet")]
+    GracePeriodNotElapsed,
+    #[msg("Signer is not the config arbiter")]
+    UnauthorizedArbiter,
+    #[msg("Bounty is not in Disputed status")]
+    BountyNotDisputed,
+    #[msg("Bounty was not cancelled or expired")]
+    BountyNotRefundable,
+    #[msg("Agent is not on the bounty whitelist")]
+    AgentNotWhitelisted,
+    #[msg("Not enough successful bounties for the next badge")]
+    BadgeThresholdNotReached,
+    #[msg("Every reputation badge has already been minted")]
+    AllBadgesMinted,
+    #[msg("Referral share cannot exceed 100%")]
+    InvalidReferralBps,
+    #[msg("Agents cannot refer themselves")]
+    SelfReferral,
+    #[msg("Referrer account does not match the agent's referrer")]
+    ReferrerMismatch,
+    #[msg("Referrer reputation account is required")]
+    MissingReferrerAccount,
+    #[msg("No referral rewards to claim")]
+    NoReferralEarnings,
+    #[msg("Difficulty must be between 1 and 5")]
+    DifficultyOutOfRange,
+    #[msg("Only the submitting agent can revise the solution")]
+    UnauthorizedRevision,
+    #[msg("Minimum reward cannot exceed the maximum reward")]
+    InvalidRewardBounds,
+    #[msg("Reward is below the configured minimum")]
+    RewardBelowMinimum,
+    #[msg("Reward is above the configured maximum")]
+    RewardAboveMaximum,
+    #[msg("Escrow holds less than the outstanding reward")]
+    InsufficientEscrow,
+    #[msg("Amount exceeds the unreleased reward")]
+    AmountExceedsReward,
+    #[msg("Bounty has contributions from other funders")]
+    BountyHasContributions,
+    #[msg("Submission cooldown cannot be negative")]
+    InvalidSubmissionCooldown,
+    #[msg("Agent must wait out the submission cooldown")]
+    SubmissionCooldownActive,
+    #[msg("Bounty deadline has passed")]
+    BountyExpired,
+    #[msg("Agent token account must be the agent's associated token account")]
+    NonCanonicalTokenAccount,
+    #[msg("Bounty has received submissions and can no longer be cancelled")]
+    BountyHasSubmissions,
+    #[msg("Only competition bounties can be split between winners")]
+    NotCompetitionBounty,
+    #[msg("Number of winners does not match the accounts passed")]
+    InvalidWinnerCount,
+    #[msg("Winner weights must be non-zero and sum to 10000 bps")]
+    InvalidWinnerWeights,
+    #[msg("Agent is listed as a winner more than once")]
+    DuplicateWinner,
+    #[msg("Solution is identical to the last rejected one")]
+    DuplicateSolution,
+    #[msg("Bounty ownership can only be transferred while Open or Submitted")]
+    OwnershipTransferLocked,
+    #[msg("Every submission of the bounty must be passed exactly once")]
+    IncompleteSubmissionSet,
+    #[msg("Minimum attestation age cannot be negative")]
+    InvalidMinAttestationAge,
+    #[msg("Attestation is too recent to submit against")]
+    AttestationTooFresh,
+    #[msg("Attestation has been revoked")]
+    AttestationRevoked,
+    #[msg("Token balances after settlement do not match the expected transfer")]
+    SettlementBalanceMismatch,
+    #[msg("Reward mint uses a token extension the program does not support")]
+    UnsupportedMint,
+    #[msg("Forfeit penalty cannot exceed the rejection penalty")]
+    InvalidForfeitPenalty,
+    #[msg("History account does not belong to the bounty")]
+    HistoryMismatch,
+    #[msg("Deadline can only be extended while Open or Submitted")]
+    DeadlineExtensionLocked,
+    #[msg("New deadline must be later than the current one")]
+    DeadlineNotExtended,
+    #[msg("Submitting agent must co-sign the deadline extension")]
+    AgentConsentRequired,
+    #[msg("Agent already has a reputation, it cannot be overwritten")]
+    ReputationAlreadyExists,
+    #[msg("Reputation snapshot version is not supported")]
+    UnsupportedSnapshotVersion,
+    #[msg("Solution hash cannot be all zeros")]
+    InvalidSolutionHash,
+    #[msg("Acceptance criteria hash cannot be all zeros")]
+    InvalidCriteriaHash,
+    #[msg("Memo program account is required while memos are enabled")]
+    MissingMemoProgram,
+    #[msg("Reward mint tokens cannot be rescued")]
+    RewardMintNotRescuable,
+    #[msg("Recipient token account mint does not match the rescued tokens")]
+    RescueMintMismatch,
+    #[msg("Rescue amount must be greater than zero")]
+    InvalidRescueAmount,
+    #[msg("Agent reputation has not been initialized")]
+    ReputationNotInitialized,
+    #[msg("Bounty has a submission awaiting a decision")]
+    ActiveSubmissionExists,
+    #[msg("Agent holds the maximum number of active submissions")]
+    TooManyActiveSubmissions,
+    #[msg("Adjustment reason hash cannot be all zeros")]
+    InvalidReasonHash,
+    #[msg("Bounty has no settled reward left to claim")]
+    NothingToClaim,
+    #[msg("Settled reward has not been claimed by the agent yet")]
+    RewardUnclaimed,
+    #[msg("Only settled or cancelled bounties can be cleaned up")]
+    BountyNotClosable,
+    #[msg("Bounty escrow still holds funds")]
+    EscrowNotEmpty,
+    #[msg("Required attestations must be between 1 and the maximum")]
+    InvalidAttestationQuorum,
+    #[msg("Not enough distinct verified attestations for this bounty")]
+    AttestationQuorumNotMet,
+    #[msg("Each attestation in the quorum must come from a different agent")]
+    DuplicateAttester,
+    #[msg("Payout address is not on the agent's withdrawal whitelist")]
+    WithdrawalAddressNotAllowed,
+    #[msg("Withdrawal whitelist is full")]
+    WithdrawWhitelistFull,
+    #[msg("Address is already on the withdrawal whitelist")]
+    WithdrawalAddressAlreadyListed,
+    #[msg("Address is not on the withdrawal whitelist")]
+    WithdrawalAddressNotListed,
+    #[msg("Bounty counter can only move forward")]
+    BountyCounterRewound,
+    #[msg("Solution kind is not a known artifact format")]
+    InvalidSolutionKind,
+    #[msg("Bounty is not in the status this transition starts from")]
+    InvalidStatusTransition,
+    #[msg("A split needs between two and the maximum number of children")]
+    InvalidSplitCount,
+    #[msg("Split shares must be non-zero and add up to the bounty reward")]
+    SplitSharesMismatch,
+    #[msg("Child bounty accounts must be at the next ids from the bounty counter")]
+    InvalidChildBounty,
+    #[msg("Rejection penalty cannot exceed the maximum rejection penalty")]
+    InvalidRejectionPenalty,
+    #[msg("Price feed is not a fully verified Pyth price update")]
+    InvalidPriceFeed,
+    #[msg("Price feed is older than the maximum price age")]
+    StalePriceFeed,
+    #[msg("Price feed confidence interval is too wide")]
+    PriceConfidenceTooWide,
+    #[msg("Maximum price age must be non-negative and confidence at most 100%")]
+    InvalidOracleSettings,
+    #[msg("Settlement would pay the agent less than the minimum payout set on the submission")]
+    PayoutBelowMinimum,
+    #[msg("Prompt settlement window must be non-negative and its bonus at most 100% of the fee")]
+    InvalidPromptSettleSettings,
+    #[msg("Reputation lock must be positive, at most a year and not end before the current one")]
+    InvalidLockDuration,
+    #[msg("Reputation is still locked")]
+    ReputationLocked,
+    #[msg("Reputation is not staked")]
+    ReputationNotStaked,
+    #[msg("Vesting period must be non-negative and at most four years")]
+    InvalidVestingPeriod,
+    #[msg("Vesting account is required when the bounty's payout vests")]
+    MissingVestingAccount,
+    #[msg("Reward vests over time and is claimed with claim_vested")]
+    RewardVesting,
+    #[msg("Creators cannot submit to or settle their own bounty")]
+    SelfSettlementForbidden,
+    #[msg("Only token bounties can change their reward mint")]
+    NotTokenBounty,
+    #[msg("New reward mint is the bounty's current one")]
+    RewardMintUnchanged,
+    #[msg("Encryption scheme is not supported")]
+    InvalidEncryptionScheme,
+    #[msg("Private bounties need a non-zero delivery pubkey")]
+    MissingDeliveryPubkey,
+    #[msg("Bounty is flagged by a moderator and takes no submissions")]
+    BountyFlagged,
+    #[msg("Bounty is not flagged")]
+    BountyNotFlagged,
+    #[msg("Only the admin or a moderator may flag bounties")]
+    UnauthorizedModerator,
+    #[msg("Too many moderators")]
+    TooManyModerators,
+    #[msg("Vesting rewards cannot be paid in milestones or split between winners")]
+    VestingNotSupported,
+    #[msg("Agents still hold open submissions on this bounty")]
+    SubmissionsOutstanding,
 }
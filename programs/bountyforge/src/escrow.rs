@@ -0,0 +1,230 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer, TokenAccount, Transfer};
+
+use crate::errors::BountyForgeError;
+use crate::events::{PayoutHeld, ReferralRewardAccrued, VestingStarted};
+use crate::state::{Bounty, Config, Reputation, RewardKind, Vesting};
+
+/// Moves lamports out of a program-owned escrow account such as a SOL bounty.
+///
+/// The system program can only debit accounts that carry no data, so the
+/// balances are adjusted directly. The escrow is never left below its
+/// rent-exempt minimum, otherwise the runtime would reclaim the account.
+pub fn withdraw_lamports<'info>(
+    escrow: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(escrow.data_len());
+    let remaining = escrow
+        .lamports()
+        .checked_sub(amount)
+        .ok_or(BountyForgeError::EscrowBelowRentExempt)?;
+    require!(
+        remaining >= rent_exempt_minimum,
+        BountyForgeError::EscrowBelowRentExempt
+    );
+
+    escrow.sub_lamports(amount)?;
+    to.add_lamports(amount)?;
+
+    Ok(())
+}
+
+/// Pays a claim of `amount` out of the bounty's escrow into a recipient token
+/// account the agent whitelisted, for claim_reward and claim_vested. Both
+/// balances are checked to have moved by exactly `amount`, so a mint that
+/// takes a cut or redirects tokens on transfer can't pay out less than was
+/// settled.
+pub fn claim_tokens<'info>(
+    bounty: &Account<'info, Bounty>,
+    vault: &AccountInfo<'info>,
+    reputation: &Reputation,
+    bounty_token_account: Option<&mut InterfaceAccount<'info, TokenAccount>>,
+    recipient_token_account: Option<&mut InterfaceAccount<'info, TokenAccount>>,
+    token_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<Pubkey> {
+    let (Some(bounty_token_account), Some(recipient_token_account)) =
+        (bounty_token_account, recipient_token_account)
+    else {
+        return err!(BountyForgeError::MissingTokenAccount);
+    };
+    require!(
+        reputation.allows_withdrawal_to(&recipient_token_account.owner),
+        BountyForgeError::WithdrawalAddressNotAllowed
+    );
+
+    let escrow_before = bounty_token_account.amount;
+    let recipient_before = recipient_token_account.amount;
+
+    let bounty_id_bytes = bounty.id.to_le_bytes();
+    let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[bounty.vault_bump]];
+    let vault_signer = &[&vault_seeds[..]];
+
+    // transfering the payout from the vault to the recipient token account
+    let cpi_accounts = Transfer {
+        from: bounty_token_account.to_account_info(),
+        to: recipient_token_account.to_account_info(),
+        authority: vault.clone(),
+    };
+
+    let cpi_ctx = CpiContext::new_with_signer(token_program.clone(), cpi_accounts, vault_signer);
+
+    transfer(cpi_ctx, amount)?;
+
+    bounty_token_account.reload()?;
+    recipient_token_account.reload()?;
+
+    let escrow_expected = escrow_before
+        .checked_sub(amount)
+        .ok_or(BountyForgeError::MathOverflow)?;
+    let recipient_expected = recipient_before
+        .checked_add(amount)
+        .ok_or(BountyForgeError::MathOverflow)?;
+
+    require!(
+        bounty_token_account.amount == escrow_expected
+            && recipient_token_account.amount == recipient_expected,
+        BountyForgeError::SettlementBalanceMismatch
+    );
+
+    Ok(recipient_token_account.key())
+}
+
+/// Pays a claim of `amount` lamports out of a SOL bounty to `recipient`, or
+/// to the agent when none is given, provided the agent whitelisted it.
+pub fn claim_lamports<'info>(
+    bounty: &Account<'info, Bounty>,
+    reputation: &Reputation,
+    agent: &AccountInfo<'info>,
+    recipient: Option<&AccountInfo<'info>>,
+    amount: u64,
+) -> Result<Pubkey> {
+    let recipient = recipient.unwrap_or(agent);
+    require!(
+        reputation.allows_withdrawal_to(recipient.key),
+        BountyForgeError::WithdrawalAddressNotAllowed
+    );
+
+    withdraw_lamports(&bounty.to_account_info(), recipient, amount)?;
+
+    Ok(recipient.key())
+}
+
+/// Pays the referrer of the agent behind `reputation` its cut of a settlement
+/// `fee` out of the bounty's escrow, into `referrer_reputation` where
+/// claim_referral_rewards picks it up. Referral earnings are held in lamports,
+/// so only SOL bounties share their fee, an SPL fee goes to the treasury
+/// whole. Returns the cut, which the caller keeps back from the treasury.
+pub fn pay_referral<'info>(
+    bounty: &Account<'info, Bounty>,
+    config: &Config,
+    reputation: &Reputation,
+    referrer_reputation: Option<&mut Account<'info, Reputation>>,
+    fee: u64,
+) -> Result<u64> {
+    let referral = match (bounty.reward_kind, reputation.referrer) {
+        (RewardKind::Sol, Some(_)) => config.referral_cut(fee)?,
+        _ => 0,
+    };
+    if referral == 0 {
+        return Ok(0);
+    }
+
+    let referrer_reputation =
+        referrer_reputation.ok_or(BountyForgeError::MissingReferrerAccount)?;
+    require!(
+        reputation.referrer == Some(referrer_reputation.agent),
+        BountyForgeError::ReferrerMismatch
+    );
+
+    withdraw_lamports(
+        &bounty.to_account_info(),
+        &referrer_reputation.to_account_info(),
+        referral,
+    )?;
+    referrer_reputation.referral_earnings = referrer_reputation
+        .referral_earnings
+        .checked_add(referral)
+        .ok_or(BountyForgeError::MathOverflow)?;
+
+    emit!(ReferralRewardAccrued {
+        referrer: referrer_reputation.agent,
+        agent: reputation.agent,
+        amount: referral,
+    });
+
+    Ok(referral)
+}
+
+/// Hands the submission fees collected on `bounty` to `to` and empties the pool.
+pub fn release_submission_fees<'info>(
+    bounty: &mut Account<'info, Bounty>,
+    to: &AccountInfo<'info>,
+) -> Result<u64> {
+    let fees = bounty.submission_fees;
+    if fees > 0 {
+        withdraw_lamports(&bounty.to_account_info(), to, fees)?;
+        bounty.submission_fees = 0;
+    }
+
+    Ok(fees)
+}
+
+/// Whether the agent's token account is frozen and would refuse a payout.
+pub fn is_frozen(token_account: Option<&InterfaceAccount<TokenAccount>>) -> bool {
+    token_account.is_some_and(|account| account.is_frozen())
+}
+
+/// Leaves `amount` in escrow as the bounty's `claimable_amount` instead of
+/// paying it out, so a frozen agent token account can't hold up settlement.
+/// The agent pulls it with claim_reward once the account is thawed, or into
+/// another whitelisted account.
+pub fn hold_payout(bounty: &mut Account<Bounty>, agent: Pubkey, amount: u64) {
+    bounty.claimable_amount = amount;
+
+    emit!(PayoutHeld {
+        bounty_id: bounty.id,
+        agent,
+        amount,
+    });
+}
+
+/// Leaves `payout` in escrow as the bounty's `claimable_amount` and opens the
+/// schedule claim_vested releases it on, starting at `now`. Every settlement
+/// of a bounty posted with `vesting_seconds` goes through here instead of
+/// paying the agent directly.
+pub fn vest_payout(
+    bounty: &mut Account<Bounty>,
+    vesting: Option<&mut Account<Vesting>>,
+    bump: Option<u8>,
+    agent: Pubkey,
+    payout: u64,
+    now: i64,
+) -> Result<()> {
+    let (Some(vesting), Some(bump)) = (vesting, bump) else {
+        return err!(BountyForgeError::MissingVestingAccount);
+    };
+
+    bounty.claimable_amount = payout;
+    vesting.set_inner(Vesting {
+        bounty_id: bounty.id,
+        agent,
+        total: payout,
+        claimed: 0,
+        start: now,
+        duration: bounty.vesting_seconds,
+        completed: false,
+        bump,
+    });
+
+    emit!(VestingStarted {
+        bounty_id: bounty.id,
+        agent,
+        total: payout,
+        end: now.saturating_add(bounty.vesting_seconds),
+    });
+
+    Ok(())
+}
@@ -0,0 +1,363 @@
+use anchor_lang::prelude::*;
+
+use crate::state::DisputeOutcome;
+
+#[event]
+pub struct BountyCreated {
+    pub bounty_id: u64,
+    pub creator: Pubkey,
+    pub reward: u64,
+    pub deadline: i64,
+    pub category_bits: u64,
+}
+
+#[event]
+pub struct SolutionAttested {
+    pub solution_id: u64,
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+}
+
+#[event]
+pub struct AttestationVerified {
+    pub solution_id: u64,
+    pub verifier: Pubkey,
+}
+
+#[event]
+pub struct AttestationRevoked {
+    pub solution_id: u64,
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+}
+
+#[event]
+pub struct SolutionCommitted {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+}
+
+#[event]
+pub struct SolutionSubmitted {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub solution_hash: [u8; 32],
+}
+
+#[event]
+pub struct SolutionRevised {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub solution_hash: [u8; 32],
+    pub revision: u16,
+}
+
+#[event]
+pub struct SolutionRejected {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub slashed: u64,
+}
+
+#[event]
+pub struct SolutionForfeited {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub penalty: u64,
+}
+
+#[event]
+pub struct BountyReopened {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+}
+
+#[event]
+pub struct WinnerSelected {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub score: u64,        // reputation score the agent submitted with
+    pub submitted_at: i64, // submission timestamp, the first tie-breaker
+}
+
+#[event]
+pub struct SettlementProposed {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub unlock_at: i64,
+}
+
+#[event]
+pub struct DisputeRaised {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+}
+
+#[event]
+pub struct MilestoneReleased {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub index: u8,
+    pub amount: u64, // net amount paid to the agent
+    pub fee: u64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub bounty_id: u64,
+    pub outcome: DisputeOutcome,
+}
+
+#[event]
+pub struct BountySettled {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub reward: u64, // net amount owed to the agent, paid out by claim_reward
+    pub fee: u64,
+}
+
+#[event]
+pub struct RewardClaimed {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BountyMultiSettled {
+    pub bounty_id: u64,
+    pub winners: Vec<Pubkey>,
+    pub payouts: Vec<u64>, // net amount paid to each winner, in winner order
+    pub fee: u64,
+}
+
+#[event]
+pub struct BountyPartiallySettled {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub reward: u64, // net amount paid to the agent
+    pub fee: u64,
+    pub refund: u64, // unreleased reward returned to the creator
+}
+
+#[event]
+pub struct ReputationDecayed {
+    pub agent: Pubkey,
+    pub score: u64,
+}
+
+#[event]
+pub struct ReputationInitialized {
+    pub agent: Pubkey,
+    pub referrer: Option<Pubkey>,
+}
+
+#[event]
+pub struct ReputationImported {
+    pub agent: Pubkey,
+    pub score: u64,
+}
+
+#[event]
+pub struct WithdrawalAddressAdded {
+    pub agent: Pubkey,
+    pub address: Pubkey,
+}
+
+#[event]
+pub struct WithdrawalAddressRemoved {
+    pub agent: Pubkey,
+    pub address: Pubkey,
+}
+
+#[event]
+pub struct ReputationAdjusted {
+    pub agent: Pubkey,
+    pub score_delta: i64,
+    pub successful_bounties_delta: i64,
+    pub failed_bounties_delta: i64,
+    pub reason_hash: [u8; 32],
+    pub score: u64, // score after the adjustment
+}
+
+#[event]
+pub struct RewardIncreased {
+    pub bounty_id: u64,
+    pub amount: u64,
+    pub reward: u64,
+}
+
+#[event]
+pub struct BountyContributed {
+    pub bounty_id: u64,
+    pub funder: Pubkey,
+    pub amount: u64,
+    pub reward: u64,
+}
+
+#[event]
+pub struct ContributionRefunded {
+    pub bounty_id: u64,
+    pub funder: Pubkey,
+    pub refund: u64,
+}
+
+#[event]
+pub struct EscrowSwept {
+    pub bounty_id: u64,
+    pub amount: u64, // residual balance sent to the treasury
+    pub closed: bool,
+}
+
+#[event]
+pub struct BountyCleanedUp {
+    pub bounty_id: u64,
+    pub creator: Pubkey,
+}
+
+#[event]
+pub struct TokensRescued {
+    pub bounty_id: u64,
+    pub mint: Pubkey,
+    pub recipient: Pubkey, // token account the stray tokens went to
+    pub amount: u64,
+}
+
+#[event]
+pub struct OwnershipTransferred {
+    pub bounty_id: u64,
+    pub previous_creator: Pubkey,
+    pub new_creator: Pubkey,
+}
+
+#[event]
+pub struct DeadlineExtended {
+    pub bounty_id: u64,
+    pub previous_deadline: i64,
+    pub new_deadline: i64,
+}
+
+#[event]
+pub struct BountyCancelled {
+    pub bounty_id: u64,
+    pub refund: u64,
+}
+
+#[event]
+pub struct BountyReclaimed {
+    pub bounty_id: u64,
+    pub refund: u64,
+}
+
+#[event]
+pub struct ReputationBadgeMinted {
+    pub agent: Pubkey,
+    pub badge_mint: Pubkey,
+    pub level: u8,
+    pub successful_bounties: u64,
+}
+
+#[event]
+pub struct ReferralRewardAccrued {
+    pub referrer: Pubkey,
+    pub agent: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct BountySplit {
+    pub bounty_id: u64,
+    pub child_ids: Vec<u64>,
+    pub shares: Vec<u64>,
+}
+
+#[event]
+pub struct AgentRegistered {
+    pub agent: Pubkey,
+    pub handle: Option<String>,
+}
+
+#[event]
+pub struct AgentProfileUpdated {
+    pub agent: Pubkey,
+    pub handle: Option<String>,
+}
+
+#[event]
+pub struct PayoutHeld {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReputationStaked {
+    pub agent: Pubkey,
+    pub locked_until: i64,
+}
+
+#[event]
+pub struct ReputationUnstaked {
+    pub agent: Pubkey,
+    pub score: u64,
+}
+
+#[event]
+pub struct VestingStarted {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub total: u64,
+    pub end: i64,
+}
+
+#[event]
+pub struct VestedRewardClaimed {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub completed: bool,
+}
+
+#[event]
+pub struct RewardMintMigrated {
+    pub bounty_id: u64,
+    pub old_mint: Pubkey,
+    pub new_mint: Pubkey,
+    pub old_reward: u64,
+    pub new_reward: u64,
+}
+
+#[event]
+pub struct BountyFlagged {
+    pub bounty_id: u64,
+    pub moderator: Pubkey,
+    pub reason_hash: [u8; 32], // hash of the off-chain moderation note
+}
+
+#[event]
+pub struct BountyUnflagged {
+    pub bounty_id: u64,
+    pub moderator: Pubkey,
+}
+
+/// One agent's standing as reported by get_reputations.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ReputationEntry {
+    pub agent: Pubkey,
+    pub score: u64,
+    pub successful_bounties: u64,
+    pub failed_bounties: u64,
+}
+
+#[event]
+pub struct ReputationsRead {
+    pub entries: Vec<ReputationEntry>,
+}
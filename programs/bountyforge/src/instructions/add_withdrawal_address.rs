@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_WITHDRAW_WHITELIST;
+use crate::errors::BountyForgeError;
+use crate::events::WithdrawalAddressAdded;
+use crate::state::Reputation;
+
+/// Adds an owner the agent's claimed rewards may be paid to. Once the
+/// whitelist has an entry, claim_reward refuses every other destination.
+#[derive(Accounts)]
+pub struct AddWithdrawalAddress<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"rep", agent.key().as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+}
+
+impl<'info> AddWithdrawalAddress<'info> {
+    pub fn add_withdrawal_address(&mut self, address: Pubkey) -> Result<()> {
+        let whitelist = &mut self.reputation.withdraw_whitelist;
+        require!(
+            !whitelist.contains(&address),
+            BountyForgeError::WithdrawalAddressAlreadyListed
+        );
+        require!(
+            whitelist.len() < MAX_WITHDRAW_WHITELIST,
+            BountyForgeError::WithdrawWhitelistFull
+        );
+
+        whitelist.push(address);
+
+        emit!(WithdrawalAddressAdded {
+            agent: self.agent.key(),
+            address,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::ReputationAdjusted;
+use crate::state::{Config, Reputation};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AdjustReputationArgs {
+    pub score_delta: i64,
+    pub successful_bounties_delta: i64,
+    pub failed_bounties_delta: i64,
+    pub reason_hash: [u8; 32], // hash of the off-chain moderation note
+}
+
+/// Corrects an agent's reputation when it was gamed. Meant for abuse
+/// remediation, every use is published through `ReputationAdjusted`.
+#[derive(Accounts)]
+pub struct AdjustReputation<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ BountyForgeError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"rep", reputation.agent.as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+}
+
+impl<'info> AdjustReputation<'info> {
+    pub fn adjust_reputation(&mut self, args: AdjustReputationArgs) -> Result<()> {
+        let AdjustReputationArgs {
+            score_delta,
+            successful_bounties_delta,
+            failed_bounties_delta,
+            reason_hash,
+        } = args;
+
+        // 1. validating the adjustment is documented
+        require!(reason_hash != [0; 32], BountyForgeError::InvalidReasonHash);
+
+        // 2. applying the correction
+        self.reputation.adjust(
+            score_delta,
+            successful_bounties_delta,
+            failed_bounties_delta,
+        )?;
+
+        emit!(ReputationAdjusted {
+            agent: self.reputation.agent,
+            score_delta,
+            successful_bounties_delta,
+            failed_bounties_delta,
+            reason_hash,
+            score: self.reputation.score,
+        });
+
+        Ok(())
+    }
+}
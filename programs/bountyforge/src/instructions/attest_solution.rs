@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::ANCHOR_DISCRIMINATOR, state::Attestation};
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR,
+    errors::BountyForgeError,
+    events::SolutionAttested,
+    state::{Attestation, Config},
+};
 
 #[derive(Accounts)]
 #[instruction(solution_id : u64)]
@@ -8,6 +13,13 @@ pub struct AttestSolution<'info> {
     #[account(mut)]
     pub agent: Signer<'info>,
 
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ BountyForgeError::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
     #[account(
         init,
         payer = agent,
@@ -24,20 +36,36 @@ impl<'info> AttestSolution<'info> {
     pub fn attest_solution(
         &mut self,
         solution_id: u64,
+        bounty_id: u64,
         solution_hash: [u8; 32],
         bumps: &AttestSolutionBumps,
     ) -> Result<()> {
+        // an all-zero hash is a client placeholder, never a real solution
+        require!(
+            solution_hash != [0; 32],
+            BountyForgeError::InvalidSolutionHash
+        );
+
         let now = Clock::get()?.unix_timestamp;
 
         self.attestation.set_inner(Attestation {
             solution_id,
+            bounty_id,
             solution_hash,
             timestamp: now,
             agent: self.agent.key(),
             verified: false,
+            verified_at: 0,
+            revoked: false,
             bump: bumps.attestation,
         });
 
+        emit!(SolutionAttested {
+            solution_id,
+            bounty_id,
+            agent: self.agent.key(),
+        });
+
         Ok(())
     }
 }
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::WinnerSelected;
+use crate::instructions::settle_bounty::propose_settlement;
+use crate::state::{Bounty, BountyHistory, BountyStatus, Config, Submission};
+
+/// Lets the program pick the winner of a competition bounty. Every submission
+/// is passed in `remaining_accounts` and the agent with the highest reputation
+/// score at submit time wins, ties going to agents who had staked their
+/// reputation, then to the earliest submission and then to the lowest agent
+/// key. The winner goes through the usual dispute window like `settle_bounty`.
+#[derive(Accounts)]
+pub struct AutoSettle<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ BountyForgeError::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedSettlement,
+        constraint = bounty.competition @ BountyForgeError::NotCompetitionBounty,
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+}
+
+impl<'info> AutoSettle<'info> {
+    pub fn auto_settle(&mut self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        // 1. validating every submission of the bounty is passed exactly once
+        require!(
+            !remaining_accounts.is_empty()
+                && remaining_accounts.len() == self.bounty.submission_count as usize,
+            BountyForgeError::IncompleteSubmissionSet
+        );
+
+        let mut seen = Vec::with_capacity(remaining_accounts.len());
+        let mut best: Option<(Account<'info, Submission>, u64)> = None;
+
+        for submission_info in remaining_accounts {
+            let submission = Account::<Submission>::try_from(submission_info)?;
+
+            require!(
+                submission.bounty_id == self.bounty.id,
+                BountyForgeError::SubmissionMismatch
+            );
+            require!(
+                !seen.contains(&submission.agent),
+                BountyForgeError::IncompleteSubmissionSet
+            );
+            seen.push(submission.agent);
+
+            // 2. keeping the best ranked submission so far
+            let score = submission.reputation_score_at_submit;
+            let outranks = match &best {
+                None => true,
+                Some((leader, leader_score)) => {
+                    let rank = (score, submission.staked_at_submit);
+                    let leader_rank = (*leader_score, leader.staked_at_submit);
+                    rank > leader_rank
+                        || (rank == leader_rank
+                            && (submission.timestamp, submission.agent)
+                                < (leader.timestamp, leader.agent))
+                }
+            };
+            if outranks {
+                best = Some((submission, score));
+            }
+        }
+
+        // 3. proposing the settlement to the winner
+        let (winner, score) = best.ok_or(BountyForgeError::IncompleteSubmissionSet)?;
+
+        emit!(WinnerSelected {
+            bounty_id: self.bounty.id,
+            agent: winner.agent,
+            score,
+            submitted_at: winner.timestamp,
+        });
+
+        propose_settlement(
+            &mut self.bounty,
+            &mut self.history,
+            &winner,
+            winner.agent,
+            self.creator.key(),
+            &self.config,
+        )
+    }
+}
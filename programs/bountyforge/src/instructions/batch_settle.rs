@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_BATCH_SETTLE;
+use crate::errors::BountyForgeError;
+use crate::instructions::settle_bounty::propose_settlement;
+use crate::state::{Bounty, BountyHistory, BountyStatus, Config, Submission};
+
+/// Settles several bounties of one creator at once. Each bounty is passed in
+/// `remaining_accounts` as a (bounty, history, submission, agent) group,
+/// checked the same way as `settle_bounty`. Any invalid group fails the whole
+/// batch.
+#[derive(Accounts)]
+pub struct BatchSettle<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ BountyForgeError::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> BatchSettle<'info> {
+    pub fn batch_settle(&mut self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        let groups = remaining_accounts.chunks_exact(4);
+        require!(
+            groups.remainder().is_empty() && (1..=MAX_BATCH_SETTLE).contains(&groups.len()),
+            BountyForgeError::InvalidBatchSize
+        );
+
+        for group in groups {
+            let [bounty_info, history_info, submission_info, agent] = group else {
+                unreachable!()
+            };
+
+            // 1. validating the group like the settle_bounty constraints
+            require!(
+                bounty_info.is_writable && history_info.is_writable,
+                BountyForgeError::AccountNotWritable
+            );
+            let mut bounty = Account::<Bounty>::try_from(bounty_info)?;
+            let mut history = Account::<BountyHistory>::try_from(history_info)?;
+            let submission = Account::<Submission>::try_from(submission_info)?;
+
+            require!(
+                bounty.status == BountyStatus::Submitted
+                    || (bounty.competition && bounty.status == BountyStatus::Open),
+                BountyForgeError::BountyNotSubmitted
+            );
+            require_keys_eq!(
+                self.creator.key(),
+                bounty.creator,
+                BountyForgeError::UnauthorizedSettlement
+            );
+            require!(
+                bounty.competition || bounty.agent == Some(agent.key()),
+                BountyForgeError::AgentMismatch
+            );
+            require!(
+                submission.bounty_id == bounty.id && submission.agent == agent.key(),
+                BountyForgeError::SubmissionMismatch
+            );
+            require!(
+                history.bounty_id == bounty.id,
+                BountyForgeError::HistoryMismatch
+            );
+
+            // 2. proposing the settlement and writing the bounty back so a
+            // repeated bounty later in the batch sees its new status
+            propose_settlement(
+                &mut bounty,
+                &mut history,
+                &submission,
+                agent.key(),
+                self.creator.key(),
+                &self.config,
+            )?;
+            bounty.exit(&crate::ID)?;
+            history.exit(&crate::ID)?;
+        }
+
+        Ok(())
+    }
+}
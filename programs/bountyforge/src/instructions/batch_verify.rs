@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_BATCH_VERIFY;
+use crate::errors::BountyForgeError;
+use crate::events::AttestationVerified;
+use crate::state::{Attestation, Config};
+
+/// Verifies several attestations at once. Each attestation is passed in
+/// `remaining_accounts` and checked the same way as `verify_attestation`.
+/// Any invalid attestation fails the whole batch.
+#[derive(Accounts)]
+pub struct BatchVerify<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.verifier == verifier.key() @ BountyForgeError::UnauthorizedVerifier
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> BatchVerify<'info> {
+    pub fn batch_verify(&mut self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        require!(
+            (1..=MAX_BATCH_VERIFY).contains(&remaining_accounts.len()),
+            BountyForgeError::InvalidBatchSize
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+
+        for attestation_info in remaining_accounts {
+            // 1. validating the attestation like the verify_attestation constraints
+            require!(
+                attestation_info.is_writable,
+                BountyForgeError::AccountNotWritable
+            );
+            let mut attestation = Account::<Attestation>::try_from(attestation_info)?;
+
+            require!(!attestation.verified, BountyForgeError::AlreadyVerified);
+            require!(!attestation.revoked, BountyForgeError::AttestationRevoked);
+
+            // 2. verifying and writing the attestation back so a repeated
+            // attestation later in the batch is seen as already verified
+            attestation.verified = true;
+            attestation.verified_at = now;
+            attestation.exit(&crate::ID)?;
+
+            emit!(AttestationVerified {
+                solution_id: attestation.solution_id,
+                verifier: self.verifier.key(),
+            });
+        }
+
+        Ok(())
+    }
+}
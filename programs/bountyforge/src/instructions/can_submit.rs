@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+
+use crate::eligibility::SubmitEligibility;
+use crate::state::{Bounty, Config, Reputation};
+
+/// Read-only check of whether `agent` could reveal on `bounty` right now.
+/// Meant to be simulated, the returned flags say which guards would fail.
+#[derive(Accounts)]
+pub struct CanSubmit<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Agent the guards are evaluated for
+    pub agent: AccountInfo<'info>,
+
+    // absent for an agent who has not submitted anywhere yet
+    #[account(seeds = [b"rep", agent.key().as_ref()], bump = reputation.bump)]
+    pub reputation: Option<Account<'info, Reputation>>,
+}
+
+impl<'info> CanSubmit<'info> {
+    pub fn can_submit(&self, proof: Vec<[u8; 32]>) -> Result<SubmitEligibility> {
+        let now = Clock::get()?.unix_timestamp;
+
+        Ok(SubmitEligibility::evaluate(
+            &self.bounty,
+            self.reputation.as_deref(),
+            &self.config,
+            &self.agent.key(),
+            &proof,
+            now,
+        ))
+    }
+}
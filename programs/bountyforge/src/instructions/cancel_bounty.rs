@@ -0,0 +1,124 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::errors::BountyForgeError;
+use crate::escrow::withdraw_lamports;
+use crate::events::BountyCancelled;
+use crate::state::{Bounty, BountyHistory, BountyStatus, Config, CreatorProfile, RewardKind};
+
+#[derive(Accounts)]
+pub struct CancelBounty<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = bounty.solution_hash.is_none() @ BountyForgeError::BountyAlreadySubmitted,
+        constraint = bounty.submission_count == 0 @ BountyForgeError::BountyHasSubmissions,
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        mut,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key(),
+        constraint = creator_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Reward mint recorded on the bounty, omitted for SOL bounties
+    #[account(address = bounty.reward_mint @ BountyForgeError::RewardMintMismatch)]
+    pub reward_mint: Option<AccountInfo<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CancelBounty<'info> {
+    pub fn cancel_bounty(&mut self) -> Result<()> {
+        // 1. refunding the creator's share, contributors withdraw their own
+        let refund = self.bounty.creator_share();
+        match self.bounty.reward_kind {
+            RewardKind::Spl => self.refund_tokens(refund)?,
+            RewardKind::Sol => withdraw_lamports(
+                &self.bounty.to_account_info(),
+                &self.creator.to_account_info(),
+                refund,
+            )?,
+        }
+        self.config.sub_escrowed(refund)?;
+
+        // 2. recording the cancellation on the creator's profile
+        self.creator_profile.bounties_cancelled = self
+            .creator_profile
+            .bounties_cancelled
+            .checked_add(1)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        // 3. updating bounty status
+        self.bounty.transition(BountyStatus::Open, BountyStatus::Cancelled)?;
+        self.history.record(
+            BountyStatus::Cancelled,
+            Clock::get()?.unix_timestamp,
+            self.creator.key(),
+        );
+
+        emit!(BountyCancelled {
+            bounty_id: self.bounty.id,
+            refund,
+        });
+
+        Ok(())
+    }
+
+    fn refund_tokens(&self, refund: u64) -> Result<()> {
+        let (Some(creator_token_account), Some(bounty_token_account)) =
+            (&self.creator_token_account, &self.bounty_token_account)
+        else {
+            return err!(BountyForgeError::MissingTokenAccount);
+        };
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: bounty_token_account.to_account_info(),
+            to: creator_token_account.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, refund)
+    }
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::escrow::withdraw_lamports;
+use crate::events::ReferralRewardsClaimed;
+use crate::state::Reputation;
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"rep", referrer.key().as_ref()],
+        bump = reputation.bump,
+        constraint = reputation.agent == referrer.key() @ BountyForgeError::ReputationOwnerMismatch
+    )]
+    pub reputation: Account<'info, Reputation>,
+}
+
+impl<'info> ClaimReferralRewards<'info> {
+    pub fn claim_referral_rewards(&mut self) -> Result<()> {
+        let amount = self.reputation.referral_earnings;
+        require!(amount > 0, BountyForgeError::NoReferralEarnings);
+
+        // transfering the accrued lamports held on the reputation account to the referrer
+        withdraw_lamports(
+            &self.reputation.to_account_info(),
+            &self.referrer.to_account_info(),
+            amount,
+        )?;
+        self.reputation.referral_earnings = 0;
+
+        emit!(ReferralRewardsClaimed {
+            referrer: self.referrer.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::errors::BountyForgeError;
+use crate::escrow::{claim_lamports, claim_tokens};
+use crate::events::RewardClaimed;
+use crate::state::{Bounty, BountyStatus, Config, Reputation, RewardKind};
+
+/// Pays out a settled bounty. finalize_settlement only records what the agent
+/// is owed, as do the other settlements when the agent's token account is
+/// frozen, so the agent pulls it here into any account of their choosing.
+/// Payouts that vest are claimed with claim_vested instead.
+#[derive(Accounts)]
+pub struct ClaimReward<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Settled @ BountyForgeError::BountyNotFinalized,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch,
+        constraint = bounty.claimable_amount > 0 @ BountyForgeError::NothingToClaim,
+        constraint = bounty.vesting_seconds == 0 @ BountyForgeError::RewardVesting
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"rep", agent.key().as_ref()], bump = reputation.bump)]
+    pub reputation: Account<'info, Reputation>,
+
+    /// CHECK: Wallet receiving the reward on SOL bounties
+    #[account(mut)]
+    pub recipient: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimReward<'info> {
+    pub fn claim_reward(&mut self) -> Result<()> {
+        let amount = self.bounty.claimable_amount;
+
+        // 1. moving the payout out of escrow to a destination the agent whitelisted
+        let recipient = match self.bounty.reward_kind {
+            RewardKind::Spl => claim_tokens(
+                &self.bounty,
+                &self.vault.to_account_info(),
+                &self.reputation,
+                self.bounty_token_account.as_mut(),
+                self.recipient_token_account.as_mut(),
+                &self.token_program.to_account_info(),
+                amount,
+            )?,
+            RewardKind::Sol => claim_lamports(
+                &self.bounty,
+                &self.reputation,
+                &self.agent.to_account_info(),
+                self.recipient.as_ref(),
+                amount,
+            )?,
+        };
+        self.config.sub_escrowed(amount)?;
+        self.bounty.claimable_amount = 0;
+
+        emit!(RewardClaimed {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            recipient,
+            amount,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{TokenAccount, TokenInterface};
+
+use crate::errors::BountyForgeError;
+use crate::escrow::{claim_lamports, claim_tokens};
+use crate::events::VestedRewardClaimed;
+use crate::state::{Bounty, BountyStatus, Config, Reputation, RewardKind, Vesting};
+
+/// Pays out the part of a vesting reward unlocked so far. Can be called as
+/// often as the agent likes, each call sends what vested since the last one
+/// to a destination the agent whitelisted, and the last claim completes the
+/// schedule.
+#[derive(Accounts)]
+pub struct ClaimVested<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Settled @ BountyForgeError::BountyNotFinalized,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"vesting", bounty.id.to_le_bytes().as_ref()],
+        bump = vesting.bump,
+        constraint = !vesting.completed @ BountyForgeError::NothingToClaim
+    )]
+    pub vesting: Account<'info, Vesting>,
+
+    #[account(seeds = [b"rep", agent.key().as_ref()], bump = reputation.bump)]
+    pub reputation: Account<'info, Reputation>,
+
+    /// CHECK: Wallet receiving the reward on SOL bounties
+    #[account(mut)]
+    pub recipient: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub recipient_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ClaimVested<'info> {
+    pub fn claim_vested(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // 1. working out what vested since the last claim
+        let amount = self.vesting.claimable_amount(now);
+        require!(amount > 0, BountyForgeError::NothingToClaim);
+
+        // 2. moving it out of escrow to a destination the agent whitelisted
+        let recipient = match self.bounty.reward_kind {
+            RewardKind::Spl => claim_tokens(
+                &self.bounty,
+                &self.vault.to_account_info(),
+                &self.reputation,
+                self.bounty_token_account.as_mut(),
+                self.recipient_token_account.as_mut(),
+                &self.token_program.to_account_info(),
+                amount,
+            )?,
+            RewardKind::Sol => claim_lamports(
+                &self.bounty,
+                &self.reputation,
+                &self.agent.to_account_info(),
+                self.recipient.as_ref(),
+                amount,
+            )?,
+        };
+        self.config.sub_escrowed(amount)?;
+        self.bounty.claimable_amount = self
+            .bounty
+            .claimable_amount
+            .checked_sub(amount)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        // 3. recording the claim, the schedule completes with the last of the payout
+        self.vesting.claimed = self
+            .vesting
+            .claimed
+            .checked_add(amount)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        self.vesting.completed = self.vesting.claimed == self.vesting.total;
+
+        emit!(VestedRewardClaimed {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            recipient,
+            amount,
+            completed: self.vesting.completed,
+        });
+
+        Ok(())
+    }
+}
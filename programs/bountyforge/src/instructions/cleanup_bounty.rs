@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{close_account, CloseAccount, TokenAccount, TokenInterface};
+
+use crate::errors::BountyForgeError;
+use crate::events::BountyCleanedUp;
+use crate::state::{Bounty, BountyStatus, RewardKind};
+
+/// Closes a settled or cancelled bounty together with its empty escrow token
+/// account and hands both rent deposits back to the creator. Refused while
+/// any agent still holds a submission on it, since close_submission needs the
+/// bounty to hand back the stake.
+#[derive(Accounts)]
+pub struct CleanupBounty<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        close = creator,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = matches!(bounty.status, BountyStatus::Settled | BountyStatus::Cancelled)
+            @ BountyForgeError::BountyNotClosable,
+        constraint = bounty.claimable_amount == 0 @ BountyForgeError::RewardUnclaimed,
+        constraint = bounty.open_submissions == 0 @ BountyForgeError::SubmissionsOutstanding
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    /// CHECK: The bounty's escrow, omitted for SOL bounties. It may already
+    /// have been closed by sweep_escrow, so it is only read when it exists.
+    #[account(
+        mut,
+        address = get_associated_token_address_with_program_id(
+            &vault.key(),
+            &bounty.reward_mint,
+            &token_program.key()
+        ) @ BountyForgeError::NonCanonicalTokenAccount
+    )]
+    pub bounty_token_account: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> CleanupBounty<'info> {
+    pub fn cleanup_bounty(&mut self) -> Result<()> {
+        // 1. making sure nothing is left in escrow, the bounty's own rent goes
+        // back to the creator through the `close` constraint
+        match self.bounty.reward_kind {
+            RewardKind::Spl => self.close_escrow()?,
+            RewardKind::Sol => {
+                let bounty = self.bounty.to_account_info();
+                let rent_exempt_minimum = Rent::get()?.minimum_balance(bounty.data_len());
+                require!(
+                    bounty.lamports() <= rent_exempt_minimum,
+                    BountyForgeError::EscrowNotEmpty
+                );
+            }
+        }
+
+        emit!(BountyCleanedUp {
+            bounty_id: self.bounty.id,
+            creator: self.creator.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Closes the escrow token account if it still exists, refusing while it
+    /// holds any tokens.
+    fn close_escrow(&self) -> Result<()> {
+        let bounty_token_account = self
+            .bounty_token_account
+            .as_ref()
+            .ok_or(BountyForgeError::MissingTokenAccount)?;
+
+        if bounty_token_account.data_is_empty() {
+            return Ok(());
+        }
+
+        let escrow = {
+            let data = bounty_token_account.try_borrow_data()?;
+            TokenAccount::try_deserialize(&mut &data[..])?
+        };
+        require!(escrow.amount == 0, BountyForgeError::EscrowNotEmpty);
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        // closing the escrow, its rent goes back to the creator
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = CloseAccount {
+            account: bounty_token_account.to_account_info(),
+            destination: self.creator.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        close_account(cpi_ctx)
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::state::{Attestation, Bounty, BountyStatus};
+
+#[derive(Accounts)]
+pub struct CloseAttestation<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        close = agent,
+        constraint = attestation.agent == agent.key() @ BountyForgeError::AttestationOwnerMismatch
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    /// CHECK: The bounty the attestation was made for. cleanup_bounty may
+    /// already have closed it, so it is only read while it exists.
+    #[account(seeds = [b"bounty", attestation.bounty_id.to_le_bytes().as_ref()], bump)]
+    pub bounty: UncheckedAccount<'info>,
+}
+
+impl<'info> CloseAttestation<'info> {
+    pub fn close_attestation(&mut self) -> Result<()> {
+        // a bounty that was cleaned up was settled or cancelled first
+        if !self.bounty.data_is_empty() {
+            let bounty = {
+                let data = self.bounty.try_borrow_data()?;
+                Bounty::try_deserialize(&mut &data[..])?
+            };
+            require!(
+                matches!(bounty.status, BountyStatus::Settled | BountyStatus::Cancelled),
+                BountyForgeError::BountyNotFinalized
+            );
+        }
+
+        // the attestation rent goes back to the agent through the `close` constraint
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::state::{Bounty, Reputation, Stake, Submission};
+
+#[derive(Accounts)]
+pub struct CloseSubmission<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bounty.status.is_final() @ BountyForgeError::BountyNotFinalized
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"submission", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"stake", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = stake.bump
+    )]
+    pub stake: Account<'info, Stake>,
+
+    #[account(
+        mut,
+        seeds = [b"rep", agent.key().as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+}
+
+impl<'info> CloseSubmission<'info> {
+    pub fn close_submission(&mut self) -> Result<()> {
+        // rent and any stake not yet returned or slashed go back to the agent
+        // through the `close` constraints, freeing one of its submission slots
+        // unless the settlement that paid it already did
+        let won = self.bounty.agent == Some(self.agent.key()) || self.submission.won;
+        if !won {
+            self.reputation.release_submission();
+        }
+        self.bounty.submission_closed();
+
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::ANCHOR_DISCRIMINATOR,
+    errors::BountyForgeError,
+    events::SolutionCommitted,
+    state::{Bounty, BountyStatus, Commitment, Config},
+};
+
+#[derive(Accounts)]
+pub struct CommitSolution<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ BountyForgeError::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen,
+        constraint = !bounty.flagged @ BountyForgeError::BountyFlagged
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        init,
+        payer = agent,
+        space = ANCHOR_DISCRIMINATOR + Commitment::INIT_SPACE,
+        seeds = [b"commitment", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump
+    )]
+    pub commitment: Account<'info, Commitment>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CommitSolution<'info> {
+    pub fn commit_solution(
+        &mut self,
+        commitment: [u8; 32],
+        bumps: &CommitSolutionBumps,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(now <= self.bounty.deadline, BountyForgeError::BountyExpired);
+
+        self.commitment.set_inner(Commitment {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            commitment,
+            committed_at: now,
+            bump: bumps.commitment,
+        });
+
+        emit!(SolutionCommitted {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+        });
+
+        Ok(())
+    }
+}
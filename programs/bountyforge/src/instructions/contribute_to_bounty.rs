@@ -0,0 +1,147 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::constants::ANCHOR_DISCRIMINATOR;
+use crate::errors::BountyForgeError;
+use crate::events::BountyContributed;
+use crate::state::{Bounty, BountyStatus, Config, Contribution, RewardKind};
+
+#[derive(Accounts)]
+pub struct ContributeToBounty<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = funder,
+        space = ANCHOR_DISCRIMINATOR + Contribution::INIT_SPACE,
+        seeds = [b"contrib", bounty.id.to_le_bytes().as_ref(), funder.key().as_ref()],
+        bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        constraint = funder_token_account.owner == funder.key(),
+        constraint = funder_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub funder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ContributeToBounty<'info> {
+    pub fn contribute_to_bounty(
+        &mut self,
+        amount: u64,
+        bumps: &ContributeToBountyBumps,
+    ) -> Result<()> {
+        // 1. recording the contribution
+        if self.contribution.funder == Pubkey::default() {
+            self.contribution.set_inner(Contribution {
+                bounty_id: self.bounty.id,
+                funder: self.funder.key(),
+                amount: 0,
+                bump: bumps.contribution,
+            });
+        }
+
+        self.contribution.amount = self
+            .contribution
+            .amount
+            .checked_add(amount)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        // 2. growing the reward, the top-up lands on the last milestone as on increase_reward
+        self.bounty.reward = self
+            .bounty
+            .reward
+            .checked_add(amount)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        require!(
+            self.bounty.within_reward_cap(),
+            BountyForgeError::RewardAboveMaximum
+        );
+        self.bounty.contributed = self
+            .bounty
+            .contributed
+            .checked_add(amount)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        if let Some(milestone) = self.bounty.milestones.last_mut() {
+            milestone.amount = milestone
+                .amount
+                .checked_add(amount)
+                .ok_or(BountyForgeError::MathOverflow)?;
+        }
+
+        // 3. escrowing the contribution
+        match self.bounty.reward_kind {
+            RewardKind::Spl => self.escrow_tokens(amount)?,
+            RewardKind::Sol => self.escrow_lamports(amount)?,
+        }
+        self.config.add_escrowed(amount)?;
+
+        emit!(BountyContributed {
+            bounty_id: self.bounty.id,
+            funder: self.funder.key(),
+            amount,
+            reward: self.bounty.reward,
+        });
+
+        Ok(())
+    }
+
+    fn escrow_tokens(&self, amount: u64) -> Result<()> {
+        let (Some(funder_token_account), Some(bounty_token_account)) =
+            (&self.funder_token_account, &self.bounty_token_account)
+        else {
+            return err!(BountyForgeError::MissingTokenAccount);
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: funder_token_account.to_account_info(),
+            to: bounty_token_account.to_account_info(),
+            authority: self.funder.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+
+        transfer(cpi_context, amount)
+    }
+
+    fn escrow_lamports(&self, amount: u64) -> Result<()> {
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_accounts = system_program::Transfer {
+            from: self.funder.to_account_info(),
+            to: self.bounty.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+
+        system_program::transfer(cpi_context, amount)
+    }
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::events::ReputationDecayed;
+use crate::state::{Config, Reputation};
+
+#[derive(Accounts)]
+pub struct DecayReputation<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"rep", reputation.agent.as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+}
+
+impl<'info> DecayReputation<'info> {
+    pub fn decay_reputation(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        self.reputation
+            .apply_decay(now, self.config.reputation_half_life);
+
+        emit!(ReputationDecayed {
+            agent: self.reputation.agent,
+            score: self.reputation.score,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,310 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::constants::ANCHOR_DISCRIMINATOR;
+use crate::errors::BountyForgeError;
+use crate::escrow::{
+    hold_payout, is_frozen, pay_referral, release_submission_fees, vest_payout,
+    withdraw_lamports,
+};
+use crate::events::BountySettled;
+use crate::state::{
+    Bounty, BountyHistory, BountyStatus, Config, Reputation, RewardKind, Stake, Vesting,
+};
+
+/// Pays out a submission the creator never settled once the deadline and the
+/// grace period have passed. Callable by anyone so agents aren't left waiting
+/// on a creator who stopped responding.
+#[derive(Accounts)]
+pub struct ExpireSubmitted<'info> {
+    #[account(mut)]
+    pub caller: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Submitted @ BountyForgeError::BountyNotSubmitted,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        mut,
+        constraint = reputation.agent == agent.key() @ BountyForgeError::ReputationOwnerMismatch
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    #[account(
+        mut,
+        constraint = reputation.referrer == Some(referrer_reputation.agent) @ BountyForgeError::ReferrerMismatch
+    )]
+    pub referrer_reputation: Option<Account<'info, Reputation>>,
+
+    /// CHECK: Agent receiving the reward
+    #[account(mut)]
+    pub agent: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = stake.bump
+    )]
+    pub stake: Account<'info, Stake>,
+
+    #[account(
+        mut,
+        constraint = agent_token_account.owner == agent.key(),
+        constraint = agent_token_account.key() == get_associated_token_address_with_program_id(
+            &agent.key(),
+            &bounty.reward_mint,
+            &token_program.key()
+        ) @ BountyForgeError::NonCanonicalTokenAccount,
+        constraint = agent_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub agent_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch,
+        constraint = bounty_token_account.amount >= bounty.unreleased_reward() @ BountyForgeError::InsufficientEscrow
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury,
+        constraint = treasury_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Treasury wallet receiving the fee on SOL bounties
+    #[account(mut, address = config.treasury)]
+    pub treasury: Option<AccountInfo<'info>>,
+
+    /// Required when the bounty was posted with `vesting_seconds`
+    #[account(
+        init,
+        payer = caller,
+        space = ANCHOR_DISCRIMINATOR + Vesting::INIT_SPACE,
+        seeds = [b"vesting", bounty.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vesting: Option<Account<'info, Vesting>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Option<Program<'info, System>>,
+}
+
+impl<'info> ExpireSubmitted<'info> {
+    pub fn expire_submitted(&mut self, bumps: &ExpireSubmittedBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let expires_at = self
+            .bounty
+            .deadline
+            .checked_add(self.config.grace_period)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        require!(now >= expires_at, BountyForgeError::GracePeriodNotElapsed);
+
+        // 1. splitting what milestones have not paid out into platform fee and agent payout
+        let outstanding = self
+            .bounty
+            .reward
+            .checked_sub(self.bounty.released_reward())
+            .ok_or(BountyForgeError::MathOverflow)?;
+        let fee = self
+            .config
+            .fee_for_tier(outstanding, self.bounty.tier_at_submit)?;
+        let payout = outstanding
+            .checked_sub(fee)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        self.bounty.check_min_payout(payout)?;
+
+        // 2. paying the referrer its cut of the fee, the treasury the rest of it and the
+        // agent its payout, a payout that vests is held in escrow for claim_vested and one
+        // meeting a frozen agent token account for claim_reward
+        let referral = pay_referral(
+            &self.bounty,
+            &self.config,
+            &self.reputation,
+            self.referrer_reputation.as_mut(),
+            fee,
+        )?;
+        let treasury_fee = fee
+            .checked_sub(referral)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        let held = if self.bounty.vesting_seconds > 0 {
+            vest_payout(
+                &mut self.bounty,
+                self.vesting.as_mut(),
+                bumps.vesting,
+                self.agent.key(),
+                payout,
+                now,
+            )?;
+            payout
+        } else if is_frozen(self.agent_token_account.as_ref()) {
+            hold_payout(&mut self.bounty, self.agent.key(), payout);
+            payout
+        } else {
+            0
+        };
+        // what is pushed lands in the agent's own accounts, which its whitelist must allow
+        require!(
+            held == payout || self.reputation.allows_withdrawal_to(&self.agent.key()),
+            BountyForgeError::WithdrawalAddressNotAllowed
+        );
+        match self.bounty.reward_kind {
+            RewardKind::Spl => {
+                self.pay_out_tokens(treasury_fee, payout - held)?;
+                self.verify_escrow_balance()?;
+            }
+            RewardKind::Sol => self.pay_out_lamports(treasury_fee, payout - held)?,
+        }
+        self.config.sub_escrowed(outstanding - held)?;
+
+        // 3. returning the agent's stake and handing over the submission fees, the stake
+        // rent comes back on close_submission
+        withdraw_lamports(
+            &self.stake.to_account_info(),
+            &self.agent,
+            self.stake.amount,
+        )?;
+        self.stake.amount = 0;
+        release_submission_fees(&mut self.bounty, &self.agent)?;
+
+        // 4. crediting the agent as if the creator had settled
+        self.reputation
+            .apply_decay(now, self.config.reputation_half_life);
+
+        self.reputation.successful_bounties = self
+            .reputation
+            .successful_bounties
+            .checked_add(1)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+
+        self.reputation.total_earned = self
+            .reputation
+            .total_earned
+            .checked_add(payout)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+
+        self.reputation.score = self
+            .reputation
+            .score
+            .checked_add(self.config.settle_score(self.bounty.difficulty)?)
+            .ok_or(BountyForgeError::ReputationScoreOverflow)?;
+
+        self.reputation.release_submission();
+        self.reputation.refresh_rank_weight();
+
+        // 5. updating bounty status
+        self.bounty.last_rejected_hash = None;
+        self.bounty.transition(BountyStatus::Submitted, BountyStatus::Settled)?;
+        self.history
+            .record(BountyStatus::Settled, now, self.caller.key());
+
+        emit!(BountySettled {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            reward: payout,
+            fee,
+        });
+
+        Ok(())
+    }
+
+    fn pay_out_tokens(&self, fee: u64, payout: u64) -> Result<()> {
+        let (Some(bounty_token_account), Some(agent_token_account)) =
+            (&self.bounty_token_account, &self.agent_token_account)
+        else {
+            return err!(BountyForgeError::MissingTokenAccount);
+        };
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        // transfering the fee from the vault to treasury token account
+        if fee > 0 {
+            let treasury_token_account = self
+                .treasury_token_account
+                .as_ref()
+                .ok_or(BountyForgeError::MissingTokenAccount)?;
+
+            let cpi_program = self.token_program.to_account_info();
+            let cpi_accounts = Transfer {
+                from: bounty_token_account.to_account_info(),
+                to: treasury_token_account.to_account_info(),
+                authority: self.vault.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+            transfer(cpi_ctx, fee)?;
+        }
+
+        // transfering the rest from the vault to agent token account
+        if payout > 0 {
+            let cpi_program = self.token_program.to_account_info();
+            let cpi_accounts = Transfer {
+                from: bounty_token_account.to_account_info(),
+                to: agent_token_account.to_account_info(),
+                authority: self.vault.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+            transfer(cpi_ctx, payout)?;
+        }
+
+        Ok(())
+    }
+
+    /// Re-reads the escrow after the payout CPIs and checks it still backs
+    /// the payout held for claim_reward or claim_vested.
+    fn verify_escrow_balance(&mut self) -> Result<()> {
+        let bounty_token_account = self
+            .bounty_token_account
+            .as_mut()
+            .ok_or(BountyForgeError::MissingTokenAccount)?;
+
+        bounty_token_account.reload()?;
+
+        require!(
+            bounty_token_account.amount >= self.bounty.claimable_amount,
+            BountyForgeError::SettlementBalanceMismatch
+        );
+
+        Ok(())
+    }
+
+    fn pay_out_lamports(&self, fee: u64, payout: u64) -> Result<()> {
+        let bounty = self.bounty.to_account_info();
+
+        if fee > 0 {
+            let treasury = self
+                .treasury
+                .as_ref()
+                .ok_or(BountyForgeError::MissingTreasuryAccount)?;
+            withdraw_lamports(&bounty, treasury, fee)?;
+        }
+
+        withdraw_lamports(&bounty, &self.agent, payout)
+    }
+}
@@ -0,0 +1,20 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{Reputation, ReputationSnapshot};
+
+/// Read-only snapshot of an agent's reputation, meant to be simulated before a
+/// layout migration and fed back through `import_reputation`.
+#[derive(Accounts)]
+pub struct ExportReputation<'info> {
+    #[account(
+        seeds = [b"rep", reputation.agent.as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+}
+
+impl<'info> ExportReputation<'info> {
+    pub fn export_reputation(&self) -> Result<ReputationSnapshot> {
+        Ok(self.reputation.snapshot())
+    }
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::DeadlineExtended;
+use crate::state::{Bounty, BountyStatus};
+
+/// Pushes a live bounty's deadline back. An open bounty only needs the
+/// creator, a submitted one also needs the submitting agent to co-sign so the
+/// terms it worked under are not changed behind its back.
+#[derive(Accounts)]
+pub struct ExtendDeadline<'info> {
+    pub creator: Signer<'info>,
+
+    // required once the bounty is Submitted
+    pub agent: Option<Signer<'info>>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = matches!(bounty.status, BountyStatus::Open | BountyStatus::Submitted)
+            @ BountyForgeError::DeadlineExtensionLocked
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
+
+impl<'info> ExtendDeadline<'info> {
+    pub fn extend_deadline(&mut self, deadline: i64) -> Result<()> {
+        // 1. validating the new deadline only moves later
+        require!(
+            deadline > self.bounty.deadline,
+            BountyForgeError::DeadlineNotExtended
+        );
+
+        // 2. requiring the submitting agent's consent on submitted bounties
+        if self.bounty.status == BountyStatus::Submitted {
+            let agent = self
+                .agent
+                .as_ref()
+                .ok_or(BountyForgeError::AgentConsentRequired)?;
+            require!(
+                self.bounty.agent == Some(agent.key()),
+                BountyForgeError::AgentMismatch
+            );
+        }
+
+        // 3. updating bounty deadline
+        let previous_deadline = self.bounty.deadline;
+        self.bounty.deadline = deadline;
+
+        emit!(DeadlineExtended {
+            bounty_id: self.bounty.id,
+            previous_deadline,
+            new_deadline: deadline,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,332 @@
+use anchor_lang::prelude::*;
+use anchor_spl::memo::{build_memo, BuildMemo, Memo};
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::constants::ANCHOR_DISCRIMINATOR;
+use crate::errors::BountyForgeError;
+use crate::escrow::{pay_referral, release_submission_fees, vest_payout, withdraw_lamports};
+use crate::events::BountySettled;
+use crate::state::{
+    Bounty, BountyHistory, BountyStatus, Config, CreatorProfile, Reputation, RewardKind, Stake,
+    Vesting,
+};
+
+#[derive(Accounts)]
+pub struct FinalizeSettlement<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::PendingSettlement @ BountyForgeError::BountyNotPendingSettlement,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        mut,
+        seeds = [b"creator", bounty.creator.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        constraint = reputation.agent == agent.key() @ BountyForgeError::ReputationOwnerMismatch
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    #[account(
+        mut,
+        constraint = reputation.referrer == Some(referrer_reputation.agent) @ BountyForgeError::ReferrerMismatch
+    )]
+    pub referrer_reputation: Option<Account<'info, Reputation>>,
+
+    /// CHECK: Agent receiving the reward
+    #[account(mut)]
+    pub agent: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = stake.bump
+    )]
+    pub stake: Account<'info, Stake>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch,
+        constraint = bounty_token_account.amount >= bounty.unreleased_reward() @ BountyForgeError::InsufficientEscrow
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury,
+        constraint = treasury_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Treasury wallet receiving the fee on SOL bounties
+    #[account(mut, address = config.treasury)]
+    pub treasury: Option<AccountInfo<'info>>,
+
+    /// CHECK: Reward mint recorded on the bounty, omitted for SOL bounties
+    #[account(address = bounty.reward_mint @ BountyForgeError::RewardMintMismatch)]
+    pub reward_mint: Option<AccountInfo<'info>>,
+
+    /// Pays the vesting account's rent, required with `vesting`
+    #[account(mut)]
+    pub payer: Option<Signer<'info>>,
+
+    /// Required when the bounty was posted with `vesting_seconds`
+    #[account(
+        init,
+        payer = payer,
+        space = ANCHOR_DISCRIMINATOR + Vesting::INIT_SPACE,
+        seeds = [b"vesting", bounty.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vesting: Option<Account<'info, Vesting>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Required when `config.enable_memos` is set
+    pub memo_program: Option<Program<'info, Memo>>,
+
+    pub system_program: Option<Program<'info, System>>,
+}
+
+impl<'info> FinalizeSettlement<'info> {
+    pub fn finalize_settlement(&mut self, bumps: &FinalizeSettlementBumps) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now >= self.bounty.settle_unlock_at,
+            BountyForgeError::SettlementLocked
+        );
+        require!(
+            self.bounty.all_milestones_released(),
+            BountyForgeError::MilestonesOutstanding
+        );
+
+        // 1. splitting what milestones have not paid out into platform fee and agent payout
+        let outstanding = self
+            .bounty
+            .reward
+            .checked_sub(self.bounty.released_reward())
+            .ok_or(BountyForgeError::MathOverflow)?;
+        let fee = self
+            .config
+            .fee_for_tier(outstanding, self.bounty.tier_at_submit)?;
+        // a prompt settlement hands part of the fee to the agent instead of the treasury
+        let bonus = if self.bounty.settled_promptly {
+            self.config.prompt_settle_bonus(fee)?
+        } else {
+            0
+        };
+        let fee = fee - bonus;
+        let payout = outstanding
+            .checked_sub(fee)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        self.bounty.check_min_payout(payout)?;
+
+        // 2. paying the referrer its cut of the fee and the treasury the rest, the payout
+        // stays in escrow until claim_reward, or claim_vested when the payout vests
+        let referral = pay_referral(
+            &self.bounty,
+            &self.config,
+            &self.reputation,
+            self.referrer_reputation.as_mut(),
+            fee,
+        )?;
+        let treasury_fee = fee
+            .checked_sub(referral)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        match self.bounty.reward_kind {
+            RewardKind::Spl => {
+                let balance = self.escrow_balance()?;
+                self.pay_out_tokens(treasury_fee)?;
+                self.verify_escrow_balance(balance, treasury_fee)?;
+            }
+            RewardKind::Sol => self.pay_out_lamports(treasury_fee)?,
+        }
+        self.config.sub_escrowed(fee)?;
+        if self.bounty.vesting_seconds > 0 {
+            vest_payout(
+                &mut self.bounty,
+                self.vesting.as_mut(),
+                bumps.vesting,
+                self.agent.key(),
+                payout,
+                now,
+            )?;
+        } else {
+            self.bounty.claimable_amount = payout;
+        }
+
+        if self.config.enable_memos {
+            self.write_memo()?;
+        }
+
+        // 3. returning the agent's stake and handing over the submission fees, the stake
+        // rent comes back on close_submission
+        withdraw_lamports(
+            &self.stake.to_account_info(),
+            &self.agent,
+            self.stake.amount,
+        )?;
+        self.stake.amount = 0;
+        release_submission_fees(&mut self.bounty, &self.agent)?;
+
+        // 4. updating reputation
+        self.reputation
+            .apply_decay(now, self.config.reputation_half_life);
+
+        self.reputation.successful_bounties = self
+            .reputation
+            .successful_bounties
+            .checked_add(1)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+
+        self.reputation.total_earned = self
+            .reputation
+            .total_earned
+            .checked_add(payout)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+
+        self.reputation.score = self
+            .reputation
+            .score
+            .checked_add(self.config.settle_score(self.bounty.difficulty)?)
+            .ok_or(BountyForgeError::ReputationScoreOverflow)?;
+
+        self.reputation.release_submission();
+        self.reputation.refresh_rank_weight();
+
+        // 5. crediting the creator with the whole reward, milestones included
+        self.creator_profile.bounties_settled = self
+            .creator_profile
+            .bounties_settled
+            .checked_add(1)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        self.creator_profile.total_paid = self
+            .creator_profile
+            .total_paid
+            .checked_add(self.bounty.reward)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        // 6. updating bounty status
+        self.bounty.last_rejected_hash = None;
+        self.bounty.transition(BountyStatus::PendingSettlement, BountyStatus::Settled)?;
+        self.history
+            .record(BountyStatus::Settled, now, self.agent.key());
+
+        emit!(BountySettled {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            reward: payout,
+            fee,
+        });
+
+        Ok(())
+    }
+
+    /// Tags the settlement with `bf:settle:<bounty_id>` so exported transaction
+    /// histories can be traced back to the bounty.
+    fn write_memo(&self) -> Result<()> {
+        let memo_program = self
+            .memo_program
+            .as_ref()
+            .ok_or(BountyForgeError::MissingMemoProgram)?;
+
+        let memo = format!("bf:settle:{}", self.bounty.id);
+        let cpi_ctx = CpiContext::new(memo_program.to_account_info(), BuildMemo {});
+
+        build_memo(cpi_ctx, memo.as_bytes())
+    }
+
+    fn pay_out_tokens(&self, fee: u64) -> Result<()> {
+        if fee == 0 {
+            return Ok(());
+        }
+
+        let (Some(bounty_token_account), Some(treasury_token_account)) =
+            (&self.bounty_token_account, &self.treasury_token_account)
+        else {
+            return err!(BountyForgeError::MissingTokenAccount);
+        };
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        // transfering the fee from the vault to treasury token account
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: bounty_token_account.to_account_info(),
+            to: treasury_token_account.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, fee)
+    }
+
+    /// Escrow token balance before the fee CPI.
+    fn escrow_balance(&self) -> Result<u64> {
+        let bounty_token_account = self
+            .bounty_token_account
+            .as_ref()
+            .ok_or(BountyForgeError::MissingTokenAccount)?;
+
+        Ok(bounty_token_account.amount)
+    }
+
+    /// Re-reads the escrow after the fee CPI and checks it lost exactly `fee`,
+    /// so the agent's claimable payout is still fully backed.
+    fn verify_escrow_balance(&mut self, escrow_before: u64, fee: u64) -> Result<()> {
+        let bounty_token_account = self
+            .bounty_token_account
+            .as_mut()
+            .ok_or(BountyForgeError::MissingTokenAccount)?;
+
+        bounty_token_account.reload()?;
+
+        let escrow_expected = escrow_before
+            .checked_sub(fee)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        require!(
+            bounty_token_account.amount == escrow_expected,
+            BountyForgeError::SettlementBalanceMismatch
+        );
+
+        Ok(())
+    }
+
+    fn pay_out_lamports(&self, fee: u64) -> Result<()> {
+        if fee > 0 {
+            let treasury = self
+                .treasury
+                .as_ref()
+                .ok_or(BountyForgeError::MissingTreasuryAccount)?;
+            withdraw_lamports(&self.bounty.to_account_info(), treasury, fee)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::BountyFlagged;
+use crate::state::{Bounty, Config};
+
+/// Quarantines a suspicious bounty while it is investigated. Flagged bounties
+/// take no new commitments or reveals, everything the creator can already
+/// reclaim stays reclaimable.
+#[derive(Accounts)]
+pub struct FlagBounty<'info> {
+    pub moderator: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_moderator(&moderator.key()) @ BountyForgeError::UnauthorizedModerator
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = !bounty.flagged @ BountyForgeError::BountyFlagged
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
+
+impl<'info> FlagBounty<'info> {
+    pub fn flag_bounty(&mut self, reason_hash: [u8; 32]) -> Result<()> {
+        // 1. validating the flag is documented
+        require!(reason_hash != [0; 32], BountyForgeError::InvalidReasonHash);
+
+        // 2. quarantining the bounty
+        self.bounty.flagged = true;
+
+        emit!(BountyFlagged {
+            bounty_id: self.bounty.id,
+            moderator: self.moderator.key(),
+            reason_hash,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::SolutionForfeited;
+use crate::state::{Bounty, BountyHistory, BountyStatus, Config, Reputation, Stake, Submission};
+
+/// Lets the submitting agent withdraw from a bounty they can't complete.
+/// Unlike `reject_solution` the stake is returned rather than slashed, the
+/// withdrawal isn't counted as a failed bounty, and the score penalty is the
+/// smaller `forfeit_penalty`.
+#[derive(Accounts)]
+pub struct ForfeitSubmission<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Submitted @ BountyForgeError::BountyNotSubmitted,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch,
+        constraint = bounty.released_reward() == 0 @ BountyForgeError::MilestonesPartiallyReleased
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        mut,
+        seeds = [b"rep", agent.key().as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"submission", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    // closing hands the staked lamports back along with the rent
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"stake", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = stake.bump
+    )]
+    pub stake: Account<'info, Stake>,
+}
+
+impl<'info> ForfeitSubmission<'info> {
+    pub fn forfeit_submission(&mut self) -> Result<()> {
+        // 1. applying the forfeit penalty to the agent's reputation
+        let penalty = self.config.forfeit_penalty;
+        self.reputation.score = self.reputation.score.saturating_sub(penalty);
+        self.reputation.release_submission();
+        self.bounty.submission_closed();
+        self.reputation.refresh_rank_weight();
+
+        // 2. reopening the bounty for other agents
+        self.bounty.solution_hash = None;
+        self.bounty.revision = 0;
+        self.bounty.agent = None;
+        self.bounty.transition(BountyStatus::Submitted, BountyStatus::Open)?;
+        self.history.record(
+            BountyStatus::Open,
+            Clock::get()?.unix_timestamp,
+            self.agent.key(),
+        );
+
+        emit!(SolutionForfeited {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            penalty,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_BATCH_REPUTATIONS;
+use crate::errors::BountyForgeError;
+use crate::events::{ReputationEntry, ReputationsRead};
+use crate::state::{Config, Reputation};
+
+/// Read-only dump of a page of reputations for leaderboards. Each reputation
+/// is passed in `remaining_accounts` and reported, with its score decayed to
+/// now, in a single `ReputationsRead` event. Meant to be simulated.
+#[derive(Accounts)]
+pub struct GetReputations<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> GetReputations<'info> {
+    pub fn get_reputations(&self, remaining_accounts: &'info [AccountInfo<'info>]) -> Result<()> {
+        require!(
+            (1..=MAX_BATCH_REPUTATIONS).contains(&remaining_accounts.len()),
+            BountyForgeError::InvalidBatchSize
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+
+        let entries = remaining_accounts
+            .iter()
+            .map(|reputation_info| {
+                let reputation = Account::<Reputation>::try_from(reputation_info)?;
+                Ok(ReputationEntry {
+                    agent: reputation.agent,
+                    score: reputation.decayed_score(now, self.config.reputation_half_life),
+                    successful_bounties: reputation.successful_bounties,
+                    failed_bounties: reputation.failed_bounties,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        emit!(ReputationsRead { entries });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::constants::{ANCHOR_DISCRIMINATOR, MAX_WITHDRAW_WHITELIST, REPUTATION_SNAPSHOT_VERSION};
+use crate::errors::BountyForgeError;
+use crate::events::ReputationImported;
+use crate::state::{Config, Reputation, ReputationSnapshot};
+
+/// Seeds an agent's reputation from an exported snapshot during a migration.
+/// Never overwrites a reputation the agent already has.
+#[derive(Accounts)]
+pub struct ImportReputation<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ BountyForgeError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    /// CHECK: Agent the reputation is restored for, only its key is used
+    pub agent: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + Reputation::INIT_SPACE,
+        seeds = [b"rep", agent.key().as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> ImportReputation<'info> {
+    pub fn import_reputation(
+        &mut self,
+        snapshot: ReputationSnapshot,
+        bumps: &ImportReputationBumps,
+    ) -> Result<()> {
+        // 1. validating the snapshot belongs to the agent and its reputation is still empty
+        require!(
+            snapshot.version == REPUTATION_SNAPSHOT_VERSION,
+            BountyForgeError::UnsupportedSnapshotVersion
+        );
+        require_keys_eq!(
+            snapshot.agent,
+            self.agent.key(),
+            BountyForgeError::ReputationOwnerMismatch
+        );
+        require!(
+            self.reputation.agent == Pubkey::default(),
+            BountyForgeError::ReputationAlreadyExists
+        );
+        require!(
+            snapshot.withdraw_whitelist.len() <= MAX_WITHDRAW_WHITELIST,
+            BountyForgeError::WithdrawWhitelistFull
+        );
+
+        // 2. restoring the reputation
+        self.reputation
+            .set_inner(Reputation::from_snapshot(&snapshot, bumps.reputation));
+
+        // 3. backing unclaimed referral earnings, they are paid out of the
+        // reputation account's own lamports
+        if snapshot.referral_earnings > 0 {
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_accounts = system_program::Transfer {
+                from: self.admin.to_account_info(),
+                to: self.reputation.to_account_info(),
+            };
+
+            let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+
+            system_program::transfer(cpi_context, snapshot.referral_earnings)?;
+        }
+
+        emit!(ReputationImported {
+            agent: snapshot.agent,
+            score: snapshot.score,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::errors::BountyForgeError;
+use crate::events::RewardIncreased;
+use crate::state::{Bounty, BountyStatus, Config, RewardKind};
+
+#[derive(Accounts)]
+pub struct IncreaseReward<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key(),
+        constraint = creator_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> IncreaseReward<'info> {
+    pub fn increase_reward(&mut self, amount: u64) -> Result<()> {
+        // 1. updating the reward before escrowing so overflow aborts early
+        self.bounty.reward = self
+            .bounty
+            .reward
+            .checked_add(amount)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        require!(
+            self.bounty.within_reward_cap(),
+            BountyForgeError::RewardAboveMaximum
+        );
+
+        // nothing is released while the bounty is open, so the top-up lands on
+        // the last milestone and the amounts still add up to the reward
+        if let Some(milestone) = self.bounty.milestones.last_mut() {
+            milestone.amount = milestone
+                .amount
+                .checked_add(amount)
+                .ok_or(BountyForgeError::MathOverflow)?;
+        }
+
+        // 2. escrowing the additional amount
+        match self.bounty.reward_kind {
+            RewardKind::Spl => self.escrow_tokens(amount)?,
+            RewardKind::Sol => self.escrow_lamports(amount)?,
+        }
+        self.config.add_escrowed(amount)?;
+
+        emit!(RewardIncreased {
+            bounty_id: self.bounty.id,
+            amount,
+            reward: self.bounty.reward,
+        });
+
+        Ok(())
+    }
+
+    fn escrow_tokens(&self, amount: u64) -> Result<()> {
+        let (Some(creator_token_account), Some(bounty_token_account)) =
+            (&self.creator_token_account, &self.bounty_token_account)
+        else {
+            return err!(BountyForgeError::MissingTokenAccount);
+        };
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: creator_token_account.to_account_info(),
+            to: bounty_token_account.to_account_info(),
+            authority: self.creator.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+
+        transfer(cpi_context, amount)
+    }
+
+    fn escrow_lamports(&self, amount: u64) -> Result<()> {
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_accounts = system_program::Transfer {
+            from: self.creator.to_account_info(),
+            to: self.bounty.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+
+        system_program::transfer(cpi_context, amount)
+    }
+}
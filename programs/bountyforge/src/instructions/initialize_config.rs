@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::ANCHOR_DISCRIMINATOR, state::Config};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeConfigArgs {
+    pub verifier: Pubkey,
+    pub arbiter: Pubkey,
+    pub treasury: Pubkey,
+    pub fee_bps: u16,
+    pub fee_bps_for_tier: [u16; 4],
+    pub referral_bps: u16,
+    pub dispute_window: i64,
+    pub reputation_half_life: i64,
+    pub stake_amount: u64,
+    pub min_reward: u64,
+    pub max_reward: u64,
+    pub reveal_delay: i64,
+    pub submission_timeout: i64,
+    pub grace_period: i64,
+    pub submission_cooldown: i64,
+    pub min_attestation_age: i64,
+    pub score_on_submit: u64,
+    pub score_on_settle: u64,
+    pub submission_fee: u64,
+    pub rejection_penalty: u64,
+    pub rejection_penalty_step: u64,
+    pub max_rejection_penalty: u64,
+    pub forfeit_penalty: u64,
+    pub max_rejections: u8,
+    pub enable_memos: bool,
+    pub max_concurrent_submissions: u16,
+    pub max_price_age: i64,
+    pub max_price_conf_bps: u16,
+    pub prompt_settle_window: i64,
+    pub prompt_settle_bonus_bps: u16,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = ANCHOR_DISCRIMINATOR + Config::INIT_SPACE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeConfig<'info> {
+    pub fn initialize_config(
+        &mut self,
+        args: InitializeConfigArgs,
+        bumps: &InitializeConfigBumps,
+    ) -> Result<()> {
+        let InitializeConfigArgs {
+            verifier,
+            arbiter,
+            treasury,
+            fee_bps,
+            fee_bps_for_tier,
+            referral_bps,
+            dispute_window,
+            reputation_half_life,
+            stake_amount,
+            min_reward,
+            max_reward,
+            reveal_delay,
+            submission_timeout,
+            grace_period,
+            submission_cooldown,
+            min_attestation_age,
+            score_on_submit,
+            score_on_settle,
+            submission_fee,
+            rejection_penalty,
+            rejection_penalty_step,
+            max_rejection_penalty,
+            forfeit_penalty,
+            max_rejections,
+            enable_memos,
+            max_concurrent_submissions,
+            max_price_age,
+            max_price_conf_bps,
+            prompt_settle_window,
+            prompt_settle_bonus_bps,
+        } = args;
+
+        self.config.set_inner(Config {
+            admin: self.admin.key(),
+            verifier,
+            arbiter,
+            treasury,
+            fee_bps,
+            fee_bps_for_tier,
+            referral_bps,
+            dispute_window,
+            reputation_half_life,
+            stake_amount,
+            min_reward,
+            max_reward,
+            reveal_delay,
+            submission_timeout,
+            grace_period,
+            submission_cooldown,
+            min_attestation_age,
+            score_on_submit,
+            score_on_settle,
+            submission_fee,
+            rejection_penalty,
+            rejection_penalty_step,
+            max_rejection_penalty,
+            forfeit_penalty,
+            max_rejections,
+            enable_memos,
+            max_concurrent_submissions,
+            max_price_age,
+            max_price_conf_bps,
+            prompt_settle_window,
+            prompt_settle_bonus_bps,
+            paused: false,
+            total_escrowed: 0,
+            bounty_counter: 0,
+            moderators: vec![],
+            bump: bumps.config,
+        });
+
+        self.config.validate()
+    }
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::ANCHOR_DISCRIMINATOR;
+use crate::errors::BountyForgeError;
+use crate::events::ReputationInitialized;
+use crate::state::Reputation;
+
+/// Creates an agent's reputation with clean defaults. Runs once per agent,
+/// before its first reveal.
+#[derive(Accounts)]
+pub struct InitializeReputation<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        init,
+        payer = agent,
+        space = ANCHOR_DISCRIMINATOR + Reputation::INIT_SPACE,
+        seeds = [b"rep", agent.key().as_ref()],
+        bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    // recorded as the agent's referrer, cannot be changed afterwards
+    #[account(
+        constraint = referrer_reputation.agent != agent.key() @ BountyForgeError::SelfReferral
+    )]
+    pub referrer_reputation: Option<Account<'info, Reputation>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> InitializeReputation<'info> {
+    pub fn initialize_reputation(&mut self, bumps: &InitializeReputationBumps) -> Result<()> {
+        let referrer = self
+            .referrer_reputation
+            .as_ref()
+            .map(|referrer| referrer.agent);
+
+        self.reputation.set_inner(Reputation {
+            agent: self.agent.key(),
+            score: 0,
+            successful_bounties: 0,
+            failed_bounties: 0,
+            total_earned: 0,
+            last_active: Clock::get()?.unix_timestamp,
+            badges_minted: 0,
+            referrer,
+            referral_earnings: 0,
+            rank_weight: 0,
+            last_submission_at: 0,
+            active_submissions: 0,
+            locked_until: 0,
+            withdraw_whitelist: Vec::new(),
+            bump: bumps.reputation,
+        });
+
+        emit!(ReputationInitialized {
+            agent: self.agent.key(),
+            referrer,
+        });
+
+        Ok(())
+    }
+}
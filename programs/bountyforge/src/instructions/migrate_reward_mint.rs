@@ -0,0 +1,176 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{
+    close_account, transfer, CloseAccount, TokenAccount, TokenInterface, Transfer,
+};
+
+use crate::errors::BountyForgeError;
+use crate::events::RewardMintMigrated;
+use crate::mint::ensure_supported_mint;
+use crate::state::{Bounty, BountyStatus, Config, RewardKind};
+
+/// Swaps the reward token of an open token bounty nobody has submitted to.
+/// The old escrow is emptied back to the creator and closed, and `new_reward`
+/// of the new mint is escrowed in the vault's associated token account for
+/// it, which must already exist. Milestones keep their share of the reward.
+#[derive(Accounts)]
+pub struct MigrateRewardMint<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen,
+        constraint = bounty.submission_count == 0 @ BountyForgeError::BountyHasSubmissions,
+        constraint = bounty.contributed == 0 @ BountyForgeError::BountyHasContributions,
+        constraint = bounty.reward_kind == RewardKind::Spl @ BountyForgeError::NotTokenBounty
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token accounts, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key(),
+        constraint = creator_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: New reward mint, checked by `ensure_supported_mint`
+    #[account(
+        constraint = new_reward_mint.key() != bounty.reward_mint @ BountyForgeError::RewardMintUnchanged
+    )]
+    pub new_reward_mint: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = new_creator_token_account.owner == creator.key(),
+        constraint = new_creator_token_account.mint == new_reward_mint.key() @ BountyForgeError::RewardMintMismatch
+    )]
+    pub new_creator_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = new_bounty_token_account.mint == new_reward_mint.key() @ BountyForgeError::RewardMintMismatch
+    )]
+    pub new_bounty_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Token program of the old reward mint
+    pub token_program: Interface<'info, TokenInterface>,
+
+    /// Token program of the new reward mint
+    pub new_token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> MigrateRewardMint<'info> {
+    pub fn migrate_reward_mint(&mut self, new_reward: u64) -> Result<()> {
+        // 1. validating the new reward like post_bounty would
+        require!(
+            new_reward >= self.config.min_reward,
+            BountyForgeError::RewardBelowMinimum
+        );
+        require!(
+            new_reward <= self.config.max_reward,
+            BountyForgeError::RewardAboveMaximum
+        );
+        let new_decimals = ensure_supported_mint(&self.new_reward_mint)?;
+        require!(
+            self.new_bounty_token_account.key()
+                == get_associated_token_address_with_program_id(
+                    &self.vault.key(),
+                    &self.new_reward_mint.key(),
+                    &self.new_token_program.key(),
+                ),
+            BountyForgeError::NonCanonicalTokenAccount
+        );
+
+        // 2. refunding the old reward and closing its escrow
+        let old_mint = self.bounty.reward_mint;
+        let old_reward = self.bounty.reward;
+        self.refund_old_escrow()?;
+        self.config.sub_escrowed(old_reward)?;
+
+        // 3. escrowing the new reward
+        self.escrow_new_reward(new_reward)?;
+        self.config.add_escrowed(new_reward)?;
+
+        // 4. repricing the bounty in the new mint
+        self.bounty.reward_mint = self.new_reward_mint.key();
+        self.bounty.reward_decimals = new_decimals;
+        self.bounty.reprice(new_reward)?;
+        require!(
+            self.bounty.within_reward_cap(),
+            BountyForgeError::RewardAboveMaximum
+        );
+        // the USD price was taken in the old mint
+        self.bounty.reward_usd_at_creation = 0;
+        self.bounty.price_feed_id = [0; 32];
+
+        emit!(RewardMintMigrated {
+            bounty_id: self.bounty.id,
+            old_mint,
+            new_mint: self.bounty.reward_mint,
+            old_reward,
+            new_reward,
+        });
+
+        Ok(())
+    }
+
+    /// Sends the whole old escrow balance, stray tokens included, back to the
+    /// creator so the account can be closed.
+    fn refund_old_escrow(&self) -> Result<()> {
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        // transfering the old reward from the vault back to the creator
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: self.bounty_token_account.to_account_info(),
+            to: self.creator_token_account.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, self.bounty_token_account.amount)?;
+
+        // closing the emptied escrow, its rent goes back to the creator
+        let cpi_accounts = CloseAccount {
+            account: self.bounty_token_account.to_account_info(),
+            destination: self.creator.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        close_account(cpi_ctx)
+    }
+
+    fn escrow_new_reward(&self, amount: u64) -> Result<()> {
+        let cpi_program = self.new_token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: self.new_creator_token_account.to_account_info(),
+            to: self.new_bounty_token_account.to_account_info(),
+            authority: self.creator.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+
+        transfer(cpi_context, amount)
+    }
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{
+    freeze_account, mint_to, FreezeAccount, Mint, MintTo, Token, TokenAccount,
+};
+
+use crate::errors::BountyForgeError;
+use crate::events::ReputationBadgeMinted;
+use crate::state::{Config, Reputation};
+
+#[derive(Accounts)]
+pub struct MintReputationBadge<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"rep", agent.key().as_ref()],
+        bump = reputation.bump,
+        constraint = reputation.agent == agent.key() @ BountyForgeError::ReputationOwnerMismatch
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    // one mint per agent and badge level, so a level can never be minted twice
+    #[account(
+        init,
+        payer = agent,
+        seeds = [b"badge", agent.key().as_ref(), &[reputation.badges_minted]],
+        bump,
+        mint::decimals = 0,
+        mint::authority = config,
+        mint::freeze_authority = config
+    )]
+    pub badge_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = agent,
+        associated_token::mint = badge_mint,
+        associated_token::authority = agent
+    )]
+    pub agent_badge_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MintReputationBadge<'info> {
+    pub fn mint_reputation_badge(&mut self) -> Result<()> {
+        // 1. validating the next badge has been earned
+        let threshold = self
+            .reputation
+            .next_badge_threshold()
+            .ok_or(BountyForgeError::AllBadgesMinted)?;
+        require!(
+            self.reputation.successful_bounties >= threshold,
+            BountyForgeError::BadgeThresholdNotReached
+        );
+
+        // 2. minting the badge and freezing it in the agent's wallet so it stays soulbound
+        let signer_seeds: &[&[&[u8]]] = &[&[b"config", &[self.config.bump]]];
+
+        let cpi_accounts = MintTo {
+            mint: self.badge_mint.to_account_info(),
+            to: self.agent_badge_account.to_account_info(),
+            authority: self.config.to_account_info(),
+        };
+        let cpi_context = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        mint_to(cpi_context, 1)?;
+
+        let cpi_accounts = FreezeAccount {
+            account: self.agent_badge_account.to_account_info(),
+            mint: self.badge_mint.to_account_info(),
+            authority: self.config.to_account_info(),
+        };
+        let cpi_context = CpiContext::new_with_signer(
+            self.token_program.to_account_info(),
+            cpi_accounts,
+            signer_seeds,
+        );
+        freeze_account(cpi_context)?;
+
+        // 3. recording the badge
+        let level = self.reputation.badges_minted;
+        self.reputation.badges_minted =
+            level.checked_add(1).ok_or(BountyForgeError::MathOverflow)?;
+
+        emit!(ReputationBadgeMinted {
+            agent: self.agent.key(),
+            badge_mint: self.badge_mint.key(),
+            level,
+            successful_bounties: self.reputation.successful_bounties,
+        });
+
+        Ok(())
+    }
+}
@@ -1,9 +1,113 @@
+pub mod add_withdrawal_address;
+pub mod adjust_reputation;
 pub mod attest_solution;
+pub mod auto_settle;
+pub mod batch_settle;
+pub mod batch_verify;
+pub mod can_submit;
+pub mod cancel_bounty;
+pub mod claim_referral_rewards;
+pub mod claim_reward;
+pub mod claim_vested;
+pub mod cleanup_bounty;
+pub mod close_attestation;
+pub mod close_submission;
+pub mod commit_solution;
+pub mod contribute_to_bounty;
+pub mod decay_reputation;
+pub mod expire_submitted;
+pub mod export_reputation;
+pub mod extend_deadline;
+pub mod finalize_settlement;
+pub mod flag_bounty;
+pub mod forfeit_submission;
+pub mod get_reputations;
+pub mod import_reputation;
+pub mod increase_reward;
+pub mod initialize_config;
+pub mod initialize_reputation;
+pub mod migrate_reward_mint;
+pub mod mint_reputation_badge;
 pub mod post_bounty;
+pub mod raise_dispute;
+pub mod reclaim_expired;
+pub mod register_agent;
+pub mod reject_solution;
+pub mod release_milestone;
+pub mod remove_withdrawal_address;
+pub mod reopen_bounty;
+pub mod rescue_tokens;
+pub mod resolve_dispute;
+pub mod reveal_solution;
+pub mod revise_solution;
+pub mod revoke_attestation;
 pub mod settle_bounty;
-pub mod submit_solution;
+pub mod settle_multi;
+pub mod settle_partial;
+pub mod split_bounty;
+pub mod stake_reputation;
+pub mod sweep_escrow;
+pub mod transfer_bounty_ownership;
+pub mod unflag_bounty;
+pub mod unstake_reputation;
+pub mod update_agent;
+pub mod update_config;
+pub mod verify_attestation;
+pub mod withdraw_contribution;
 
+pub use add_withdrawal_address::*;
+pub use adjust_reputation::*;
 pub use attest_solution::*;
+pub use auto_settle::*;
+pub use batch_settle::*;
+pub use batch_verify::*;
+pub use can_submit::*;
+pub use cancel_bounty::*;
+pub use claim_referral_rewards::*;
+pub use claim_reward::*;
+pub use claim_vested::*;
+pub use cleanup_bounty::*;
+pub use close_attestation::*;
+pub use close_submission::*;
+pub use commit_solution::*;
+pub use contribute_to_bounty::*;
+pub use decay_reputation::*;
+pub use expire_submitted::*;
+pub use export_reputation::*;
+pub use extend_deadline::*;
+pub use finalize_settlement::*;
+pub use flag_bounty::*;
+pub use forfeit_submission::*;
+pub use get_reputations::*;
+pub use import_reputation::*;
+pub use increase_reward::*;
+pub use initialize_config::*;
+pub use initialize_reputation::*;
+pub use migrate_reward_mint::*;
+pub use mint_reputation_badge::*;
 pub use post_bounty::*;
+pub use raise_dispute::*;
+pub use reclaim_expired::*;
+pub use register_agent::*;
+pub use reject_solution::*;
+pub use release_milestone::*;
+pub use remove_withdrawal_address::*;
+pub use reopen_bounty::*;
+pub use rescue_tokens::*;
+pub use resolve_dispute::*;
+pub use reveal_solution::*;
+pub use revise_solution::*;
+pub use revoke_attestation::*;
 pub use settle_bounty::*;
-pub use submit_solution::*;
+pub use settle_multi::*;
+pub use settle_partial::*;
+pub use split_bounty::*;
+pub use stake_reputation::*;
+pub use sweep_escrow::*;
+pub use transfer_bounty_ownership::*;
+pub use unflag_bounty::*;
+pub use unstake_reputation::*;
+pub use update_agent::*;
+pub use update_config::*;
+pub use verify_attestation::*;
+pub use withdraw_contribution::*;
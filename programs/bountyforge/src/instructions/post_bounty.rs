@@ -1,42 +1,108 @@
-use crate::constants::ANCHOR_DISCRIMINATOR;
-use crate::state::{Bounty, BountyStatus};
+use crate::constants::{
+    ANCHOR_DISCRIMINATOR, MAX_DESCRIPTION_URI_LEN, MAX_DIFFICULTY, MAX_ENCRYPTION_SCHEME,
+    MAX_MILESTONES, MAX_REQUIRED_ATTESTATIONS, MAX_TAGS, MAX_TITLE_LEN, MAX_VESTING_SECONDS,
+    MIN_DIFFICULTY, SOL_DECIMALS,
+};
+use crate::errors::BountyForgeError;
+use crate::events::BountyCreated;
+use crate::mint::ensure_supported_mint;
+use crate::oracle::PriceFeed;
+use crate::state::{
+    Bounty, BountyHistory, BountyStatus, Config, CreatorProfile, Milestone, ReputationTier,
+    RewardKind,
+};
 use anchor_lang::prelude::*;
-use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
-use anchor_spl::associated_token::{AssociatedToken, get_associated_token_address};
+use anchor_lang::system_program;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+use anchor_spl::associated_token::{AssociatedToken, get_associated_token_address_with_program_id};
 
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PostBountyArgs {
+    pub description: String,
+    pub title: String,
+    pub description_uri: String,
+    pub tags: Vec<u32>,
+    pub reward: u64,
+    pub deadline: i64,
+    pub competition: bool,
+    pub min_tier: ReputationTier,
+    pub min_reputation_score: u64,
+    pub reward_kind: RewardKind,
+    pub milestones: Vec<u64>, // amounts, empty to pay the whole reward on settlement
+    pub whitelist_root: [u8; 32],
+    pub difficulty: u8,                  // 1-5, score an agent gains by submitting
+    pub settle_delegate: Option<Pubkey>, // may call settle_bounty on the creator's behalf
+    pub criteria_hash: [u8; 32],         // hash of the off-chain acceptance criteria
+    pub category_bits: u64,              // one bit per category the bounty belongs to
+    pub max_reward_cap: u64,             // ceiling for top-ups, 0 for none
+    pub required_attestations: u8,       // distinct verified attesters a reveal needs, 1 or more
+    pub vesting_seconds: i64,            // releases the payout linearly over this long, 0 pays at once
+    pub encryption_scheme: u8,           // how solutions are encrypted for the creator, 0 for public delivery
+}
+
+/// Bounty ids come from `config.bounty_counter` rather than the caller. The
+/// PDA seeds are still `[b"bounty", id]`, so bounties posted with a caller
+/// chosen id keep their addresses; on such a deployment raise the counter
+/// past the highest existing id with update_config before posting again.
 #[derive(Accounts)]
-#[instruction(bounty_id: u64)]
 pub struct PostBounty<'info> {
     #[account(mut)]
     pub creator: Signer<'info>,
 
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
     #[account(
         init,
         payer = creator,
         space = ANCHOR_DISCRIMINATOR + Bounty::INIT_SPACE,
-        seeds = [b"bounty", bounty_id.to_le_bytes().as_ref()],
+        seeds = [b"bounty", config.bounty_counter.to_le_bytes().as_ref()],
         bump
     )]
     pub bounty: Account<'info, Bounty>,
 
-    /// CHECK: USDC mint address (validated by token account)
-    pub usdc_mint: AccountInfo<'info>,
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", config.bounty_counter.to_le_bytes().as_ref()], bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR + BountyHistory::INIT_SPACE,
+        seeds = [b"history", config.bounty_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR + CreatorProfile::INIT_SPACE,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    /// CHECK: Reward mint (checked by `ensure_supported_mint`), omitted for SOL bounties
+    pub reward_mint: Option<AccountInfo<'info>>,
 
     #[account(
         mut,
-        constraint = creator_token_account.mint == usdc_mint.key(),
         constraint = creator_token_account.owner == creator.key()
     )]
-    pub creator_token_account: Account<'info, TokenAccount>,
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
     #[account(
         mut,
-        constraint = bounty_token_account.mint == usdc_mint.key(),
-        constraint = bounty_token_account.owner == bounty.key()
+        constraint = bounty_token_account.owner == vault.key()
     )]
-    pub bounty_token_account: Account<'info, TokenAccount>,
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
 
-    pub token_program: Program<'info, Token>,
+    /// CHECK: Optional Pyth price update for the reward token, checked by
+    /// `PriceFeed::load`, prices `reward_usd_at_creation` for dashboards
+    pub price_feed: Option<AccountInfo<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
 }
@@ -44,40 +110,275 @@ pub struct PostBounty<'info> {
 impl<'info> PostBounty<'info> {
     pub fn post_bounty(
         &mut self,
-        bounty_id: u64,
-        description: String,
-        reward: u64,
+        args: PostBountyArgs,
         bumps: &PostBountyBumps,
     ) -> Result<()> {
+        let PostBountyArgs {
+            description,
+            title,
+            description_uri,
+            tags,
+            reward,
+            deadline,
+            competition,
+            min_tier,
+            min_reputation_score,
+            reward_kind,
+            milestones,
+            whitelist_root,
+            difficulty,
+            settle_delegate,
+            criteria_hash,
+            category_bits,
+            max_reward_cap,
+            required_attestations,
+            vesting_seconds,
+            encryption_scheme,
+        } = args;
+
+        require!(
+            deadline > Clock::get()?.unix_timestamp,
+            BountyForgeError::InvalidDeadline
+        );
+        require!(
+            title.len() <= MAX_TITLE_LEN
+                && description_uri.len() <= MAX_DESCRIPTION_URI_LEN
+                && tags.len() <= MAX_TAGS,
+            BountyForgeError::MetadataTooLong
+        );
+        require!(
+            reward >= self.config.min_reward,
+            BountyForgeError::RewardBelowMinimum
+        );
+        require!(
+            reward <= self.config.max_reward,
+            BountyForgeError::RewardAboveMaximum
+        );
+        require!(
+            max_reward_cap == 0 || reward <= max_reward_cap,
+            BountyForgeError::RewardAboveMaximum
+        );
+        require!(
+            (MIN_DIFFICULTY..=MAX_DIFFICULTY).contains(&difficulty),
+            BountyForgeError::DifficultyOutOfRange
+        );
+        require!(
+            criteria_hash != [0; 32],
+            BountyForgeError::InvalidCriteriaHash
+        );
+        require!(
+            (1..=MAX_REQUIRED_ATTESTATIONS).contains(&required_attestations),
+            BountyForgeError::InvalidAttestationQuorum
+        );
+        require!(
+            (0..=MAX_VESTING_SECONDS).contains(&vesting_seconds),
+            BountyForgeError::InvalidVestingPeriod
+        );
+        // a vesting payout is released on one schedule, milestones would pay around it
+        require!(
+            vesting_seconds == 0 || milestones.is_empty(),
+            BountyForgeError::VestingNotSupported
+        );
+        require!(
+            encryption_scheme <= MAX_ENCRYPTION_SCHEME,
+            BountyForgeError::InvalidEncryptionScheme
+        );
+        require!(
+            milestones.len() <= MAX_MILESTONES,
+            BountyForgeError::TooManyMilestones
+        );
+        if !milestones.is_empty() {
+            let total = milestones
+                .iter()
+                .try_fold(0u64, |total, amount| total.checked_add(*amount))
+                .ok_or(BountyForgeError::MathOverflow)?;
+            require!(total == reward, BountyForgeError::MilestoneSumMismatch);
+        }
+
+        let (reward_mint, reward_decimals) = match reward_kind {
+            RewardKind::Spl => {
+                let reward_mint = self
+                    .reward_mint
+                    .as_ref()
+                    .ok_or(BountyForgeError::MissingTokenAccount)?;
+                let decimals = ensure_supported_mint(reward_mint)?;
+                (reward_mint.key(), decimals)
+            }
+            RewardKind::Sol => (Pubkey::default(), SOL_DECIMALS),
+        };
+
+        // pricing the reward in USD when a feed is supplied, display only
+        let (reward_usd_at_creation, price_feed_id) = match &self.price_feed {
+            Some(price_feed) => {
+                let feed = PriceFeed::load(price_feed)?;
+                feed.check(
+                    Clock::get()?.unix_timestamp,
+                    self.config.max_price_age,
+                    self.config.max_price_conf_bps,
+                )?;
+                (feed.usd_value(reward, reward_decimals)?, feed.feed_id)
+            }
+            None => (0, [0; 32]),
+        };
+
+        // assigning the next id, the bounty and history PDAs were derived from it
+        let bounty_id = self.config.bounty_counter;
+        self.config.bounty_counter = bounty_id
+            .checked_add(1)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
         // 1. init bounty account
         self.bounty.set_inner(Bounty {
             id: bounty_id,
             description,
+            title,
+            description_uri,
+            tags,
             reward,
+            reward_kind,
+            reward_mint,
+            reward_decimals,
             solution_hash: None,
+            solution_kind: 0,
+            solution_size: 0,
+            last_rejected_hash: None,
+            agent: None,
+            revision: 0,
+            rejection_count: 0,
+            submission_count: 0,
+            submission_fees: 0,
+            open_submissions: 0,
             status: BountyStatus::Open,
             creator: self.creator.key(),
+            deadline,
+            competition,
+            settle_unlock_at: 0,
+            min_tier,
+            min_reputation_score,
+            whitelist_root,
+            difficulty,
+            milestones: milestones
+                .into_iter()
+                .map(|amount| Milestone {
+                    amount,
+                    released: false,
+                })
+                .collect(),
+            contributed: 0,
+            settle_delegate,
+            criteria_hash,
+            category_bits,
+            max_reward_cap,
+            claimable_amount: 0,
+            reputation_score_at_submit: 0,
+            tier_at_submit: ReputationTier::Novice,
+            min_payout: 0,
+            submitted_at: 0,
+            settled_promptly: false,
+            required_attestations,
+            reward_usd_at_creation,
+            price_feed_id,
+            vesting_seconds,
+            encryption_scheme,
+            delivery_pubkey: Pubkey::default(),
+            flagged: false,
+            vault_bump: bumps.vault,
             bump: bumps.bounty,
         });
 
-        // 2. Create associated token account for bounty PDA if it doesn't exist
-        let expected_ata = get_associated_token_address(&self.bounty.key(), &self.usdc_mint.key());
-        if self.bounty_token_account.key() != expected_ata {
+        // 2. opening the bounty's history with its creation
+        self.history.set_inner(BountyHistory {
+            bounty_id,
+            entries: Vec::new(),
+            total_recorded: 0,
+            bump: bumps.history,
+        });
+        self.history.record(
+            BountyStatus::Open,
+            Clock::get()?.unix_timestamp,
+            self.creator.key(),
+        );
+
+        // 3. recording the bounty on the creator's profile
+        if self.creator_profile.creator == Pubkey::default() {
+            self.creator_profile.set_inner(CreatorProfile {
+                creator: self.creator.key(),
+                bounties_created: 0,
+                bounties_settled: 0,
+                bounties_cancelled: 0,
+                total_paid: 0,
+                bump: bumps.creator_profile,
+            });
+        }
+
+        self.creator_profile.bounties_created = self
+            .creator_profile
+            .bounties_created
+            .checked_add(1)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        // 4. escrowing the reward
+        match reward_kind {
+            RewardKind::Spl => self.escrow_tokens(reward_mint, reward)?,
+            RewardKind::Sol => self.escrow_lamports(reward)?,
+        }
+        self.config.add_escrowed(reward)?;
+
+        emit!(BountyCreated {
+            bounty_id,
+            creator: self.creator.key(),
+            reward,
+            deadline,
+            category_bits,
+        });
+
+        Ok(())
+    }
+    fn escrow_tokens(&self, reward_mint: Pubkey, reward: u64) -> Result<()> {
+        let (Some(creator_token_account), Some(bounty_token_account)) =
+            (&self.creator_token_account, &self.bounty_token_account)
+        else {
+            return err!(BountyForgeError::MissingTokenAccount);
+        };
+        require_keys_eq!(
+            creator_token_account.mint,
+            reward_mint,
+            BountyForgeError::RewardMintMismatch
+        );
+
+        // the escrow must be the vault's associated token account
+        let expected_ata = get_associated_token_address_with_program_id(
+            &self.vault.key(),
+            &reward_mint,
+            &self.token_program.key(),
+        );
+        if bounty_token_account.key() != expected_ata {
             return Err(anchor_lang::error!(anchor_lang::error::ErrorCode::ConstraintTokenMint));
         }
 
-        // 3. transfering USDC from creator to bounty PDA token account (escrow)
+        // transfering reward tokens from creator to the vault's token account (escrow)
         let cpi_program = self.token_program.to_account_info();
         let cpi_accounts = Transfer {
-            from: self.creator_token_account.to_account_info(),
-            to: self.bounty_token_account.to_account_info(),
+            from: creator_token_account.to_account_info(),
+            to: bounty_token_account.to_account_info(),
             authority: self.creator.to_account_info(),
         };
 
         let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
 
-        transfer(cpi_context, reward)?;
+        transfer(cpi_context, reward)
+    }
 
-        Ok(())
+    fn escrow_lamports(&self, reward: u64) -> Result<()> {
+        // transfering lamports from creator to the bounty PDA on top of its rent
+        let cpi_program = self.system_program.to_account_info();
+        let cpi_accounts = system_program::Transfer {
+            from: self.creator.to_account_info(),
+            to: self.bounty.to_account_info(),
+        };
+
+        let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+
+        system_program::transfer(cpi_context, reward)
     }
 }
@@ -0,0 +1,46 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::DisputeRaised;
+use crate::state::{Bounty, BountyHistory, BountyStatus};
+
+#[derive(Accounts)]
+pub struct RaiseDispute<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::PendingSettlement @ BountyForgeError::BountyNotPendingSettlement,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+}
+
+impl<'info> RaiseDispute<'info> {
+    pub fn raise_dispute(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            now < self.bounty.settle_unlock_at,
+            BountyForgeError::DisputeWindowClosed
+        );
+
+        // finalization stays frozen until an arbiter resolves the dispute
+        self.bounty.transition(BountyStatus::PendingSettlement, BountyStatus::Disputed)?;
+        self.history
+            .record(BountyStatus::Disputed, now, self.agent.key());
+
+        emit!(DisputeRaised {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::errors::BountyForgeError;
+use crate::escrow::{release_submission_fees, withdraw_lamports};
+use crate::events::BountyReclaimed;
+use crate::state::{Bounty, BountyHistory, BountyStatus, Config, RewardKind};
+
+#[derive(Accounts)]
+pub struct ReclaimExpired<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen,
+        constraint = !bounty.has_active_submission() @ BountyForgeError::ActiveSubmissionExists,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key(),
+        constraint = creator_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Reward mint recorded on the bounty, omitted for SOL bounties
+    #[account(address = bounty.reward_mint @ BountyForgeError::RewardMintMismatch)]
+    pub reward_mint: Option<AccountInfo<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ReclaimExpired<'info> {
+    pub fn reclaim_expired(&mut self) -> Result<()> {
+        // 1. checking the deadline has passed
+        require!(
+            Clock::get()?.unix_timestamp > self.bounty.deadline,
+            BountyForgeError::BountyNotExpired
+        );
+
+        // 2. refunding the creator's share and any submission fees, contributors withdraw
+        // their own
+        let refund = self.bounty.creator_share();
+        match self.bounty.reward_kind {
+            RewardKind::Spl => self.refund_tokens(refund)?,
+            RewardKind::Sol => withdraw_lamports(
+                &self.bounty.to_account_info(),
+                &self.creator.to_account_info(),
+                refund,
+            )?,
+        }
+        self.config.sub_escrowed(refund)?;
+        release_submission_fees(&mut self.bounty, &self.creator.to_account_info())?;
+
+        // 3. updating bounty status
+        self.bounty.transition(BountyStatus::Open, BountyStatus::Expired)?;
+        self.history.record(
+            BountyStatus::Expired,
+            Clock::get()?.unix_timestamp,
+            self.creator.key(),
+        );
+
+        emit!(BountyReclaimed {
+            bounty_id: self.bounty.id,
+            refund,
+        });
+
+        Ok(())
+    }
+
+    fn refund_tokens(&self, refund: u64) -> Result<()> {
+        let (Some(creator_token_account), Some(bounty_token_account)) =
+            (&self.creator_token_account, &self.bounty_token_account)
+        else {
+            return err!(BountyForgeError::MissingTokenAccount);
+        };
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: bounty_token_account.to_account_info(),
+            to: creator_token_account.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, refund)
+    }
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::ANCHOR_DISCRIMINATOR;
+use crate::events::AgentRegistered;
+use crate::state::AgentProfile;
+
+/// Creates the agent's public profile. Independent of `Reputation`, an agent
+/// may register before or after it first submits.
+#[derive(Accounts)]
+pub struct RegisterAgent<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        init,
+        payer = agent,
+        space = ANCHOR_DISCRIMINATOR + AgentProfile::INIT_SPACE,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RegisterAgent<'info> {
+    pub fn register_agent(
+        &mut self,
+        handle: Option<String>,
+        metadata_uri: String,
+        bumps: &RegisterAgentBumps,
+    ) -> Result<()> {
+        self.agent_profile.set_inner(AgentProfile {
+            agent: self.agent.key(),
+            handle: None,
+            metadata_uri: String::new(),
+            joined_at: Clock::get()?.unix_timestamp,
+            bump: bumps.agent_profile,
+        });
+        self.agent_profile
+            .set_metadata(handle.clone(), metadata_uri)?;
+
+        emit!(AgentRegistered {
+            agent: self.agent.key(),
+            handle,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,213 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::errors::BountyForgeError;
+use crate::escrow::{release_submission_fees, withdraw_lamports};
+use crate::events::{BountyCancelled, SolutionRejected};
+use crate::state::{
+    Bounty, BountyHistory, BountyStatus, Config, CreatorProfile, Reputation, RewardKind, Stake,
+    Submission,
+};
+
+#[derive(Accounts)]
+pub struct RejectSolution<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = bounty.status == BountyStatus::Submitted @ BountyForgeError::BountyNotSubmitted,
+        constraint = bounty.released_reward() == 0 @ BountyForgeError::MilestonesPartiallyReleased
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        mut,
+        constraint = bounty.agent == Some(reputation.agent) @ BountyForgeError::ReputationOwnerMismatch
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    /// CHECK: Agent whose submission is rejected, receives the submission rent
+    #[account(
+        mut,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch
+    )]
+    pub agent: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"submission", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"stake", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = stake.bump
+    )]
+    pub stake: Account<'info, Stake>,
+
+    /// CHECK: Treasury wallet receiving the slashed stake
+    #[account(mut, address = config.treasury)]
+    pub treasury: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key(),
+        constraint = creator_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RejectSolution<'info> {
+    pub fn reject_solution(&mut self) -> Result<()> {
+        // 1. recording the failure on the agent's reputation, the penalty
+        //    growing with the bounties it already failed
+        let penalty = self
+            .config
+            .rejection_penalty_for(self.reputation.failed_bounties);
+        self.reputation.failed_bounties = self
+            .reputation
+            .failed_bounties
+            .checked_add(1)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+
+        self.reputation.score = self.reputation.score.saturating_sub(penalty);
+        self.reputation.release_submission();
+        self.bounty.submission_closed();
+        self.reputation.refresh_rank_weight();
+
+        // 2. slashing the stake to the treasury, the stake rent goes back to the agent
+        let slashed = self.stake.amount;
+        withdraw_lamports(&self.stake.to_account_info(), &self.treasury, slashed)?;
+        self.stake.amount = 0;
+
+        // 3. clearing the submission, remembering the rejected hash
+        self.bounty.last_rejected_hash = self.bounty.solution_hash;
+        self.bounty.solution_hash = None;
+        self.bounty.revision = 0;
+        self.bounty.agent = None;
+        self.bounty.rejection_count = self
+            .bounty
+            .rejection_count
+            .checked_add(1)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        emit!(SolutionRejected {
+            bounty_id: self.bounty.id,
+            agent: self.reputation.agent,
+            slashed,
+        });
+
+        // 4. reopening the bounty for other agents, or cancelling it once the
+        //    rejection limit is reached
+        let max_rejections = self.config.max_rejections;
+        if max_rejections > 0 && self.bounty.rejection_count >= max_rejections {
+            return self.auto_cancel();
+        }
+
+        self.bounty.transition(BountyStatus::Submitted, BountyStatus::Open)?;
+        self.history.record(
+            BountyStatus::Open,
+            Clock::get()?.unix_timestamp,
+            self.creator.key(),
+        );
+
+        Ok(())
+    }
+
+    fn auto_cancel(&mut self) -> Result<()> {
+        // 1. refunding the creator's share and the collected submission fees,
+        //    contributors withdraw their own
+        let refund = self.bounty.creator_share();
+        match self.bounty.reward_kind {
+            RewardKind::Spl => self.refund_tokens(refund)?,
+            RewardKind::Sol => withdraw_lamports(
+                &self.bounty.to_account_info(),
+                &self.creator.to_account_info(),
+                refund,
+            )?,
+        }
+        self.config.sub_escrowed(refund)?;
+        release_submission_fees(&mut self.bounty, &self.creator.to_account_info())?;
+
+        // 2. recording the cancellation on the creator's profile
+        self.creator_profile.bounties_cancelled = self
+            .creator_profile
+            .bounties_cancelled
+            .checked_add(1)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        // 3. updating bounty status
+        self.bounty.transition(BountyStatus::Submitted, BountyStatus::Cancelled)?;
+        self.history.record(
+            BountyStatus::Cancelled,
+            Clock::get()?.unix_timestamp,
+            self.creator.key(),
+        );
+
+        emit!(BountyCancelled {
+            bounty_id: self.bounty.id,
+            refund,
+        });
+
+        Ok(())
+    }
+
+    fn refund_tokens(&self, refund: u64) -> Result<()> {
+        let (Some(creator_token_account), Some(bounty_token_account)) =
+            (&self.creator_token_account, &self.bounty_token_account)
+        else {
+            return err!(BountyForgeError::MissingTokenAccount);
+        };
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: bounty_token_account.to_account_info(),
+            to: creator_token_account.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, refund)
+    }
+}
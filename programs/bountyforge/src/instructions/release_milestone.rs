@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::errors::BountyForgeError;
+use crate::escrow::{pay_referral, withdraw_lamports};
+use crate::events::MilestoneReleased;
+use crate::state::{Bounty, BountyStatus, Config, Reputation, RewardKind};
+
+#[derive(Accounts)]
+pub struct ReleaseMilestone<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = matches!(bounty.status, BountyStatus::Submitted | BountyStatus::PendingSettlement)
+            @ BountyForgeError::BountyNotSubmitted,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch,
+        constraint = bounty.vesting_seconds == 0 @ BountyForgeError::VestingNotSupported
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = reputation.agent == agent.key() @ BountyForgeError::ReputationOwnerMismatch
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    #[account(
+        mut,
+        constraint = reputation.referrer == Some(referrer_reputation.agent) @ BountyForgeError::ReferrerMismatch
+    )]
+    pub referrer_reputation: Option<Account<'info, Reputation>>,
+
+    /// CHECK: Agent receiving the milestone
+    #[account(mut)]
+    pub agent: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        constraint = agent_token_account.owner == agent.key(),
+        constraint = agent_token_account.key() == get_associated_token_address_with_program_id(
+            &agent.key(),
+            &bounty.reward_mint,
+            &token_program.key()
+        ) @ BountyForgeError::NonCanonicalTokenAccount,
+        constraint = agent_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub agent_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury,
+        constraint = treasury_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Treasury wallet receiving the fee on SOL bounties
+    #[account(mut, address = config.treasury)]
+    pub treasury: Option<AccountInfo<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> ReleaseMilestone<'info> {
+    pub fn release_milestone(&mut self, index: u8) -> Result<()> {
+        // 1. marking the milestone released
+        let milestone = self
+            .bounty
+            .milestones
+            .get_mut(index as usize)
+            .ok_or(BountyForgeError::InvalidMilestone)?;
+        require!(
+            !milestone.released,
+            BountyForgeError::MilestoneAlreadyReleased
+        );
+        milestone.released = true;
+        let amount = milestone.amount;
+
+        // 2. splitting the milestone into platform fee and agent payout
+        let fee = self
+            .config
+            .fee_for_tier(amount, self.bounty.tier_at_submit)?;
+        let payout = amount
+            .checked_sub(fee)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        self.bounty.check_min_payout(payout)?;
+
+        // 3. paying the referrer its cut of the fee, the treasury the rest of it and the
+        // agent its payout, into accounts its whitelist allows
+        require!(
+            self.reputation.allows_withdrawal_to(&self.agent.key()),
+            BountyForgeError::WithdrawalAddressNotAllowed
+        );
+        let referral = pay_referral(
+            &self.bounty,
+            &self.config,
+            &self.reputation,
+            self.referrer_reputation.as_mut(),
+            fee,
+        )?;
+        let treasury_fee = fee
+            .checked_sub(referral)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        match self.bounty.reward_kind {
+            RewardKind::Spl => self.pay_out_tokens(treasury_fee, payout)?,
+            RewardKind::Sol => self.pay_out_lamports(treasury_fee, payout)?,
+        }
+        self.config.sub_escrowed(amount)?;
+
+        // 4. crediting the agent, successful_bounties moves on finalize_settlement
+        self.reputation.total_earned = self
+            .reputation
+            .total_earned
+            .checked_add(payout)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+
+        self.reputation.refresh_rank_weight();
+
+        emit!(MilestoneReleased {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            index,
+            amount: payout,
+            fee,
+        });
+
+        Ok(())
+    }
+
+    fn pay_out_tokens(&self, fee: u64, payout: u64) -> Result<()> {
+        let (Some(bounty_token_account), Some(agent_token_account)) =
+            (&self.bounty_token_account, &self.agent_token_account)
+        else {
+            return err!(BountyForgeError::MissingTokenAccount);
+        };
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        // transfering the fee from the vault to treasury token account
+        if fee > 0 {
+            let treasury_token_account = self
+                .treasury_token_account
+                .as_ref()
+                .ok_or(BountyForgeError::MissingTokenAccount)?;
+
+            let cpi_program = self.token_program.to_account_info();
+            let cpi_accounts = Transfer {
+                from: bounty_token_account.to_account_info(),
+                to: treasury_token_account.to_account_info(),
+                authority: self.vault.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+            transfer(cpi_ctx, fee)?;
+        }
+
+        // transfering the rest from the vault to agent token account
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: bounty_token_account.to_account_info(),
+            to: agent_token_account.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, payout)
+    }
+
+    fn pay_out_lamports(&self, fee: u64, payout: u64) -> Result<()> {
+        let bounty = self.bounty.to_account_info();
+
+        if fee > 0 {
+            let treasury = self
+                .treasury
+                .as_ref()
+                .ok_or(BountyForgeError::MissingTreasuryAccount)?;
+            withdraw_lamports(&bounty, treasury, fee)?;
+        }
+
+        withdraw_lamports(&bounty, &self.agent, payout)
+    }
+}
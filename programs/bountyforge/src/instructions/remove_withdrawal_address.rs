@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::WithdrawalAddressRemoved;
+use crate::state::Reputation;
+
+/// Drops an owner from the agent's withdrawal whitelist. Removing the last
+/// entry lifts the restriction altogether.
+#[derive(Accounts)]
+pub struct RemoveWithdrawalAddress<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"rep", agent.key().as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+}
+
+impl<'info> RemoveWithdrawalAddress<'info> {
+    pub fn remove_withdrawal_address(&mut self, address: Pubkey) -> Result<()> {
+        let whitelist = &mut self.reputation.withdraw_whitelist;
+        let index = whitelist
+            .iter()
+            .position(|listed| *listed == address)
+            .ok_or(BountyForgeError::WithdrawalAddressNotListed)?;
+
+        whitelist.remove(index);
+
+        emit!(WithdrawalAddressRemoved {
+            agent: self.agent.key(),
+            address,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::BountyReopened;
+use crate::state::{Bounty, BountyHistory, BountyStatus, Config, Reputation, Stake, Submission};
+
+#[derive(Accounts)]
+pub struct ReopenBounty<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = bounty.status == BountyStatus::Submitted @ BountyForgeError::BountyNotSubmitted,
+        constraint = bounty.released_reward() == 0 @ BountyForgeError::MilestonesPartiallyReleased
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        mut,
+        constraint = bounty.agent == Some(reputation.agent) @ BountyForgeError::ReputationOwnerMismatch
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    /// CHECK: Agent of the stale submission, receives the submission rent and stake
+    #[account(
+        mut,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch
+    )]
+    pub agent: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"submission", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"stake", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = stake.bump
+    )]
+    pub stake: Account<'info, Stake>,
+}
+
+impl<'info> ReopenBounty<'info> {
+    pub fn reopen_bounty(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // 1. checking the submission has gone unreviewed for the timeout
+        let reopen_at = self
+            .submission
+            .timestamp
+            .checked_add(self.config.submission_timeout)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        require!(
+            now >= reopen_at,
+            BountyForgeError::SubmissionTimeoutNotElapsed
+        );
+
+        // 2. recording the failure on the agent's reputation, the stake is
+        // returned through the `close` constraint since nobody reviewed it
+        self.reputation.failed_bounties = self
+            .reputation
+            .failed_bounties
+            .checked_add(1)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+        self.reputation.release_submission();
+        self.bounty.submission_closed();
+        self.reputation.refresh_rank_weight();
+
+        // 3. reopening the bounty for other agents
+        self.bounty.solution_hash = None;
+        self.bounty.revision = 0;
+        self.bounty.agent = None;
+        self.bounty.transition(BountyStatus::Submitted, BountyStatus::Open)?;
+        self.history
+            .record(BountyStatus::Open, now, self.creator.key());
+
+        emit!(BountyReopened {
+            bounty_id: self.bounty.id,
+            agent: self.reputation.agent,
+        });
+
+        Ok(())
+    }
+}
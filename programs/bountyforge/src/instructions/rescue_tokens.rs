@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::errors::BountyForgeError;
+use crate::events::TokensRescued;
+use crate::state::{Bounty, Config};
+
+/// Returns tokens sent to a bounty's vault by mistake. Only mints other than
+/// the bounty's reward mint can be moved, so the reward escrow is never touched.
+#[derive(Accounts)]
+pub struct RescueTokens<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ BountyForgeError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = source_token_account.owner == vault.key(),
+        constraint = source_token_account.mint != bounty.reward_mint @ BountyForgeError::RewardMintNotRescuable
+    )]
+    pub source_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == source_token_account.mint @ BountyForgeError::RescueMintMismatch
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> RescueTokens<'info> {
+    pub fn rescue_tokens(&mut self, amount: u64) -> Result<()> {
+        require!(amount > 0, BountyForgeError::InvalidRescueAmount);
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        // 1. transfering the stray tokens out, signed by the vault
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: self.source_token_account.to_account_info(),
+            to: self.recipient_token_account.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, amount)?;
+
+        emit!(TokensRescued {
+            bounty_id: self.bounty.id,
+            mint: self.source_token_account.mint,
+            recipient: self.recipient_token_account.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+}
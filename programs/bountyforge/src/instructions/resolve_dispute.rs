@@ -0,0 +1,335 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::constants::ANCHOR_DISCRIMINATOR;
+use crate::errors::BountyForgeError;
+use crate::escrow::{
+    hold_payout, is_frozen, pay_referral, release_submission_fees, vest_payout,
+    withdraw_lamports,
+};
+use crate::events::DisputeResolved;
+use crate::state::{
+    Bounty, BountyHistory, BountyStatus, Config, DisputeOutcome, Reputation, RewardKind, Stake,
+    Vesting,
+};
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        mut,
+        constraint = arbiter.key() == config.arbiter @ BountyForgeError::UnauthorizedArbiter
+    )]
+    pub arbiter: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Disputed @ BountyForgeError::BountyNotDisputed,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        mut,
+        constraint = reputation.agent == agent.key() @ BountyForgeError::ReputationOwnerMismatch
+    )]
+    pub reputation: Account<'info, Reputation>,
+
+    #[account(
+        mut,
+        constraint = reputation.referrer == Some(referrer_reputation.agent) @ BountyForgeError::ReferrerMismatch
+    )]
+    pub referrer_reputation: Option<Account<'info, Reputation>>,
+
+    /// CHECK: Agent behind the disputed submission
+    #[account(mut)]
+    pub agent: AccountInfo<'info>,
+
+    /// CHECK: Bounty creator, refunded when the dispute goes their way
+    #[account(mut, address = bounty.creator @ BountyForgeError::UnauthorizedCreator)]
+    pub creator: AccountInfo<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"stake", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = stake.bump
+    )]
+    pub stake: Account<'info, Stake>,
+
+    #[account(
+        mut,
+        constraint = agent_token_account.owner == agent.key(),
+        constraint = agent_token_account.key() == get_associated_token_address_with_program_id(
+            &agent.key(),
+            &bounty.reward_mint,
+            &token_program.key()
+        ) @ BountyForgeError::NonCanonicalTokenAccount,
+        constraint = agent_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub agent_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = creator_token_account.owner == creator.key(),
+        constraint = creator_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub creator_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury,
+        constraint = treasury_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Treasury wallet receiving SOL fees and slashed stakes
+    #[account(mut, address = config.treasury)]
+    pub treasury: AccountInfo<'info>,
+
+    /// Required when the bounty was posted with `vesting_seconds`
+    #[account(
+        init,
+        payer = arbiter,
+        space = ANCHOR_DISCRIMINATOR + Vesting::INIT_SPACE,
+        seeds = [b"vesting", bounty.id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub vesting: Option<Account<'info, Vesting>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+
+    pub system_program: Option<Program<'info, System>>,
+}
+
+impl<'info> ResolveDispute<'info> {
+    pub fn resolve_dispute(
+        &mut self,
+        outcome: DisputeOutcome,
+        bumps: &ResolveDisputeBumps,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        self.reputation
+            .apply_decay(now, self.config.reputation_half_life);
+
+        match outcome {
+            DisputeOutcome::PayAgent => self.pay_agent(now, bumps)?,
+            DisputeOutcome::RefundCreator => self.refund_creator()?,
+        }
+        self.history
+            .record(self.bounty.status, now, self.arbiter.key());
+
+        emit!(DisputeResolved {
+            bounty_id: self.bounty.id,
+            outcome,
+        });
+
+        Ok(())
+    }
+
+    fn pay_agent(&mut self, now: i64, bumps: &ResolveDisputeBumps) -> Result<()> {
+        // 1. splitting what milestones have not paid out into platform fee and agent payout
+        let outstanding = self
+            .bounty
+            .reward
+            .checked_sub(self.bounty.released_reward())
+            .ok_or(BountyForgeError::MathOverflow)?;
+        let fee = self
+            .config
+            .fee_for_tier(outstanding, self.bounty.tier_at_submit)?;
+        let payout = outstanding
+            .checked_sub(fee)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        self.bounty.check_min_payout(payout)?;
+
+        // 2. paying the referrer its cut of the fee, the treasury the rest of it and the
+        // agent its payout, a payout that vests is held in escrow for claim_vested and one
+        // meeting a frozen agent token account for claim_reward
+        let referral = pay_referral(
+            &self.bounty,
+            &self.config,
+            &self.reputation,
+            self.referrer_reputation.as_mut(),
+            fee,
+        )?;
+        let treasury_fee = fee
+            .checked_sub(referral)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        let held = if self.bounty.vesting_seconds > 0 {
+            vest_payout(
+                &mut self.bounty,
+                self.vesting.as_mut(),
+                bumps.vesting,
+                self.agent.key(),
+                payout,
+                now,
+            )?;
+            payout
+        } else if is_frozen(self.agent_token_account.as_ref()) {
+            hold_payout(&mut self.bounty, self.agent.key(), payout);
+            payout
+        } else {
+            0
+        };
+        // what is pushed lands in the agent's own accounts, which its whitelist must allow
+        require!(
+            held == payout || self.reputation.allows_withdrawal_to(&self.agent.key()),
+            BountyForgeError::WithdrawalAddressNotAllowed
+        );
+        match self.bounty.reward_kind {
+            RewardKind::Spl => {
+                if treasury_fee > 0 {
+                    let treasury_token_account = self
+                        .treasury_token_account
+                        .as_ref()
+                        .ok_or(BountyForgeError::MissingTokenAccount)?;
+                    self.transfer_tokens(treasury_token_account, treasury_fee)?;
+                }
+                if payout > held {
+                    let agent_token_account = self
+                        .agent_token_account
+                        .as_ref()
+                        .ok_or(BountyForgeError::MissingTokenAccount)?;
+                    self.transfer_tokens(agent_token_account, payout - held)?;
+                }
+            }
+            RewardKind::Sol => {
+                let bounty = self.bounty.to_account_info();
+                withdraw_lamports(&bounty, &self.treasury, treasury_fee)?;
+                withdraw_lamports(&bounty, &self.agent, payout - held)?;
+            }
+        }
+        self.config.sub_escrowed(outstanding - held)?;
+
+        // 3. returning the agent's stake and handing over the submission fees, the stake
+        // rent comes back on close_submission
+        withdraw_lamports(
+            &self.stake.to_account_info(),
+            &self.agent,
+            self.stake.amount,
+        )?;
+        self.stake.amount = 0;
+        release_submission_fees(&mut self.bounty, &self.agent)?;
+
+        // 4. crediting the agent
+        self.reputation.successful_bounties = self
+            .reputation
+            .successful_bounties
+            .checked_add(1)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+
+        self.reputation.total_earned = self
+            .reputation
+            .total_earned
+            .checked_add(payout)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+
+        self.reputation.score = self
+            .reputation
+            .score
+            .checked_add(self.config.settle_score(self.bounty.difficulty)?)
+            .ok_or(BountyForgeError::ReputationScoreOverflow)?;
+
+        self.reputation.release_submission();
+        self.reputation.refresh_rank_weight();
+
+        self.bounty.last_rejected_hash = None;
+        self.bounty.transition(BountyStatus::Disputed, BountyStatus::Settled)?;
+
+        Ok(())
+    }
+
+    fn refund_creator(&mut self) -> Result<()> {
+        // 1. refunding the creator's share of what is left along with the submission fees,
+        // contributors withdraw their own
+        let refund = self.bounty.refund_for(self.bounty.creator_share());
+        match self.bounty.reward_kind {
+            RewardKind::Spl => {
+                let creator_token_account = self
+                    .creator_token_account
+                    .as_ref()
+                    .ok_or(BountyForgeError::MissingTokenAccount)?;
+                self.transfer_tokens(creator_token_account, refund)?;
+            }
+            RewardKind::Sol => {
+                withdraw_lamports(&self.bounty.to_account_info(), &self.creator, refund)?
+            }
+        }
+        self.config.sub_escrowed(refund)?;
+        release_submission_fees(&mut self.bounty, &self.creator)?;
+
+        // 2. slashing the agent's stake to the treasury as on reject_solution
+        withdraw_lamports(
+            &self.stake.to_account_info(),
+            &self.treasury,
+            self.stake.amount,
+        )?;
+        self.stake.amount = 0;
+
+        // 3. recording the failure on the agent's reputation, penalized as on reject_solution
+        let penalty = self
+            .config
+            .rejection_penalty_for(self.reputation.failed_bounties);
+        self.reputation.failed_bounties = self
+            .reputation
+            .failed_bounties
+            .checked_add(1)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+
+        self.reputation.score = self.reputation.score.saturating_sub(penalty);
+        self.reputation.release_submission();
+        self.reputation.refresh_rank_weight();
+
+        self.bounty.transition(BountyStatus::Disputed, BountyStatus::Cancelled)?;
+
+        Ok(())
+    }
+
+    fn transfer_tokens(
+        &self,
+        to: &InterfaceAccount<'info, TokenAccount>,
+        amount: u64,
+    ) -> Result<()> {
+        let bounty_token_account = self
+            .bounty_token_account
+            .as_ref()
+            .ok_or(BountyForgeError::MissingTokenAccount)?;
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        // transfering from the vault token account (escrow)
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: bounty_token_account.to_account_info(),
+            to: to.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, amount)
+    }
+}
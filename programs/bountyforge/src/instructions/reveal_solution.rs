@@ -0,0 +1,373 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+use crate::{
+    constants::{ANCHOR_DISCRIMINATOR, MAX_SOLUTION_KIND},
+    eligibility::SubmitEligibility,
+    errors::BountyForgeError,
+    events::SolutionSubmitted,
+    state::{Attestation, Bounty, BountyHistory, BountyStatus, Commitment, Config, Reputation, ReputationTier, Stake, Submission},
+};
+
+#[derive(Accounts)]
+pub struct RevealSolution<'info> {
+    #[account(mut)]
+    pub agent: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ BountyForgeError::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen,
+        constraint = bounty.solution_hash.is_none() @ BountyForgeError::BountyAlreadySubmitted,
+        constraint = bounty.creator != agent.key() @ BountyForgeError::SelfSettlementForbidden
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        constraint = attestation.agent == agent.key() @ BountyForgeError::AttestationOwnerMismatch,
+        constraint = attestation.bounty_id == bounty.id @ BountyForgeError::AttestationBountyMismatch,
+        constraint = attestation.verified @ BountyForgeError::AttestationNotVerified,
+        constraint = !attestation.revoked @ BountyForgeError::AttestationRevoked
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(
+        mut,
+        close = agent,
+        seeds = [b"commitment", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = commitment.bump
+    )]
+    pub commitment: Account<'info, Commitment>,
+
+    /// CHECK: Agent reputation created by initialize_reputation, loaded in
+    /// reveal_solution so a missing one fails with ReputationNotInitialized
+    #[account(mut, seeds = [b"rep", agent.key().as_ref()], bump)]
+    pub reputation: AccountInfo<'info>,
+
+    #[account(
+        init,
+        payer = agent,
+        space = ANCHOR_DISCRIMINATOR + Submission::INIT_SPACE,
+        seeds = [b"submission", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump
+    )]
+    pub submission: Account<'info, Submission>,
+
+    #[account(
+        init,
+        payer = agent,
+        space = ANCHOR_DISCRIMINATOR + Stake::INIT_SPACE,
+        seeds = [b"stake", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump
+    )]
+    pub stake: Account<'info, Stake>,
+
+    /// CHECK: Optional Switchboard oracle account for price verification
+    /// Only required if bounty description mentions oracle/price
+    /// This is a generic account info - verification happens off-chain
+    pub oracle: Option<AccountInfo<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> RevealSolution<'info> {
+    #[allow(clippy::too_many_arguments)]
+    pub fn reveal_solution(
+        &mut self,
+        solution_hash: [u8; 32],
+        solution_kind: u8,
+        solution_size: u32,
+        min_payout: u64,
+        delivery_pubkey: Pubkey,
+        nonce: [u8; 32],
+        proof: Vec<[u8; 32]>,
+        co_attestations: &'info [AccountInfo<'info>],
+        bumps: &RevealSolutionBumps,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // 1. validating the reveal against the earlier commitment
+        require!(
+            solution_hash != [0; 32],
+            BountyForgeError::InvalidSolutionHash
+        );
+        require!(
+            solution_kind <= MAX_SOLUTION_KIND,
+            BountyForgeError::InvalidSolutionKind
+        );
+        // private bounties need a key to deliver the encrypted solution to
+        require!(
+            self.bounty.encryption_scheme == 0 || delivery_pubkey != Pubkey::default(),
+            BountyForgeError::MissingDeliveryPubkey
+        );
+        require!(
+            now >= self
+                .commitment
+                .committed_at
+                .checked_add(self.config.reveal_delay)
+                .ok_or(BountyForgeError::MathOverflow)?,
+            BountyForgeError::RevealTooEarly
+        );
+        require!(
+            Commitment::compute(&solution_hash, &self.agent.key(), &nonce)
+                == self.commitment.commitment,
+            BountyForgeError::CommitmentMismatch
+        );
+
+        // 2. validating the agent clears every submit guard, the same ones can_submit reports
+        let mut reputation = self.load_reputation()?;
+        SubmitEligibility::evaluate(
+            &self.bounty,
+            Some(&reputation),
+            &self.config,
+            &self.agent.key(),
+            &proof,
+            now,
+        )
+        .require()?;
+
+        // 3. validating the attestations match, are old enough and the hash was not just rejected
+        require!(
+            self.attestation.solution_hash == solution_hash,
+            BountyForgeError::SolutionHashMismatch
+        );
+        require!(
+            self.bounty.last_rejected_hash != Some(solution_hash),
+            BountyForgeError::DuplicateSolution
+        );
+        require!(
+            now >= self
+                .attestation
+                .timestamp
+                .checked_add(self.config.min_attestation_age)
+                .ok_or(BountyForgeError::MathOverflow)?,
+            BountyForgeError::AttestationTooFresh
+        );
+        check_attestation_quorum(
+            &self.bounty,
+            &self.attestation,
+            solution_hash,
+            co_attestations,
+        )?;
+
+        let description_lower = self.bounty.description.to_lowercase();
+        let requires_oracle = description_lower.contains("oracle") 
+            || description_lower.contains("switchboard") 
+            || description_lower.contains("price");
+        
+        if requires_oracle {
+            // Oracle verification: require oracle account to be provided
+            // Full verification happens off-chain via x402 gateway
+            // On-chain we just verify the account exists and is not empty
+            require!(
+                self.oracle.is_some(),
+                BountyForgeError::OracleVerificationFailed
+            );
+            
+            if let Some(ref oracle_account) = self.oracle {
+                // Basic check: oracle account must exist and have data
+                require!(
+                    !oracle_account.data_is_empty(),
+                    BountyForgeError::OracleVerificationFailed
+                );
+            }
+        }
+
+        // 4. recording the submission
+        self.submission.set_inner(Submission {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            solution_hash,
+            solution_kind,
+            solution_size,
+            min_payout,
+            timestamp: now,
+            reputation_score_at_submit: 0, // snapshotted once reputation is updated below
+            tier_at_submit: ReputationTier::Novice,
+            staked_at_submit: false,
+            delivery_pubkey,
+            won: false,
+            bump: bumps.submission,
+        });
+
+        // 5. locking the agent's stake on top of the stake account rent and
+        // collecting the submission fee into the bounty's pool
+        let stake_amount = self.config.stake_amount;
+        self.stake.set_inner(Stake {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            amount: stake_amount,
+            bump: bumps.stake,
+        });
+
+        if stake_amount > 0 {
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_accounts = system_program::Transfer {
+                from: self.agent.to_account_info(),
+                to: self.stake.to_account_info(),
+            };
+
+            let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+
+            system_program::transfer(cpi_context, stake_amount)?;
+        }
+
+        let submission_fee = self.config.submission_fee;
+        if submission_fee > 0 {
+            let cpi_program = self.system_program.to_account_info();
+            let cpi_accounts = system_program::Transfer {
+                from: self.agent.to_account_info(),
+                to: self.bounty.to_account_info(),
+            };
+
+            let cpi_context = CpiContext::new(cpi_program, cpi_accounts);
+
+            system_program::transfer(cpi_context, submission_fee)?;
+
+            self.bounty.submission_fees = self
+                .bounty
+                .submission_fees
+                .checked_add(submission_fee)
+                .ok_or(BountyForgeError::MathOverflow)?;
+        }
+
+        // 6. updating bounty, competition bounties stay open for other agents
+        self.bounty.submission_count = self
+            .bounty
+            .submission_count
+            .checked_add(1)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        self.bounty.open_submissions = self
+            .bounty
+            .open_submissions
+            .checked_add(1)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        if !self.bounty.competition {
+            self.bounty.solution_hash = Some(solution_hash);
+            self.bounty.solution_kind = solution_kind;
+            self.bounty.solution_size = solution_size;
+            self.bounty.min_payout = min_payout;
+            self.bounty.delivery_pubkey = delivery_pubkey;
+            self.bounty.submitted_at = now;
+            self.bounty.agent = Some(self.agent.key());
+            self.bounty.transition(BountyStatus::Open, BountyStatus::Submitted)?;
+            self.history
+                .record(BountyStatus::Submitted, now, self.agent.key());
+        }
+
+        // 7. updating reputation
+        reputation.apply_decay(now, self.config.reputation_half_life);
+        reputation.last_submission_at = now;
+        reputation.active_submissions = reputation
+            .active_submissions
+            .checked_add(1)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+
+        // harder bounties are worth proportionally more score
+        reputation.score = reputation
+            .score
+            .checked_add(self.config.submit_score(self.bounty.difficulty)?)
+            .ok_or(BountyForgeError::ReputationScoreOverflow)?;
+        reputation.refresh_rank_weight();
+        self.store_reputation(&reputation)?;
+
+        // 8. snapshotting the reputation the submission will be settled on, so
+        // later changes to it can't move the fee or the auto_settle ranking
+        self.submission.reputation_score_at_submit = reputation.score;
+        self.submission.tier_at_submit = reputation.tier();
+        self.submission.staked_at_submit = reputation.is_locked(now);
+        if !self.bounty.competition {
+            self.bounty.reputation_score_at_submit = reputation.score;
+            self.bounty.tier_at_submit = reputation.tier();
+        }
+
+        emit!(SolutionSubmitted {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            solution_hash,
+        });
+
+        Ok(())
+    }
+
+    fn load_reputation(&self) -> Result<Reputation> {
+        require!(
+            self.reputation.owner == &crate::ID && !self.reputation.data_is_empty(),
+            BountyForgeError::ReputationNotInitialized
+        );
+
+        let data = self.reputation.try_borrow_data()?;
+        let reputation = Reputation::try_deserialize(&mut &data[..])?;
+        require!(
+            reputation.agent == self.agent.key(),
+            BountyForgeError::ReputationOwnerMismatch
+        );
+
+        Ok(reputation)
+    }
+
+    fn store_reputation(&self, reputation: &Reputation) -> Result<()> {
+        let mut data = self.reputation.try_borrow_mut_data()?;
+        reputation.try_serialize(&mut &mut data[..])
+    }
+}
+
+/// Checks that `attestation` and the `co_attestations` passed in
+/// remaining_accounts add up to the bounty's `required_attestations` distinct
+/// verified attesters of `solution_hash`, shared with revise_solution.
+pub(crate) fn check_attestation_quorum<'info>(
+    bounty: &Bounty,
+    attestation: &Attestation,
+    solution_hash: [u8; 32],
+    co_attestations: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    let mut attesters = Vec::with_capacity(co_attestations.len() + 1);
+    attesters.push(attestation.agent);
+
+    for info in co_attestations {
+        let co_attestation = Account::<Attestation>::try_from(info)?;
+
+        require!(
+            co_attestation.bounty_id == bounty.id,
+            BountyForgeError::AttestationBountyMismatch
+        );
+        require!(
+            co_attestation.solution_hash == solution_hash,
+            BountyForgeError::SolutionHashMismatch
+        );
+        require!(
+            co_attestation.verified,
+            BountyForgeError::AttestationNotVerified
+        );
+        require!(
+            !co_attestation.revoked,
+            BountyForgeError::AttestationRevoked
+        );
+        require!(
+            !attesters.contains(&co_attestation.agent),
+            BountyForgeError::DuplicateAttester
+        );
+        attesters.push(co_attestation.agent);
+    }
+
+    require!(
+        attesters.len() >= bounty.required_attestations as usize,
+        BountyForgeError::AttestationQuorumNotMet
+    );
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_SOLUTION_KIND;
+use crate::errors::BountyForgeError;
+use crate::events::SolutionRevised;
+use crate::instructions::reveal_solution::check_attestation_quorum;
+use crate::state::{Attestation, Bounty, BountyStatus, Config, Submission};
+
+/// Replaces a submitted solution before the creator proposes settlement, so
+/// agents can act on feedback without a reject and a fresh commit-reveal.
+#[derive(Accounts)]
+pub struct ReviseSolution<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ BountyForgeError::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.status == BountyStatus::Submitted @ BountyForgeError::BountyNotSubmitted,
+        constraint = bounty.agent == Some(agent.key()) @ BountyForgeError::UnauthorizedRevision
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        constraint = attestation.agent == agent.key() @ BountyForgeError::AttestationOwnerMismatch,
+        constraint = attestation.bounty_id == bounty.id @ BountyForgeError::AttestationBountyMismatch,
+        constraint = attestation.verified @ BountyForgeError::AttestationNotVerified,
+        constraint = !attestation.revoked @ BountyForgeError::AttestationRevoked
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(
+        mut,
+        seeds = [b"submission", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = submission.bump
+    )]
+    pub submission: Account<'info, Submission>,
+}
+
+impl<'info> ReviseSolution<'info> {
+    pub fn revise_solution(
+        &mut self,
+        solution_hash: [u8; 32],
+        solution_kind: u8,
+        solution_size: u32,
+        co_attestations: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // 1. validating the attestations match, are old enough and the hash was not just rejected
+        require!(
+            solution_kind <= MAX_SOLUTION_KIND,
+            BountyForgeError::InvalidSolutionKind
+        );
+        require!(
+            self.attestation.solution_hash == solution_hash,
+            BountyForgeError::SolutionHashMismatch
+        );
+        require!(
+            self.bounty.last_rejected_hash != Some(solution_hash),
+            BountyForgeError::DuplicateSolution
+        );
+        require!(
+            now >= self
+                .attestation
+                .timestamp
+                .checked_add(self.config.min_attestation_age)
+                .ok_or(BountyForgeError::MathOverflow)?,
+            BountyForgeError::AttestationTooFresh
+        );
+        check_attestation_quorum(
+            &self.bounty,
+            &self.attestation,
+            solution_hash,
+            co_attestations,
+        )?;
+
+        // 2. replacing the solution, the submission timestamp is kept so
+        // revisions cannot hold off reopen_bounty
+        self.submission.solution_hash = solution_hash;
+        self.submission.solution_kind = solution_kind;
+        self.submission.solution_size = solution_size;
+        self.bounty.solution_hash = Some(solution_hash);
+        self.bounty.solution_kind = solution_kind;
+        self.bounty.solution_size = solution_size;
+        self.bounty.revision = self
+            .bounty
+            .revision
+            .checked_add(1)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        emit!(SolutionRevised {
+            bounty_id: self.bounty.id,
+            agent: self.agent.key(),
+            solution_hash,
+            revision: self.bounty.revision,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::AttestationRevoked;
+use crate::state::{Attestation, Bounty, BountyStatus};
+
+/// Withdraws an attestation its agent no longer stands behind. Only possible
+/// while the bounty is still open, a revoked attestation can never be
+/// verified or submitted against again.
+#[derive(Accounts)]
+pub struct RevokeAttestation<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    #[account(
+        mut,
+        constraint = attestation.agent == agent.key() @ BountyForgeError::AttestationOwnerMismatch,
+        constraint = attestation.bounty_id == bounty.id @ BountyForgeError::AttestationBountyMismatch,
+        constraint = !attestation.revoked @ BountyForgeError::AttestationRevoked
+    )]
+    pub attestation: Account<'info, Attestation>,
+}
+
+impl<'info> RevokeAttestation<'info> {
+    pub fn revoke_attestation(&mut self) -> Result<()> {
+        self.attestation.verified = false;
+        self.attestation.revoked = true;
+
+        emit!(AttestationRevoked {
+            solution_id: self.attestation.solution_id,
+            bounty_id: self.attestation.bounty_id,
+            agent: self.agent.key(),
+        });
+
+        Ok(())
+    }
+}
@@ -1,86 +1,111 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{transfer, Token, TokenAccount, Transfer};
 
 use crate::errors::BountyForgeError;
-use crate::state::{Bounty, BountyStatus, Reputation};
+use crate::events::SettlementProposed;
+use crate::state::{Bounty, BountyHistory, BountyStatus, Config, Submission};
 
 #[derive(Accounts)]
 pub struct SettleBounty<'info> {
+    /// Bounty creator or its settle delegate
     pub creator: Signer<'info>,
 
     #[account(
-        mut,
-        constraint = bounty.status == BountyStatus::Submitted @ BountyForgeError::BountyNotSubmitted,
-        constraint = bounty.solution_hash.is_some() @ BountyForgeError::BountyAlreadySubmitted,
-        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedSettlement
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ BountyForgeError::ProgramPaused
     )]
-    pub bounty: Account<'info, Bounty>,
+    pub config: Account<'info, Config>,
 
     #[account(
         mut,
-        constraint = reputation.agent == agent.key() @ BountyForgeError::ReputationOwnerMismatch
+        constraint = bounty.status == BountyStatus::Submitted
+            || (bounty.competition && bounty.status == BountyStatus::Open)
+            @ BountyForgeError::BountyNotSubmitted,
+        constraint = bounty.can_settle(&creator.key()) @ BountyForgeError::UnauthorizedSettlement,
+        constraint = bounty.competition || bounty.agent == Some(agent.key()) @ BountyForgeError::AgentMismatch
     )]
-    pub reputation: Account<'info, Reputation>,
-
-    /// CHECK: Agent receiving the reward
-    #[account(mut)]
-    pub agent: AccountInfo<'info>,
+    pub bounty: Account<'info, Bounty>,
 
     #[account(
         mut,
-        constraint = agent_token_account.owner == agent.key(),
-        constraint = agent_token_account.mint == usdc_mint.key()
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
     )]
-    pub agent_token_account: Account<'info, TokenAccount>,
+    pub history: Account<'info, BountyHistory>,
 
+    /// The winning submission
     #[account(
-        mut,
-        constraint = bounty_token_account.owner == bounty.key(),
-        constraint = bounty_token_account.mint == usdc_mint.key()
+        seeds = [b"submission", bounty.id.to_le_bytes().as_ref(), agent.key().as_ref()],
+        bump = submission.bump
     )]
-    pub bounty_token_account: Account<'info, TokenAccount>,
-
-    /// CHECK: USDC mint address (validated by token accounts)
-    pub usdc_mint: AccountInfo<'info>,
+    pub submission: Account<'info, Submission>,
 
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
+    /// CHECK: Agent receiving the reward
+    pub agent: AccountInfo<'info>,
 }
 
 impl<'info> SettleBounty<'info> {
     pub fn settle_bounty(&mut self) -> Result<()> {
-        // 1. transfering USDC from bounty PDA to agent token account
-        let bounty_id_bytes = self.bounty.id.to_le_bytes();
-        let bounty_seeds = &[b"bounty", bounty_id_bytes.as_ref(), &[self.bounty.bump]];
-        let bounty_signer = &[&bounty_seeds[..]];
-
-        let cpi_program = self.token_program.to_account_info();
-        let cpi_accounts = Transfer {
-            from: self.bounty_token_account.to_account_info(),
-            to: self.agent_token_account.to_account_info(),
-            authority: self.bounty.to_account_info(),
-        };
-
-        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, bounty_signer);
-
-        transfer(cpi_ctx, self.bounty.reward)?;
-
-        // 2. updating reputation
-        self.reputation.successful_bounties = self
-            .reputation
-            .successful_bounties
-            .checked_add(1)
-            .ok_or(BountyForgeError::ReputationOverflow)?;
-
-        self.reputation.total_earned = self
-            .reputation
-            .total_earned
-            .checked_add(self.bounty.reward)
-            .ok_or(BountyForgeError::ReputationOverflow)?;
-
-        // 3. updating bounty status
-        self.bounty.status = BountyStatus::Settled;
-
-        Ok(())
+        propose_settlement(
+            &mut self.bounty,
+            &mut self.history,
+            &self.submission,
+            self.agent.key(),
+            self.creator.key(),
+            &self.config,
+        )
     }
 }
+
+/// Locks in `submission` as the winner of `bounty`, shared with batch_settle.
+pub(crate) fn propose_settlement(
+    bounty: &mut Bounty,
+    history: &mut BountyHistory,
+    submission: &Submission,
+    agent: Pubkey,
+    creator: Pubkey,
+    config: &Config,
+) -> Result<()> {
+    // checked here rather than on SettleBounty so batch_settle and auto_settle
+    // cannot route a creator's own submission around it
+    require!(
+        bounty.creator != agent,
+        BountyForgeError::SelfSettlementForbidden
+    );
+
+    // 1. opening the dispute window
+    let now = Clock::get()?.unix_timestamp;
+    let unlock_at = now
+        .checked_add(config.dispute_window)
+        .ok_or(BountyForgeError::MathOverflow)?;
+
+    // 2. locking in the winning submission, paid out by finalize_settlement
+    bounty.solution_hash = Some(submission.solution_hash);
+    bounty.solution_kind = submission.solution_kind;
+    bounty.solution_size = submission.solution_size;
+    bounty.agent = Some(agent);
+    bounty.reputation_score_at_submit = submission.reputation_score_at_submit;
+    bounty.tier_at_submit = submission.tier_at_submit;
+    bounty.min_payout = submission.min_payout;
+    bounty.delivery_pubkey = submission.delivery_pubkey;
+    bounty.submitted_at = submission.timestamp;
+    // settling soon after the submission earns the agent part of the fee on finalize
+    bounty.settled_promptly = config.is_prompt_settlement(submission.timestamp, now);
+    bounty.settle_unlock_at = unlock_at;
+    // competition bounties stay open while they take submissions
+    let from = if bounty.competition {
+        BountyStatus::Open
+    } else {
+        BountyStatus::Submitted
+    };
+    bounty.transition(from, BountyStatus::PendingSettlement)?;
+    history.record(BountyStatus::PendingSettlement, now, creator);
+
+    emit!(SettlementProposed {
+        bounty_id: bounty.id,
+        agent,
+        unlock_at,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,348 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::constants::{BPS_DENOMINATOR, MAX_WINNERS};
+use crate::errors::BountyForgeError;
+use crate::escrow::{pay_referral, withdraw_lamports};
+use crate::events::BountyMultiSettled;
+use crate::state::{
+    Bounty, BountyHistory, BountyStatus, Config, CreatorProfile, Reputation, RewardKind, Submission,
+};
+
+/// Splits a competition bounty's reward between several submissions. Each
+/// winner is passed in `remaining_accounts` as an (agent, agent_token_account,
+/// reputation, submission) group, or an (agent, reputation, submission,
+/// referrer_reputation) group on SOL bounties with the program id standing in
+/// for a winner nobody referred, and `weights` gives each winner's share in
+/// bps. Any invalid group fails the whole settlement. Stakes come back to every agent on `close_submission`.
+/// Bounties whose payout vests settle through settle_bounty instead.
+#[derive(Accounts)]
+pub struct SettleMulti<'info> {
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = !config.paused @ BountyForgeError::ProgramPaused
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedSettlement,
+        constraint = bounty.competition @ BountyForgeError::NotCompetitionBounty,
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen,
+        constraint = bounty.vesting_seconds == 0 @ BountyForgeError::VestingNotSupported
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        mut,
+        seeds = [b"creator", bounty.creator.as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch,
+        constraint = bounty_token_account.amount >= bounty.unreleased_reward() @ BountyForgeError::InsufficientEscrow
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury,
+        constraint = treasury_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    /// CHECK: Treasury wallet receiving the fee on SOL bounties
+    #[account(mut, address = config.treasury)]
+    pub treasury: Option<AccountInfo<'info>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SettleMulti<'info> {
+    pub fn settle_multi(
+        &mut self,
+        weights: Vec<u16>,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // 1. validating the winner list against the weights
+        let group_len = 4;
+        require!(
+            (1..=MAX_WINNERS).contains(&weights.len())
+                && remaining_accounts.len() == weights.len() * group_len,
+            BountyForgeError::InvalidWinnerCount
+        );
+        require!(
+            weights.iter().all(|&weight| weight > 0)
+                && weights.iter().map(|&weight| weight as u64).sum::<u64>() == BPS_DENOMINATOR,
+            BountyForgeError::InvalidWinnerWeights
+        );
+
+        // 2. releasing the unreleased reward from escrow, split between winners below
+        let total = self.bounty.unreleased_reward();
+        self.config.sub_escrowed(total)?;
+
+        // 3. paying each winner its slice net of the fee for the tier it submitted at and its
+        // referrer a cut of that fee, the rounding dust going to the last one
+        let mut winners = Vec::with_capacity(weights.len());
+        let mut payouts = Vec::with_capacity(weights.len());
+        let mut assigned: u64 = 0;
+        let mut fee: u64 = 0;
+        let fee_pool = self.bounty.submission_fees;
+        let mut fees_paid: u64 = 0;
+        let mut referrals: u64 = 0;
+
+        for (i, (group, &weight)) in remaining_accounts
+            .chunks_exact(group_len)
+            .zip(weights.iter())
+            .enumerate()
+        {
+            let [first, second, third, fourth] = group else {
+                unreachable!()
+            };
+            let (agent, agent_token_account, reputation_info, submission_info, referrer_info) =
+                match self.bounty.reward_kind {
+                    RewardKind::Spl => (first, Some(second), third, fourth, None),
+                    RewardKind::Sol => (first, None, second, third, Some(fourth)),
+                };
+
+            require!(
+                agent.is_writable && reputation_info.is_writable && submission_info.is_writable,
+                BountyForgeError::AccountNotWritable
+            );
+            require!(
+                !winners.contains(&agent.key()),
+                BountyForgeError::DuplicateWinner
+            );
+
+            let mut submission = Account::<Submission>::try_from(submission_info)?;
+            require!(
+                submission.bounty_id == self.bounty.id && submission.agent == agent.key(),
+                BountyForgeError::SubmissionMismatch
+            );
+
+            let mut reputation = Account::<Reputation>::try_from(reputation_info)?;
+            require_keys_eq!(
+                reputation.agent,
+                agent.key(),
+                BountyForgeError::ReputationOwnerMismatch
+            );
+            require!(
+                reputation.allows_withdrawal_to(&agent.key()),
+                BountyForgeError::WithdrawalAddressNotAllowed
+            );
+
+            let slice = if i + 1 == weights.len() {
+                total
+                    .checked_sub(assigned)
+                    .ok_or(BountyForgeError::MathOverflow)?
+            } else {
+                (total as u128 * weight as u128 / BPS_DENOMINATOR as u128) as u64
+            };
+            assigned = assigned
+                .checked_add(slice)
+                .ok_or(BountyForgeError::MathOverflow)?;
+            let winner_fee = self
+                .config
+                .fee_for_tier(slice, submission.tier_at_submit)?;
+            fee = fee
+                .checked_add(winner_fee)
+                .ok_or(BountyForgeError::MathOverflow)?;
+            let share = slice
+                .checked_sub(winner_fee)
+                .ok_or(BountyForgeError::MathOverflow)?;
+            require!(
+                share >= submission.min_payout,
+                BountyForgeError::PayoutBelowMinimum
+            );
+
+            // submission fees are split with the same weights
+            let fee_share = if i + 1 == weights.len() {
+                fee_pool
+                    .checked_sub(fees_paid)
+                    .ok_or(BountyForgeError::MathOverflow)?
+            } else {
+                (fee_pool as u128 * weight as u128 / BPS_DENOMINATOR as u128) as u64
+            };
+            fees_paid = fees_paid
+                .checked_add(fee_share)
+                .ok_or(BountyForgeError::MathOverflow)?;
+            if fee_share > 0 {
+                withdraw_lamports(&self.bounty.to_account_info(), agent, fee_share)?;
+            }
+
+            // the winner's referrer gets its cut of the fee on this slice
+            let mut referrer_reputation = match referrer_info {
+                Some(info) if info.key() != crate::ID => {
+                    require!(info.is_writable, BountyForgeError::AccountNotWritable);
+                    Some(Account::<Reputation>::try_from(info)?)
+                }
+                _ => None,
+            };
+            let referral = pay_referral(
+                &self.bounty,
+                &self.config,
+                &reputation,
+                referrer_reputation.as_mut(),
+                winner_fee,
+            )?;
+            referrals = referrals
+                .checked_add(referral)
+                .ok_or(BountyForgeError::MathOverflow)?;
+            if let Some(referrer_reputation) = referrer_reputation {
+                referrer_reputation.exit(&crate::ID)?;
+            }
+
+            match agent_token_account {
+                Some(agent_token_account) => {
+                    let token_account =
+                        InterfaceAccount::<TokenAccount>::try_from(agent_token_account)?;
+                    require!(
+                        token_account.mint == self.bounty.reward_mint,
+                        BountyForgeError::RewardMintMismatch
+                    );
+                    require!(
+                        agent_token_account.key()
+                            == get_associated_token_address_with_program_id(
+                                &agent.key(),
+                                &self.bounty.reward_mint,
+                                &self.token_program.key(),
+                            ),
+                        BountyForgeError::NonCanonicalTokenAccount
+                    );
+                    self.transfer_tokens(agent_token_account, share)?;
+                }
+                None => withdraw_lamports(&self.bounty.to_account_info(), agent, share)?,
+            }
+
+            // updating the winner's reputation and writing it back
+            reputation.apply_decay(now, self.config.reputation_half_life);
+
+            reputation.successful_bounties = reputation
+                .successful_bounties
+                .checked_add(1)
+                .ok_or(BountyForgeError::ReputationOverflow)?;
+
+            reputation.total_earned = reputation
+                .total_earned
+                .checked_add(share)
+                .ok_or(BountyForgeError::ReputationOverflow)?;
+
+            reputation.score = reputation
+                .score
+                .checked_add(self.config.settle_score(self.bounty.difficulty)?)
+                .ok_or(BountyForgeError::ReputationScoreOverflow)?;
+
+            reputation.release_submission();
+            reputation.refresh_rank_weight();
+            reputation.exit(&crate::ID)?;
+
+            // marking the submission won so close_submission doesn't free its slot again
+            submission.won = true;
+            submission.exit(&crate::ID)?;
+
+            winners.push(agent.key());
+            payouts.push(share);
+        }
+
+        // 4. paying the treasury what is left of the fee
+        let treasury_fee = fee
+            .checked_sub(referrals)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        if treasury_fee > 0 {
+            self.pay_out_fee(treasury_fee)?;
+        }
+
+        // 5. crediting the creator with the whole reward, milestones included
+        self.creator_profile.bounties_settled = self
+            .creator_profile
+            .bounties_settled
+            .checked_add(1)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        self.creator_profile.total_paid = self
+            .creator_profile
+            .total_paid
+            .checked_add(self.bounty.reward)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        // 6. updating bounty status
+        self.bounty.submission_fees = 0;
+        self.bounty.last_rejected_hash = None;
+        self.bounty.transition(BountyStatus::Open, BountyStatus::Settled)?;
+        self.history
+            .record(BountyStatus::Settled, now, self.creator.key());
+
+        emit!(BountyMultiSettled {
+            bounty_id: self.bounty.id,
+            winners,
+            payouts,
+            fee,
+        });
+
+        Ok(())
+    }
+
+    fn pay_out_fee(&self, fee: u64) -> Result<()> {
+        match self.bounty.reward_kind {
+            RewardKind::Spl => {
+                let treasury_token_account = self
+                    .treasury_token_account
+                    .as_ref()
+                    .ok_or(BountyForgeError::MissingTokenAccount)?;
+                self.transfer_tokens(&treasury_token_account.to_account_info(), fee)
+            }
+            RewardKind::Sol => {
+                let treasury = self
+                    .treasury
+                    .as_ref()
+                    .ok_or(BountyForgeError::MissingTreasuryAccount)?;
+                withdraw_lamports(&self.bounty.to_account_info(), treasury, fee)
+            }
+        }
+    }
+
+    fn transfer_tokens(&self, to: &AccountInfo<'info>, amount: u64) -> Result<()> {
+        let bounty_token_account = self
+            .bounty_token_account
+            .as_ref()
+            .ok_or(BountyForgeError::MissingTokenAccount)?;
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        // transfering from the vault token account (escrow)
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: bounty_token_account.to_account_info(),
+            to: to.clone(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, amount)
+    }
+}
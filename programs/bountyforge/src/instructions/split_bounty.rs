@@ -0,0 +1,300 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::associated_token::get_associated_token_address_with_program_id;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::constants::{ANCHOR_DISCRIMINATOR, MAX_SPLIT_CHILDREN};
+use crate::errors::BountyForgeError;
+use crate::escrow::withdraw_lamports;
+use crate::events::{BountyCreated, BountySplit};
+use crate::state::{Bounty, BountyHistory, BountyStatus, Config, CreatorProfile, RewardKind};
+
+/// Breaks an open bounty into independent children, one per entry in
+/// `shares`. Each child is passed in `remaining_accounts` as a (bounty,
+/// history, bounty_token_account) group, without the token account on SOL
+/// bounties, at the PDAs of the next ids in `config.bounty_counter`. The token
+/// accounts must already exist as the ATAs of the children's vaults. Children
+/// copy the parent's terms but pay their share in one go, milestones are not
+/// carried over. The parent ends up cancelled with an empty escrow.
+#[derive(Accounts)]
+pub struct SplitBounty<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = bounty.status == BountyStatus::Open @ BountyForgeError::BountyNotOpen,
+        constraint = bounty.submission_count == 0 @ BountyForgeError::BountyHasSubmissions,
+        constraint = bounty.contributed == 0 @ BountyForgeError::BountyHasContributions
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"history", bounty.id.to_le_bytes().as_ref()],
+        bump = history.bump
+    )]
+    pub history: Account<'info, BountyHistory>,
+
+    #[account(
+        mut,
+        seeds = [b"creator", creator.key().as_ref()],
+        bump = creator_profile.bump
+    )]
+    pub creator_profile: Account<'info, CreatorProfile>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> SplitBounty<'info> {
+    pub fn split_bounty(
+        &mut self,
+        shares: Vec<u64>,
+        remaining_accounts: &'info [AccountInfo<'info>],
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // 1. validating the shares add up to the parent's reward
+        let group_len = match self.bounty.reward_kind {
+            RewardKind::Spl => 3,
+            RewardKind::Sol => 2,
+        };
+        require!(
+            (2..=MAX_SPLIT_CHILDREN).contains(&shares.len())
+                && remaining_accounts.len() == shares.len() * group_len,
+            BountyForgeError::InvalidSplitCount
+        );
+        let total = shares
+            .iter()
+            .try_fold(0u64, |total, share| total.checked_add(*share))
+            .ok_or(BountyForgeError::MathOverflow)?;
+        require!(
+            shares.iter().all(|&share| share > 0) && total == self.bounty.reward,
+            BountyForgeError::SplitSharesMismatch
+        );
+        require!(
+            shares.iter().all(|&share| share >= self.config.min_reward),
+            BountyForgeError::RewardBelowMinimum
+        );
+
+        // 2. creating each child under the next id and moving its share of the escrow
+        let mut child_ids = Vec::with_capacity(shares.len());
+        for (group, &share) in remaining_accounts
+            .chunks_exact(group_len)
+            .zip(shares.iter())
+        {
+            let child_id = self.config.bounty_counter;
+            self.config.bounty_counter = child_id
+                .checked_add(1)
+                .ok_or(BountyForgeError::MathOverflow)?;
+
+            let child_vault = self.create_child(child_id, share, &group[0], &group[1], now)?;
+            match group.get(2) {
+                Some(child_token_account) => {
+                    self.transfer_tokens(&child_vault, child_token_account, share)?
+                }
+                None => withdraw_lamports(&self.bounty.to_account_info(), &group[0], share)?,
+            }
+
+            emit!(BountyCreated {
+                bounty_id: child_id,
+                creator: self.creator.key(),
+                reward: share,
+                deadline: self.bounty.deadline,
+                category_bits: self.bounty.category_bits,
+            });
+            child_ids.push(child_id);
+        }
+
+        // 3. recording the children on the creator's profile
+        self.creator_profile.bounties_created = self
+            .creator_profile
+            .bounties_created
+            .checked_add(shares.len() as u64)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        // 4. cancelling the parent, its escrow now sits with the children
+        self.bounty
+            .transition(BountyStatus::Open, BountyStatus::Cancelled)?;
+        self.history
+            .record(BountyStatus::Cancelled, now, self.creator.key());
+
+        emit!(BountySplit {
+            bounty_id: self.bounty.id,
+            child_ids,
+            shares,
+        });
+
+        Ok(())
+    }
+
+    fn create_child(
+        &self,
+        child_id: u64,
+        share: u64,
+        bounty_info: &AccountInfo<'info>,
+        history_info: &AccountInfo<'info>,
+        now: i64,
+    ) -> Result<Pubkey> {
+        let id_bytes = child_id.to_le_bytes();
+        let (bounty_key, bounty_bump) =
+            Pubkey::find_program_address(&[b"bounty", id_bytes.as_ref()], &crate::ID);
+        let (vault_key, vault_bump) =
+            Pubkey::find_program_address(&[b"vault", id_bytes.as_ref()], &crate::ID);
+        let (history_key, history_bump) =
+            Pubkey::find_program_address(&[b"history", id_bytes.as_ref()], &crate::ID);
+        require!(
+            bounty_info.key() == bounty_key && history_info.key() == history_key,
+            BountyForgeError::InvalidChildBounty
+        );
+
+        self.create_pda(
+            bounty_info,
+            ANCHOR_DISCRIMINATOR + Bounty::INIT_SPACE,
+            &[b"bounty", id_bytes.as_ref(), &[bounty_bump]],
+        )?;
+        // the parent's USD price carries over pro rata
+        let reward_usd_at_creation = (self.bounty.reward_usd_at_creation as u128 * share as u128
+            / self.bounty.reward as u128) as u64;
+        let child = Bounty {
+            id: child_id,
+            reward: share,
+            reward_usd_at_creation,
+            milestones: Vec::new(),
+            vault_bump,
+            bump: bounty_bump,
+            ..(*self.bounty).clone()
+        };
+        child.try_serialize(&mut &mut bounty_info.try_borrow_mut_data()?[..])?;
+
+        self.create_pda(
+            history_info,
+            ANCHOR_DISCRIMINATOR + BountyHistory::INIT_SPACE,
+            &[b"history", id_bytes.as_ref(), &[history_bump]],
+        )?;
+        let mut history = BountyHistory {
+            bounty_id: child_id,
+            entries: Vec::new(),
+            total_recorded: 0,
+            bump: history_bump,
+        };
+        history.record(BountyStatus::Open, now, self.creator.key());
+        history.try_serialize(&mut &mut history_info.try_borrow_mut_data()?[..])?;
+
+        Ok(vault_key)
+    }
+
+    /// Creates the program-owned PDA at `account` the way Anchor's `init`
+    /// does. The child addresses are predictable, so one that was already
+    /// sent lamports is topped up to rent exemption and then allocated and
+    /// assigned, instead of failing create_account.
+    fn create_pda(
+        &self,
+        account: &AccountInfo<'info>,
+        space: usize,
+        seeds: &[&[u8]],
+    ) -> Result<()> {
+        let cpi_program = self.system_program.to_account_info();
+        let signer = &[seeds];
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(space);
+        let current_lamports = account.lamports();
+
+        if current_lamports == 0 {
+            let cpi_accounts = system_program::CreateAccount {
+                from: self.creator.to_account_info(),
+                to: account.clone(),
+            };
+            let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+
+            return system_program::create_account(
+                cpi_context,
+                rent_exempt_minimum,
+                space as u64,
+                &crate::ID,
+            );
+        }
+
+        // 1. paying only the rent the existing balance falls short of
+        let shortfall = rent_exempt_minimum.saturating_sub(current_lamports);
+        if shortfall > 0 {
+            let cpi_accounts = system_program::Transfer {
+                from: self.creator.to_account_info(),
+                to: account.clone(),
+            };
+            let cpi_context = CpiContext::new(cpi_program.clone(), cpi_accounts);
+
+            system_program::transfer(cpi_context, shortfall)?;
+        }
+
+        // 2. allocating the data and handing the account to the program
+        let cpi_accounts = system_program::Allocate {
+            account_to_allocate: account.clone(),
+        };
+        let cpi_context = CpiContext::new_with_signer(cpi_program.clone(), cpi_accounts, signer);
+        system_program::allocate(cpi_context, space as u64)?;
+
+        let cpi_accounts = system_program::Assign {
+            account_to_assign: account.clone(),
+        };
+        let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, signer);
+        system_program::assign(cpi_context, &crate::ID)
+    }
+
+    fn transfer_tokens(
+        &self,
+        child_vault: &Pubkey,
+        child_token_account: &'info AccountInfo<'info>,
+        amount: u64,
+    ) -> Result<()> {
+        let bounty_token_account = self
+            .bounty_token_account
+            .as_ref()
+            .ok_or(BountyForgeError::MissingTokenAccount)?;
+
+        let token_account = InterfaceAccount::<TokenAccount>::try_from(child_token_account)?;
+        require!(
+            token_account.mint == self.bounty.reward_mint,
+            BountyForgeError::RewardMintMismatch
+        );
+        require!(
+            child_token_account.key()
+                == get_associated_token_address_with_program_id(
+                    child_vault,
+                    &self.bounty.reward_mint,
+                    &self.token_program.key(),
+                ),
+            BountyForgeError::NonCanonicalTokenAccount
+        );
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: bounty_token_account.to_account_info(),
+            to: child_token_account.clone(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, amount)
+    }
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_REPUTATION_LOCK;
+use crate::errors::BountyForgeError;
+use crate::events::ReputationStaked;
+use crate::state::{Config, Reputation};
+
+/// Locks the agent's reputation for `duration` seconds. The score stops
+/// decaying until the lock ends, and submissions revealed in the meantime win
+/// auto_settle ties. A running lock can only be extended.
+#[derive(Accounts)]
+pub struct StakeReputation<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"rep", agent.key().as_ref()],
+        bump = reputation.bump
+    )]
+    pub reputation: Account<'info, Reputation>,
+}
+
+impl<'info> StakeReputation<'info> {
+    pub fn stake_reputation(&mut self, duration: i64) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+
+        // 1. validating the lock
+        require!(
+            duration > 0 && duration <= MAX_REPUTATION_LOCK,
+            BountyForgeError::InvalidLockDuration
+        );
+        let locked_until = now
+            .checked_add(duration)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        require!(
+            locked_until >= self.reputation.locked_until,
+            BountyForgeError::InvalidLockDuration
+        );
+
+        // 2. settling the decay owed so far, then freezing the score
+        self.reputation
+            .apply_decay(now, self.config.reputation_half_life);
+        self.reputation.locked_until = locked_until;
+
+        emit!(ReputationStaked {
+            agent: self.agent.key(),
+            locked_until,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    close_account, transfer, CloseAccount, TokenAccount, TokenInterface, Transfer,
+};
+
+use crate::errors::BountyForgeError;
+use crate::events::EscrowSwept;
+use crate::state::{Bounty, BountyStatus, Config};
+
+/// Cleans up the escrow of a finished bounty. Whatever rounding left in the
+/// token account goes to the treasury, and the account can be closed to give
+/// its rent back to the creator. Refundable bounties with contributions are
+/// left alone so funders can still withdraw.
+#[derive(Accounts)]
+pub struct SweepEscrow<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = bounty.status.is_final() @ BountyForgeError::BountyNotFinalized,
+        constraint = bounty.status == BountyStatus::Settled || bounty.contributed == 0
+            @ BountyForgeError::BountyHasContributions,
+        constraint = bounty.claimable_amount == 0 @ BountyForgeError::RewardUnclaimed
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = treasury_token_account.owner == config.treasury,
+        constraint = treasury_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub treasury_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> SweepEscrow<'info> {
+    pub fn sweep_escrow(&mut self, close: bool) -> Result<()> {
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        // 1. transfering any residual balance to the treasury
+        let amount = self.bounty_token_account.amount;
+        if amount > 0 {
+            let treasury_token_account = self
+                .treasury_token_account
+                .as_ref()
+                .ok_or(BountyForgeError::MissingTokenAccount)?;
+
+            let cpi_program = self.token_program.to_account_info();
+            let cpi_accounts = Transfer {
+                from: self.bounty_token_account.to_account_info(),
+                to: treasury_token_account.to_account_info(),
+                authority: self.vault.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+            transfer(cpi_ctx, amount)?;
+        }
+
+        // 2. closing the emptied token account, the rent goes back to the creator
+        if close {
+            let cpi_program = self.token_program.to_account_info();
+            let cpi_accounts = CloseAccount {
+                account: self.bounty_token_account.to_account_info(),
+                destination: self.creator.to_account_info(),
+                authority: self.vault.to_account_info(),
+            };
+
+            let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+            close_account(cpi_ctx)?;
+        }
+
+        emit!(EscrowSwept {
+            bounty_id: self.bounty.id,
+            amount,
+            closed: close,
+        });
+
+        Ok(())
+    }
+}
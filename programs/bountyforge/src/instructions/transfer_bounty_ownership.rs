@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::ANCHOR_DISCRIMINATOR;
+use crate::errors::BountyForgeError;
+use crate::events::OwnershipTransferred;
+use crate::state::{Bounty, BountyStatus, CreatorProfile};
+
+/// Hands a live bounty to another creator key, e.g. a rotated key or a
+/// teammate. The new creator's profile is created if needed so settlement and
+/// cancellation keep finding it.
+#[derive(Accounts)]
+pub struct TransferBountyOwnership<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+
+    #[account(
+        mut,
+        constraint = creator.key() == bounty.creator @ BountyForgeError::UnauthorizedCreator,
+        constraint = matches!(bounty.status, BountyStatus::Open | BountyStatus::Submitted)
+            @ BountyForgeError::OwnershipTransferLocked
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Wallet taking over the bounty, only its key is recorded
+    pub new_creator: AccountInfo<'info>,
+
+    #[account(
+        init_if_needed,
+        payer = creator,
+        space = ANCHOR_DISCRIMINATOR + CreatorProfile::INIT_SPACE,
+        seeds = [b"creator", new_creator.key().as_ref()],
+        bump
+    )]
+    pub new_creator_profile: Account<'info, CreatorProfile>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> TransferBountyOwnership<'info> {
+    pub fn transfer_bounty_ownership(
+        &mut self,
+        bumps: &TransferBountyOwnershipBumps,
+    ) -> Result<()> {
+        // 1. creating the new creator's profile on first use
+        if self.new_creator_profile.creator == Pubkey::default() {
+            self.new_creator_profile.set_inner(CreatorProfile {
+                creator: self.new_creator.key(),
+                bounties_created: 0,
+                bounties_settled: 0,
+                bounties_cancelled: 0,
+                total_paid: 0,
+                bump: bumps.new_creator_profile,
+            });
+        }
+
+        // 2. updating bounty creator
+        let previous_creator = self.bounty.creator;
+        self.bounty.creator = self.new_creator.key();
+
+        emit!(OwnershipTransferred {
+            bounty_id: self.bounty.id,
+            previous_creator,
+            new_creator: self.bounty.creator,
+        });
+
+        Ok(())
+    }
+}
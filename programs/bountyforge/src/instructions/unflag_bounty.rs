@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::BountyUnflagged;
+use crate::state::{Bounty, Config};
+
+/// Lifts a flag once the bounty was cleared, it takes submissions again.
+#[derive(Accounts)]
+pub struct UnflagBounty<'info> {
+    pub moderator: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_moderator(&moderator.key()) @ BountyForgeError::UnauthorizedModerator
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = bounty.flagged @ BountyForgeError::BountyNotFlagged
+    )]
+    pub bounty: Account<'info, Bounty>,
+}
+
+impl<'info> UnflagBounty<'info> {
+    pub fn unflag_bounty(&mut self) -> Result<()> {
+        self.bounty.flagged = false;
+
+        emit!(BountyUnflagged {
+            bounty_id: self.bounty.id,
+            moderator: self.moderator.key(),
+        });
+
+        Ok(())
+    }
+}
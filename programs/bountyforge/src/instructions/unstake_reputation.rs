@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::BountyForgeError;
+use crate::events::ReputationUnstaked;
+use crate::state::{Config, Reputation};
+
+/// Clears an expired stake_reputation lock. Decay resumes from the end of
+/// the lock, and whatever accrued since is applied right away.
+#[derive(Accounts)]
+pub struct UnstakeReputation<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [b"rep", agent.key().as_ref()],
+        bump = reputation.bump,
+        constraint = reputation.locked_until != 0 @ BountyForgeError::ReputationNotStaked
+    )]
+    pub reputation: Account<'info, Reputation>,
+}
+
+impl<'info> UnstakeReputation<'info> {
+    pub fn unstake_reputation(&mut self) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            !self.reputation.is_locked(now),
+            BountyForgeError::ReputationLocked
+        );
+
+        self.reputation
+            .apply_decay(now, self.config.reputation_half_life);
+        self.reputation.locked_until = 0;
+
+        emit!(ReputationUnstaked {
+            agent: self.agent.key(),
+            score: self.reputation.score,
+        });
+
+        Ok(())
+    }
+}
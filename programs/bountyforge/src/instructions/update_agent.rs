@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::events::AgentProfileUpdated;
+use crate::state::AgentProfile;
+
+/// Replaces the handle and metadata URI on the agent's profile, passing no
+/// handle clears it.
+#[derive(Accounts)]
+pub struct UpdateAgent<'info> {
+    pub agent: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"agent", agent.key().as_ref()],
+        bump = agent_profile.bump
+    )]
+    pub agent_profile: Account<'info, AgentProfile>,
+}
+
+impl<'info> UpdateAgent<'info> {
+    pub fn update_agent(&mut self, handle: Option<String>, metadata_uri: String) -> Result<()> {
+        self.agent_profile
+            .set_metadata(handle.clone(), metadata_uri)?;
+
+        emit!(AgentProfileUpdated {
+            agent: self.agent.key(),
+            handle,
+        });
+
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::BountyForgeError, state::Config};
+
+/// Fields left as `None` keep their current value.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct UpdateConfigArgs {
+    pub admin: Option<Pubkey>,
+    pub verifier: Option<Pubkey>,
+    pub arbiter: Option<Pubkey>,
+    pub treasury: Option<Pubkey>,
+    pub fee_bps: Option<u16>,
+    pub fee_bps_for_tier: Option<[u16; 4]>,
+    pub referral_bps: Option<u16>,
+    pub dispute_window: Option<i64>,
+    pub reputation_half_life: Option<i64>,
+    pub stake_amount: Option<u64>,
+    pub min_reward: Option<u64>,
+    pub max_reward: Option<u64>,
+    pub reveal_delay: Option<i64>,
+    pub submission_timeout: Option<i64>,
+    pub grace_period: Option<i64>,
+    pub submission_cooldown: Option<i64>,
+    pub min_attestation_age: Option<i64>,
+    pub score_on_submit: Option<u64>,
+    pub score_on_settle: Option<u64>,
+    pub submission_fee: Option<u64>,
+    pub rejection_penalty: Option<u64>,
+    pub rejection_penalty_step: Option<u64>,
+    pub max_rejection_penalty: Option<u64>,
+    pub forfeit_penalty: Option<u64>,
+    pub max_rejections: Option<u8>,
+    pub enable_memos: Option<bool>,
+    pub max_concurrent_submissions: Option<u16>,
+    pub max_price_age: Option<i64>,
+    pub max_price_conf_bps: Option<u16>,
+    pub prompt_settle_window: Option<i64>,
+    pub prompt_settle_bonus_bps: Option<u16>,
+    pub paused: Option<bool>,
+    pub bounty_counter: Option<u64>, // may only move forward
+    pub moderators: Option<Vec<Pubkey>>, // replaces the whole set
+}
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub admin: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = admin @ BountyForgeError::UnauthorizedAdmin
+    )]
+    pub config: Account<'info, Config>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    pub fn update_config(&mut self, args: UpdateConfigArgs) -> Result<()> {
+        let config = &mut self.config;
+
+        if let Some(admin) = args.admin {
+            config.admin = admin;
+        }
+        if let Some(verifier) = args.verifier {
+            config.verifier = verifier;
+        }
+        if let Some(arbiter) = args.arbiter {
+            config.arbiter = arbiter;
+        }
+        if let Some(treasury) = args.treasury {
+            config.treasury = treasury;
+        }
+        if let Some(fee_bps) = args.fee_bps {
+            config.fee_bps = fee_bps;
+        }
+        if let Some(fee_bps_for_tier) = args.fee_bps_for_tier {
+            config.fee_bps_for_tier = fee_bps_for_tier;
+        }
+        if let Some(referral_bps) = args.referral_bps {
+            config.referral_bps = referral_bps;
+        }
+        if let Some(dispute_window) = args.dispute_window {
+            config.dispute_window = dispute_window;
+        }
+        if let Some(reputation_half_life) = args.reputation_half_life {
+            config.reputation_half_life = reputation_half_life;
+        }
+        if let Some(stake_amount) = args.stake_amount {
+            config.stake_amount = stake_amount;
+        }
+        if let Some(min_reward) = args.min_reward {
+            config.min_reward = min_reward;
+        }
+        if let Some(max_reward) = args.max_reward {
+            config.max_reward = max_reward;
+        }
+        if let Some(reveal_delay) = args.reveal_delay {
+            config.reveal_delay = reveal_delay;
+        }
+        if let Some(submission_timeout) = args.submission_timeout {
+            config.submission_timeout = submission_timeout;
+        }
+        if let Some(grace_period) = args.grace_period {
+            config.grace_period = grace_period;
+        }
+        if let Some(submission_cooldown) = args.submission_cooldown {
+            config.submission_cooldown = submission_cooldown;
+        }
+        if let Some(min_attestation_age) = args.min_attestation_age {
+            config.min_attestation_age = min_attestation_age;
+        }
+        if let Some(score_on_submit) = args.score_on_submit {
+            config.score_on_submit = score_on_submit;
+        }
+        if let Some(score_on_settle) = args.score_on_settle {
+            config.score_on_settle = score_on_settle;
+        }
+        if let Some(submission_fee) = args.submission_fee {
+            config.submission_fee = submission_fee;
+        }
+        if let Some(rejection_penalty) = args.rejection_penalty {
+            config.rejection_penalty = rejection_penalty;
+        }
+        if let Some(rejection_penalty_step) = args.rejection_penalty_step {
+            config.rejection_penalty_step = rejection_penalty_step;
+        }
+        if let Some(max_rejection_penalty) = args.max_rejection_penalty {
+            config.max_rejection_penalty = max_rejection_penalty;
+        }
+        if let Some(forfeit_penalty) = args.forfeit_penalty {
+            config.forfeit_penalty = forfeit_penalty;
+        }
+        if let Some(max_rejections) = args.max_rejections {
+            config.max_rejections = max_rejections;
+        }
+        if let Some(enable_memos) = args.enable_memos {
+            config.enable_memos = enable_memos;
+        }
+        if let Some(max_concurrent_submissions) = args.max_concurrent_submissions {
+            config.max_concurrent_submissions = max_concurrent_submissions;
+        }
+        if let Some(max_price_age) = args.max_price_age {
+            config.max_price_age = max_price_age;
+        }
+        if let Some(max_price_conf_bps) = args.max_price_conf_bps {
+            config.max_price_conf_bps = max_price_conf_bps;
+        }
+        if let Some(prompt_settle_window) = args.prompt_settle_window {
+            config.prompt_settle_window = prompt_settle_window;
+        }
+        if let Some(prompt_settle_bonus_bps) = args.prompt_settle_bonus_bps {
+            config.prompt_settle_bonus_bps = prompt_settle_bonus_bps;
+        }
+        if let Some(paused) = args.paused {
+            config.paused = paused;
+        }
+        if let Some(bounty_counter) = args.bounty_counter {
+            // skipping ahead lets a deployment step over ids chosen by callers
+            // before post_bounty assigned them
+            require!(
+                bounty_counter >= config.bounty_counter,
+                BountyForgeError::BountyCounterRewound
+            );
+            config.bounty_counter = bounty_counter;
+        }
+        if let Some(moderators) = args.moderators {
+            config.moderators = moderators;
+        }
+
+        config.validate()
+    }
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::BountyForgeError,
+    events::AttestationVerified,
+    state::{Attestation, Config},
+};
+
+#[derive(Accounts)]
+pub struct VerifyAttestation<'info> {
+    pub verifier: Signer<'info>,
+
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.verifier == verifier.key() @ BountyForgeError::UnauthorizedVerifier
+    )]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        constraint = !attestation.verified @ BountyForgeError::AlreadyVerified,
+        constraint = !attestation.revoked @ BountyForgeError::AttestationRevoked
+    )]
+    pub attestation: Account<'info, Attestation>,
+}
+
+impl<'info> VerifyAttestation<'info> {
+    pub fn verify_attestation(&mut self) -> Result<()> {
+        self.attestation.verified = true;
+        self.attestation.verified_at = Clock::get()?.unix_timestamp;
+
+        emit!(AttestationVerified {
+            solution_id: self.attestation.solution_id,
+            verifier: self.verifier.key(),
+        });
+
+        Ok(())
+    }
+}
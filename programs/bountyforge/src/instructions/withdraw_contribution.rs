@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{transfer, TokenAccount, TokenInterface, Transfer};
+
+use crate::errors::BountyForgeError;
+use crate::escrow::withdraw_lamports;
+use crate::events::ContributionRefunded;
+use crate::state::{Bounty, BountyStatus, Config, Contribution, RewardKind};
+
+#[derive(Accounts)]
+pub struct WithdrawContribution<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        constraint = matches!(bounty.status, BountyStatus::Cancelled | BountyStatus::Expired)
+            @ BountyForgeError::BountyNotRefundable
+    )]
+    pub bounty: Account<'info, Bounty>,
+
+    /// CHECK: Escrow authority over the bounty's token account, holds no data
+    #[account(seeds = [b"vault", bounty.id.to_le_bytes().as_ref()], bump = bounty.vault_bump)]
+    pub vault: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = funder,
+        seeds = [b"contrib", bounty.id.to_le_bytes().as_ref(), funder.key().as_ref()],
+        bump = contribution.bump
+    )]
+    pub contribution: Account<'info, Contribution>,
+
+    #[account(
+        mut,
+        constraint = funder_token_account.owner == funder.key(),
+        constraint = funder_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub funder_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        constraint = bounty_token_account.owner == vault.key(),
+        constraint = bounty_token_account.mint == bounty.reward_mint @ BountyForgeError::RewardMintMismatch
+    )]
+    pub bounty_token_account: Option<InterfaceAccount<'info, TokenAccount>>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+impl<'info> WithdrawContribution<'info> {
+    pub fn withdraw_contribution(&mut self) -> Result<()> {
+        // 1. refunding the contribution, minus the same fraction milestones paid out
+        let refund = self.bounty.refund_for(self.contribution.amount);
+        match self.bounty.reward_kind {
+            RewardKind::Spl => self.refund_tokens(refund)?,
+            RewardKind::Sol => withdraw_lamports(
+                &self.bounty.to_account_info(),
+                &self.funder.to_account_info(),
+                refund,
+            )?,
+        }
+        self.config.sub_escrowed(refund)?;
+
+        emit!(ContributionRefunded {
+            bounty_id: self.bounty.id,
+            funder: self.funder.key(),
+            refund,
+        });
+
+        Ok(())
+    }
+
+    fn refund_tokens(&self, refund: u64) -> Result<()> {
+        let (Some(funder_token_account), Some(bounty_token_account)) =
+            (&self.funder_token_account, &self.bounty_token_account)
+        else {
+            return err!(BountyForgeError::MissingTokenAccount);
+        };
+
+        let bounty_id_bytes = self.bounty.id.to_le_bytes();
+        let vault_seeds = &[b"vault", bounty_id_bytes.as_ref(), &[self.bounty.vault_bump]];
+        let vault_signer = &[&vault_seeds[..]];
+
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_accounts = Transfer {
+            from: bounty_token_account.to_account_info(),
+            to: funder_token_account.to_account_info(),
+            authority: self.vault.to_account_info(),
+        };
+
+        let cpi_ctx = CpiContext::new_with_signer(cpi_program, cpi_accounts, vault_signer);
+
+        transfer(cpi_ctx, refund)
+    }
+}
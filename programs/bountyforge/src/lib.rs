@@ -2,9 +2,17 @@
 
 use anchor_lang::prelude::*;
 use instructions::*;
+use eligibility::SubmitEligibility;
+use state::{DisputeOutcome, ReputationSnapshot};
 mod constants;
+mod eligibility;
 mod errors;
+mod escrow;
+mod events;
 mod instructions;
+mod merkle;
+mod mint;
+mod oracle;
 mod state;
 declare_id!("DUYYaLDvkWfFYKB8HshseMi6f5X9ShxaydsfrJLrkGMM");
 
@@ -12,30 +20,301 @@ declare_id!("DUYYaLDvkWfFYKB8HshseMi6f5X9ShxaydsfrJLrkGMM");
 pub mod bountyforge {
     use super::*;
 
-    pub fn post_bounty(
-        ctx: Context<PostBounty>,
-        bounty_id: u64,
-        description: String,
-        reward: u64,
+    pub fn initialize_config(
+        ctx: Context<InitializeConfig>,
+        args: InitializeConfigArgs,
+    ) -> Result<()> {
+        ctx.accounts.initialize_config(args, &ctx.bumps)
+    }
+
+    pub fn update_config(ctx: Context<UpdateConfig>, args: UpdateConfigArgs) -> Result<()> {
+        ctx.accounts.update_config(args)
+    }
+
+    pub fn post_bounty(ctx: Context<PostBounty>, args: PostBountyArgs) -> Result<()> {
+        ctx.accounts.post_bounty(args, &ctx.bumps)
+    }
+
+    pub fn increase_reward(ctx: Context<IncreaseReward>, amount: u64) -> Result<()> {
+        ctx.accounts.increase_reward(amount)
+    }
+
+    pub fn contribute_to_bounty(ctx: Context<ContributeToBounty>, amount: u64) -> Result<()> {
+        ctx.accounts.contribute_to_bounty(amount, &ctx.bumps)
+    }
+
+    pub fn initialize_reputation(ctx: Context<InitializeReputation>) -> Result<()> {
+        ctx.accounts.initialize_reputation(&ctx.bumps)
+    }
+
+    pub fn register_agent(
+        ctx: Context<RegisterAgent>,
+        handle: Option<String>,
+        metadata_uri: String,
     ) -> Result<()> {
         ctx.accounts
-            .post_bounty(bounty_id, description, reward, &ctx.bumps)
+            .register_agent(handle, metadata_uri, &ctx.bumps)
+    }
+
+    pub fn update_agent(
+        ctx: Context<UpdateAgent>,
+        handle: Option<String>,
+        metadata_uri: String,
+    ) -> Result<()> {
+        ctx.accounts.update_agent(handle, metadata_uri)
     }
 
     pub fn attest_solution(
         ctx: Context<AttestSolution>,
         solution_id: u64,
+        bounty_id: u64,
         solution_hash: [u8; 32],
     ) -> Result<()> {
         ctx.accounts
-            .attest_solution(solution_id, solution_hash, &ctx.bumps)
+            .attest_solution(solution_id, bounty_id, solution_hash, &ctx.bumps)
+    }
+
+    pub fn verify_attestation(ctx: Context<VerifyAttestation>) -> Result<()> {
+        ctx.accounts.verify_attestation()
     }
 
-    pub fn submit_solution(ctx: Context<SubmitSolution>, solution_hash: [u8; 32]) -> Result<()> {
-        ctx.accounts.submit_solution(solution_hash, &ctx.bumps)
+    pub fn batch_verify<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchVerify<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.batch_verify(ctx.remaining_accounts)
+    }
+
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        ctx.accounts.revoke_attestation()
+    }
+
+    pub fn commit_solution(ctx: Context<CommitSolution>, commitment: [u8; 32]) -> Result<()> {
+        ctx.accounts.commit_solution(commitment, &ctx.bumps)
+    }
+
+    pub fn can_submit(ctx: Context<CanSubmit>, proof: Vec<[u8; 32]>) -> Result<SubmitEligibility> {
+        ctx.accounts.can_submit(proof)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn reveal_solution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, RevealSolution<'info>>,
+        solution_hash: [u8; 32],
+        solution_kind: u8,
+        solution_size: u32,
+        min_payout: u64,
+        delivery_pubkey: Pubkey,
+        nonce: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.reveal_solution(
+            solution_hash,
+            solution_kind,
+            solution_size,
+            min_payout,
+            delivery_pubkey,
+            nonce,
+            proof,
+            ctx.remaining_accounts,
+            &ctx.bumps,
+        )
+    }
+
+    pub fn revise_solution<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReviseSolution<'info>>,
+        solution_hash: [u8; 32],
+        solution_kind: u8,
+        solution_size: u32,
+    ) -> Result<()> {
+        ctx.accounts.revise_solution(
+            solution_hash,
+            solution_kind,
+            solution_size,
+            ctx.remaining_accounts,
+        )
+    }
+
+    pub fn reject_solution(ctx: Context<RejectSolution>) -> Result<()> {
+        ctx.accounts.reject_solution()
+    }
+
+    pub fn forfeit_submission(ctx: Context<ForfeitSubmission>) -> Result<()> {
+        ctx.accounts.forfeit_submission()
+    }
+
+    pub fn release_milestone(ctx: Context<ReleaseMilestone>, index: u8) -> Result<()> {
+        ctx.accounts.release_milestone(index)
+    }
+
+    pub fn reopen_bounty(ctx: Context<ReopenBounty>) -> Result<()> {
+        ctx.accounts.reopen_bounty()
     }
 
     pub fn settle_bounty(ctx: Context<SettleBounty>) -> Result<()> {
         ctx.accounts.settle_bounty()
     }
+
+    pub fn settle_partial(ctx: Context<SettlePartial>, amount: u64) -> Result<()> {
+        ctx.accounts.settle_partial(amount, &ctx.bumps)
+    }
+
+    pub fn settle_multi<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SettleMulti<'info>>,
+        weights: Vec<u16>,
+    ) -> Result<()> {
+        ctx.accounts.settle_multi(weights, ctx.remaining_accounts)
+    }
+
+    pub fn batch_settle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BatchSettle<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.batch_settle(ctx.remaining_accounts)
+    }
+
+    pub fn auto_settle<'info>(
+        ctx: Context<'_, '_, 'info, 'info, AutoSettle<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.auto_settle(ctx.remaining_accounts)
+    }
+
+    pub fn raise_dispute(ctx: Context<RaiseDispute>) -> Result<()> {
+        ctx.accounts.raise_dispute()
+    }
+
+    pub fn resolve_dispute(ctx: Context<ResolveDispute>, outcome: DisputeOutcome) -> Result<()> {
+        ctx.accounts.resolve_dispute(outcome, &ctx.bumps)
+    }
+
+    pub fn finalize_settlement(ctx: Context<FinalizeSettlement>) -> Result<()> {
+        ctx.accounts.finalize_settlement(&ctx.bumps)
+    }
+
+    pub fn claim_reward(ctx: Context<ClaimReward>) -> Result<()> {
+        ctx.accounts.claim_reward()
+    }
+
+    pub fn claim_vested(ctx: Context<ClaimVested>) -> Result<()> {
+        ctx.accounts.claim_vested()
+    }
+
+    pub fn add_withdrawal_address(
+        ctx: Context<AddWithdrawalAddress>,
+        address: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.add_withdrawal_address(address)
+    }
+
+    pub fn remove_withdrawal_address(
+        ctx: Context<RemoveWithdrawalAddress>,
+        address: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.remove_withdrawal_address(address)
+    }
+
+    pub fn expire_submitted(ctx: Context<ExpireSubmitted>) -> Result<()> {
+        ctx.accounts.expire_submitted(&ctx.bumps)
+    }
+
+    pub fn close_submission(ctx: Context<CloseSubmission>) -> Result<()> {
+        ctx.accounts.close_submission()
+    }
+
+    pub fn close_attestation(ctx: Context<CloseAttestation>) -> Result<()> {
+        ctx.accounts.close_attestation()
+    }
+
+    pub fn mint_reputation_badge(ctx: Context<MintReputationBadge>) -> Result<()> {
+        ctx.accounts.mint_reputation_badge()
+    }
+
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        ctx.accounts.claim_referral_rewards()
+    }
+
+    pub fn decay_reputation(ctx: Context<DecayReputation>) -> Result<()> {
+        ctx.accounts.decay_reputation()
+    }
+
+    pub fn stake_reputation(ctx: Context<StakeReputation>, duration: i64) -> Result<()> {
+        ctx.accounts.stake_reputation(duration)
+    }
+
+    pub fn unstake_reputation(ctx: Context<UnstakeReputation>) -> Result<()> {
+        ctx.accounts.unstake_reputation()
+    }
+
+    pub fn get_reputations<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetReputations<'info>>,
+    ) -> Result<()> {
+        ctx.accounts.get_reputations(ctx.remaining_accounts)
+    }
+
+    pub fn export_reputation(ctx: Context<ExportReputation>) -> Result<ReputationSnapshot> {
+        ctx.accounts.export_reputation()
+    }
+
+    pub fn import_reputation(
+        ctx: Context<ImportReputation>,
+        snapshot: ReputationSnapshot,
+    ) -> Result<()> {
+        ctx.accounts.import_reputation(snapshot, &ctx.bumps)
+    }
+
+    pub fn adjust_reputation(
+        ctx: Context<AdjustReputation>,
+        args: AdjustReputationArgs,
+    ) -> Result<()> {
+        ctx.accounts.adjust_reputation(args)
+    }
+
+    pub fn flag_bounty(ctx: Context<FlagBounty>, reason_hash: [u8; 32]) -> Result<()> {
+        ctx.accounts.flag_bounty(reason_hash)
+    }
+
+    pub fn unflag_bounty(ctx: Context<UnflagBounty>) -> Result<()> {
+        ctx.accounts.unflag_bounty()
+    }
+
+    pub fn cancel_bounty(ctx: Context<CancelBounty>) -> Result<()> {
+        ctx.accounts.cancel_bounty()
+    }
+
+    pub fn split_bounty<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SplitBounty<'info>>,
+        shares: Vec<u64>,
+    ) -> Result<()> {
+        ctx.accounts.split_bounty(shares, ctx.remaining_accounts)
+    }
+
+    pub fn sweep_escrow(ctx: Context<SweepEscrow>, close: bool) -> Result<()> {
+        ctx.accounts.sweep_escrow(close)
+    }
+
+    pub fn cleanup_bounty(ctx: Context<CleanupBounty>) -> Result<()> {
+        ctx.accounts.cleanup_bounty()
+    }
+
+    pub fn rescue_tokens(ctx: Context<RescueTokens>, amount: u64) -> Result<()> {
+        ctx.accounts.rescue_tokens(amount)
+    }
+
+    pub fn transfer_bounty_ownership(ctx: Context<TransferBountyOwnership>) -> Result<()> {
+        ctx.accounts.transfer_bounty_ownership(&ctx.bumps)
+    }
+
+    pub fn migrate_reward_mint(ctx: Context<MigrateRewardMint>, new_reward: u64) -> Result<()> {
+        ctx.accounts.migrate_reward_mint(new_reward)
+    }
+
+    pub fn extend_deadline(ctx: Context<ExtendDeadline>, deadline: i64) -> Result<()> {
+        ctx.accounts.extend_deadline(deadline)
+    }
+
+    pub fn reclaim_expired(ctx: Context<ReclaimExpired>) -> Result<()> {
+        ctx.accounts.reclaim_expired()
+    }
+
+    pub fn withdraw_contribution(ctx: Context<WithdrawContribution>) -> Result<()> {
+        ctx.accounts.withdraw_contribution()
+    }
 }
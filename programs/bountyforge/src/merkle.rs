@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+/// Leaf committed to a whitelist Merkle tree for `agent`.
+pub fn leaf(agent: &Pubkey) -> [u8; 32] {
+    keccak::hash(agent.as_ref()).to_bytes()
+}
+
+/// Checks `proof` links `leaf` to `root`. Each pair is hashed in sorted order,
+/// so proofs carry no left/right flags.
+pub fn verify(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).to_bytes()
+        } else {
+            keccak::hashv(&[sibling, &node]).to_bytes()
+        }
+    });
+
+    computed == *root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parent(a: [u8; 32], b: [u8; 32]) -> [u8; 32] {
+        let (left, right) = if a <= b { (a, b) } else { (b, a) };
+        keccak::hashv(&[&left, &right]).to_bytes()
+    }
+
+    #[test]
+    fn single_leaf_tree_needs_no_proof() {
+        let agent = Pubkey::new_unique();
+        assert!(verify(&[], &leaf(&agent), leaf(&agent)));
+    }
+
+    #[test]
+    fn valid_proofs_verify_for_every_leaf() {
+        let agents: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = agents.iter().map(leaf).collect();
+        let left = parent(leaves[0], leaves[1]);
+        let right = parent(leaves[2], leaves[3]);
+        let root = parent(left, right);
+
+        assert!(verify(&[leaves[1], right], &root, leaves[0]));
+        assert!(verify(&[leaves[0], right], &root, leaves[1]));
+        assert!(verify(&[leaves[3], left], &root, leaves[2]));
+        assert!(verify(&[leaves[2], left], &root, leaves[3]));
+    }
+
+    #[test]
+    fn invalid_proofs_are_rejected() {
+        let agents: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = agents.iter().map(leaf).collect();
+        let left = parent(leaves[0], leaves[1]);
+        let right = parent(leaves[2], leaves[3]);
+        let root = parent(left, right);
+        let outsider = leaf(&Pubkey::new_unique());
+
+        assert!(!verify(&[leaves[1], right], &root, outsider));
+        assert!(!verify(&[leaves[1]], &root, leaves[0]));
+        assert!(!verify(&[leaves[2], right], &root, leaves[0]));
+        assert!(!verify(&[], &root, leaves[0]));
+    }
+}
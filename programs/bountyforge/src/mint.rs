@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_2022::spl_token_2022::{
+    self,
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::Mint,
+};
+
+use crate::errors::BountyForgeError;
+
+/// Token-2022 extensions that let a mint move a different amount than the
+/// escrow asked for, or move escrowed tokens without the bounty signing.
+/// Settlement relies on the escrow holding exactly `reward`, so these mints
+/// are refused.
+const UNSUPPORTED_EXTENSIONS: [ExtensionType; 3] = [
+    ExtensionType::TransferFeeConfig,
+    ExtensionType::TransferHook,
+    ExtensionType::PermanentDelegate,
+];
+
+/// Checks `mint` can back an SPL bounty and returns its decimals.
+///
+/// Any classic SPL Token mint is allowed. Token-2022 mints are allowed as long
+/// as they carry none of the `UNSUPPORTED_EXTENSIONS`; metadata, interest
+/// bearing, mint close authority and the other extensions leave transfers
+/// alone and are accepted. Token-2022 only insists on `transfer_checked` for
+/// fee and hook mints, so the escrow keeps using the plain `transfer` CPI.
+pub fn ensure_supported_mint(mint: &AccountInfo) -> Result<u8> {
+    let is_token_2022 = *mint.owner == spl_token_2022::ID;
+    require!(
+        *mint.owner == anchor_spl::token::ID || is_token_2022,
+        BountyForgeError::UnsupportedMint
+    );
+
+    let data = mint.try_borrow_data()?;
+    let state = StateWithExtensions::<Mint>::unpack(&data)
+        .map_err(|_| BountyForgeError::UnsupportedMint)?;
+    if is_token_2022 {
+        let extensions = state
+            .get_extension_types()
+            .map_err(|_| BountyForgeError::UnsupportedMint)?;
+        require!(
+            !extensions
+                .iter()
+                .any(|extension| UNSUPPORTED_EXTENSIONS.contains(extension)),
+            BountyForgeError::UnsupportedMint
+        );
+    }
+
+    Ok(state.base.decimals)
+}
@@ -0,0 +1,163 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::BPS_DENOMINATOR;
+use crate::errors::BountyForgeError;
+
+/// Pyth's pull oracle receiver, owner of every `PriceUpdateV2` account.
+pub const PYTH_RECEIVER_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// Anchor discriminator of the receiver's `PriceUpdateV2` account.
+const PRICE_UPDATE_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+// decimals of the USD values derived from a price
+const USD_DECIMALS: i32 = 6;
+
+// mirrors of the receiver's account layout, read without pulling in its SDK
+#[derive(AnchorDeserialize)]
+struct PriceUpdateV2 {
+    _write_authority: Pubkey,
+    verification_level: VerificationLevel,
+    price_message: PriceFeedMessage,
+    _posted_slot: u64,
+}
+
+#[derive(AnchorDeserialize, PartialEq, Eq)]
+enum VerificationLevel {
+    Partial { _num_signatures: u8 },
+    Full,
+}
+
+#[derive(AnchorDeserialize)]
+struct PriceFeedMessage {
+    feed_id: [u8; 32],
+    price: i64,
+    conf: u64,
+    exponent: i32,
+    publish_time: i64,
+    _prev_publish_time: i64,
+    _ema_price: i64,
+    _ema_conf: u64,
+}
+
+/// A token's USD price as published by a Pyth price feed, worth
+/// `price * 10^exponent` dollars give or take `conf` in the same units.
+pub struct PriceFeed {
+    pub feed_id: [u8; 32],
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+impl PriceFeed {
+    /// Reads a fully verified `PriceUpdateV2` account. Partially verified
+    /// updates and non-positive prices are refused.
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        require_keys_eq!(
+            *account.owner,
+            PYTH_RECEIVER_ID,
+            BountyForgeError::InvalidPriceFeed
+        );
+
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= PRICE_UPDATE_DISCRIMINATOR.len()
+                && data[..PRICE_UPDATE_DISCRIMINATOR.len()] == PRICE_UPDATE_DISCRIMINATOR,
+            BountyForgeError::InvalidPriceFeed
+        );
+        let update = PriceUpdateV2::deserialize(&mut &data[PRICE_UPDATE_DISCRIMINATOR.len()..])
+            .map_err(|_| BountyForgeError::InvalidPriceFeed)?;
+        require!(
+            update.verification_level == VerificationLevel::Full && update.price_message.price > 0,
+            BountyForgeError::InvalidPriceFeed
+        );
+
+        let message = update.price_message;
+        Ok(Self {
+            feed_id: message.feed_id,
+            price: message.price,
+            conf: message.conf,
+            exponent: message.exponent,
+            publish_time: message.publish_time,
+        })
+    }
+
+    /// Fails when the price is older than `max_age` seconds or its
+    /// confidence interval is wider than `max_conf_bps` of the price.
+    pub fn check(&self, now: i64, max_age: i64, max_conf_bps: u16) -> Result<()> {
+        require!(
+            now.saturating_sub(self.publish_time) <= max_age,
+            BountyForgeError::StalePriceFeed
+        );
+        require!(
+            self.conf as u128 * BPS_DENOMINATOR as u128
+                <= self.price as u128 * max_conf_bps as u128,
+            BountyForgeError::PriceConfidenceTooWide
+        );
+
+        Ok(())
+    }
+
+    /// USD value of `amount` base units of a token with `decimals`, in
+    /// millionths of a dollar.
+    pub fn usd_value(&self, amount: u64, decimals: u8) -> Result<u64> {
+        let value = (amount as u128)
+            .checked_mul(self.price as u128)
+            .ok_or(BountyForgeError::MathOverflow)?;
+        let shift = self.exponent + USD_DECIMALS - decimals as i32;
+        let scale = 10u128
+            .checked_pow(shift.unsigned_abs())
+            .ok_or(BountyForgeError::MathOverflow)?;
+        let value = if shift >= 0 {
+            value
+                .checked_mul(scale)
+                .ok_or(BountyForgeError::MathOverflow)?
+        } else {
+            value / scale
+        };
+
+        u64::try_from(value).map_err(|_| error!(BountyForgeError::MathOverflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // $150.00 with a $0.15 confidence interval, published at t = 1_000
+    fn feed() -> PriceFeed {
+        PriceFeed {
+            feed_id: [1; 32],
+            price: 15_000_000_000,
+            conf: 15_000_000,
+            exponent: -8,
+            publish_time: 1_000,
+        }
+    }
+
+    #[test]
+    fn usd_value_scales_between_token_and_price_decimals() {
+        // 2 SOL at $150
+        assert_eq!(feed().usd_value(2_000_000_000, 9).unwrap(), 300_000_000);
+        // 2.5 tokens of a 6 decimal mint at $150
+        assert_eq!(feed().usd_value(2_500_000, 6).unwrap(), 375_000_000);
+
+        let whole_dollars = PriceFeed {
+            price: 3,
+            exponent: 2,
+            ..feed()
+        };
+        // 1 token of a 0 decimal mint at $300
+        assert_eq!(whole_dollars.usd_value(1, 0).unwrap(), 300_000_000);
+    }
+
+    #[test]
+    fn stale_or_uncertain_prices_are_refused() {
+        let feed = feed();
+
+        assert!(feed.check(1_060, 60, 10).is_ok());
+        assert!(feed.check(1_061, 60, 10).is_err());
+        // the interval is 0.1% of the price
+        assert!(feed.check(1_000, 60, 9).is_err());
+    }
+}
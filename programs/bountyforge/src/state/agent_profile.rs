@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::{MAX_HANDLE_LEN, MAX_METADATA_URI_LEN};
+use crate::errors::BountyForgeError;
+
+/// Display details an agent publishes about itself, shown by front-ends next
+/// to its `Reputation`.
+#[account]
+#[derive(InitSpace)]
+pub struct AgentProfile {
+    pub agent: Pubkey,
+    #[max_len(MAX_HANDLE_LEN)]
+    pub handle: Option<String>, // display name, None to stay anonymous
+    #[max_len(MAX_METADATA_URI_LEN)]
+    pub metadata_uri: String, // off-chain avatar, bio and links
+    pub joined_at: i64,
+    pub bump: u8,
+}
+
+impl AgentProfile {
+    /// Replaces the handle and metadata URI, failing if either is too long.
+    pub fn set_metadata(&mut self, handle: Option<String>, metadata_uri: String) -> Result<()> {
+        require!(
+            handle.as_ref().map_or(0, String::len) <= MAX_HANDLE_LEN
+                && metadata_uri.len() <= MAX_METADATA_URI_LEN,
+            BountyForgeError::MetadataTooLong
+        );
+
+        self.handle = handle;
+        self.metadata_uri = metadata_uri;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn profile() -> AgentProfile {
+        AgentProfile {
+            agent: Pubkey::new_unique(),
+            handle: Some("solver".to_string()),
+            metadata_uri: String::new(),
+            joined_at: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn metadata_is_replaced_and_the_handle_can_be_cleared() {
+        let mut profile = profile();
+
+        profile
+            .set_metadata(None, "ipfs://profile".to_string())
+            .unwrap();
+        assert!(profile.handle.is_none());
+        assert_eq!(profile.metadata_uri, "ipfs://profile");
+    }
+
+    #[test]
+    fn overlong_metadata_leaves_the_profile_untouched() {
+        let mut profile = profile();
+
+        assert!(profile
+            .set_metadata(Some("x".repeat(MAX_HANDLE_LEN + 1)), String::new())
+            .is_err());
+        assert!(profile
+            .set_metadata(None, "x".repeat(MAX_METADATA_URI_LEN + 1))
+            .is_err());
+        assert_eq!(profile.handle.as_deref(), Some("solver"));
+    }
+}
@@ -4,9 +4,12 @@ use anchor_lang::prelude::*;
 #[derive(InitSpace)]
 pub struct Attestation {
     pub solution_id: u64,
+    pub bounty_id: u64,
     pub solution_hash: [u8; 32],
     pub timestamp: i64,
     pub agent: Pubkey,
     pub verified: bool,
+    pub verified_at: i64,
+    pub revoked: bool, // withdrawn by the agent, blocks verification and submission
     pub bump: u8,
 }
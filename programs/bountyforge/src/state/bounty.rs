@@ -1,21 +1,490 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{MAX_DESCRIPTION_URI_LEN, MAX_MILESTONES, MAX_TAGS, MAX_TITLE_LEN};
+use crate::errors::BountyForgeError;
+use crate::state::ReputationTier;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Bounty {
     pub id: u64,
     #[max_len(50)]
     pub description: String,
+    #[max_len(MAX_TITLE_LEN)]
+    pub title: String,
+    #[max_len(MAX_DESCRIPTION_URI_LEN)]
+    pub description_uri: String, // off-chain content
+    #[max_len(MAX_TAGS)]
+    pub tags: Vec<u32>,
     pub reward: u64, // lamports
+    pub reward_kind: RewardKind,
+    pub reward_mint: Pubkey, // unused for SOL bounties
+    pub reward_decimals: u8, // decimals of reward_mint, 9 for SOL bounties
     pub solution_hash: Option<[u8; 32]>,
+    pub solution_kind: u8,  // format of the artifact behind solution_hash, see Submission
+    pub solution_size: u32, // byte length of the artifact behind solution_hash
+    pub last_rejected_hash: Option<[u8; 32]>, // cannot be resubmitted until settlement
+    pub agent: Option<Pubkey>, // agent behind solution_hash
+    pub revision: u16,         // times the agent replaced the submitted solution
+    pub rejection_count: u8,   // solutions rejected, auto-cancels at config.max_rejections
+    pub submission_count: u32, // reveals ever made, any blocks cancel_bounty
+    pub submission_fees: u64,  // lamports agents paid to submit, go to the winner
+    pub open_submissions: u32, // submission accounts not yet closed, any block cleanup_bounty
     pub status: BountyStatus,
     pub creator: Pubkey,
+    pub deadline: i64,             // unix timestamp
+    pub competition: bool,         // accepts competing submissions until settled
+    pub settle_unlock_at: i64,     // end of the dispute window once settlement is proposed
+    pub min_tier: ReputationTier,  // lowest agent tier allowed to submit
+    pub min_reputation_score: u64, // lowest agent score allowed to submit
+    pub whitelist_root: [u8; 32],  // Merkle root of allowed agents, all zeros for open bounties
+    pub difficulty: u8,            // 1-5, score an agent gains by submitting
+    #[max_len(MAX_MILESTONES)]
+    pub milestones: Vec<Milestone>, // staged payouts summing to reward, empty pays in one go
+    pub contributed: u64,          // part of reward funded through contribute_to_bounty
+    pub settle_delegate: Option<Pubkey>, // may call settle_bounty on the creator's behalf
+    pub criteria_hash: [u8; 32], // hash of the off-chain acceptance criteria, fixed at creation
+    pub category_bits: u64,      // bitmap of categories, bit n set for category n
+    pub max_reward_cap: u64,     // top-up ceiling on reward, 0 for none
+    pub claimable_amount: u64,   // settled payout the agent has yet to pull with claim_reward
+    pub reputation_score_at_submit: u64, // winning submission's snapshot, see Submission
+    pub tier_at_submit: ReputationTier,  // winning submission's snapshot, prices the fee
+    pub min_payout: u64,                 // winning submission's minimum net payout, 0 for any
+    pub submitted_at: i64,               // when the winning submission was revealed
+    pub settled_promptly: bool,          // settle_bounty came within config.prompt_settle_window
+    pub required_attestations: u8,       // distinct verified attesters a solution needs
+    pub reward_usd_at_creation: u64,     // reward's USD value when posted, in millionths, 0 without a price feed
+    pub price_feed_id: [u8; 32],         // Pyth feed reward_usd_at_creation was priced with, zeros for none
+    pub vesting_seconds: i64,            // schedule finalize_settlement releases the payout over, 0 pays at once
+    pub encryption_scheme: u8,           // scheme solutions are encrypted with, see MAX_ENCRYPTION_SCHEME
+    pub delivery_pubkey: Pubkey,         // winning submission's key-exchange pubkey, default for public delivery
+    pub flagged: bool,                   // quarantined by a moderator, blocks new submissions
+    pub vault_bump: u8,                  // [b"vault", id] PDA owning the escrow token account
     pub bump: u8,
 }
 
+impl Bounty {
+    /// Part of the reward already paid out through released milestones.
+    pub fn released_reward(&self) -> u64 {
+        self.milestones
+            .iter()
+            .filter(|milestone| milestone.released)
+            .map(|milestone| milestone.amount)
+            .sum()
+    }
+
+    /// Part of the reward still held in escrow.
+    pub fn unreleased_reward(&self) -> u64 {
+        self.reward.saturating_sub(self.released_reward())
+    }
+
+    pub fn all_milestones_released(&self) -> bool {
+        self.milestones.iter().all(|milestone| milestone.released)
+    }
+
+    /// Part of the reward funded by the creator rather than contributors.
+    pub fn creator_share(&self) -> u64 {
+        self.reward.saturating_sub(self.contributed)
+    }
+
+    /// Refund owed on a `share` of the reward, scaled down by whatever
+    /// milestones already paid out so every funder loses the same fraction.
+    pub fn refund_for(&self, share: u64) -> u64 {
+        if self.reward == 0 {
+            return 0;
+        }
+        (share as u128 * self.unreleased_reward() as u128 / self.reward as u128) as u64
+    }
+
+    /// Reward in whole tokens rather than base units, for display only.
+    pub fn reward_ui(&self, decimals: u8) -> f64 {
+        self.reward as f64 / 10f64.powi(decimals as i32)
+    }
+
+    /// Whether `signer` may propose a settlement: the creator or its delegate.
+    pub fn can_settle(&self, signer: &Pubkey) -> bool {
+        *signer == self.creator || self.settle_delegate == Some(*signer)
+    }
+
+    /// Whether a revealed submission still awaits a decision. A single-winner
+    /// bounty holds its submission in `solution_hash` until it is rejected,
+    /// forfeited or reopened, while competition submissions stay live until
+    /// the bounty is settled.
+    pub fn has_active_submission(&self) -> bool {
+        self.solution_hash.is_some() || (self.competition && self.submission_count > 0)
+    }
+
+    /// Records that one of the bounty's submission accounts was closed.
+    /// Saturates so submissions revealed before the counter existed can still
+    /// be closed.
+    pub fn submission_closed(&mut self) {
+        self.open_submissions = self.open_submissions.saturating_sub(1);
+    }
+
+    /// Whether `reward` is within the creator's `max_reward_cap`.
+    pub fn within_reward_cap(&self) -> bool {
+        self.max_reward_cap == 0 || self.reward <= self.max_reward_cap
+    }
+
+    /// Replaces the reward with `reward`, scaling every milestone by the same
+    /// factor. The last milestone takes the rounding so the amounts still add
+    /// up to the reward.
+    pub fn reprice(&mut self, reward: u64) -> Result<()> {
+        let old_reward = self.reward;
+        self.reward = reward;
+
+        let Some((last, rest)) = self.milestones.split_last_mut() else {
+            return Ok(());
+        };
+        let mut scaled_total = 0u64;
+        for milestone in rest {
+            milestone.amount = (milestone.amount as u128 * reward as u128
+                / old_reward.max(1) as u128) as u64;
+            scaled_total = scaled_total
+                .checked_add(milestone.amount)
+                .ok_or(BountyForgeError::MathOverflow)?;
+        }
+        last.amount = reward
+            .checked_sub(scaled_total)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Whether category `bit` is set in `category_bits`. Bits past 63 are
+    /// never set.
+    pub fn has_category(&self, bit: u8) -> bool {
+        1u64.checked_shl(bit as u32)
+            .is_some_and(|mask| self.category_bits & mask != 0)
+    }
+
+    /// Fails when `payout`, net of fees, is below the minimum the agent set
+    /// on the winning submission.
+    pub fn check_min_payout(&self, payout: u64) -> Result<()> {
+        require!(
+            payout >= self.min_payout,
+            BountyForgeError::PayoutBelowMinimum
+        );
+        Ok(())
+    }
+
+    /// Moves the bounty from `from` to `to`, failing if it is no longer in
+    /// `from`. Handlers call it last, after their CPIs, so a status is only
+    /// ever advanced from the one the handler validated.
+    pub fn transition(&mut self, from: BountyStatus, to: BountyStatus) -> Result<()> {
+        require!(
+            self.status == from,
+            BountyForgeError::InvalidStatusTransition
+        );
+        self.status = to;
+        Ok(())
+    }
+
+    pub fn summary(&self) -> BountySummary {
+        BountySummary {
+            id: self.id,
+            status: self.status,
+            reward: self.reward,
+            reward_decimals: self.reward_decimals,
+            creator: self.creator,
+            has_solution: self.solution_hash.is_some(),
+        }
+    }
+}
+
+/// Headline fields of a bounty, one shape for every client instead of each
+/// decoding the full account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub struct BountySummary {
+    pub id: u64,
+    pub status: BountyStatus,
+    pub reward: u64,
+    pub reward_decimals: u8,
+    pub creator: Pubkey,
+    pub has_solution: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct Milestone {
+    pub amount: u64,
+    pub released: bool,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum BountyStatus {
     Open,
     Submitted,
+    PendingSettlement,
+    Disputed,
     Settled,
+    Expired,
+    Cancelled,
+}
+
+impl BountyStatus {
+    pub fn is_final(&self) -> bool {
+        matches!(
+            self,
+            BountyStatus::Settled | BountyStatus::Expired | BountyStatus::Cancelled
+        )
+    }
+}
+
+/// How an arbiter settles a disputed bounty.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum DisputeOutcome {
+    PayAgent,
+    RefundCreator,
+}
+
+/// Where the reward is escrowed: an SPL token account owned by the bounty's
+/// `[b"vault", id]` PDA, or lamports held directly on the bounty PDA.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RewardKind {
+    Spl,
+    Sol,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bounty() -> Bounty {
+        Bounty {
+            id: 7,
+            description: String::new(),
+            title: String::new(),
+            description_uri: String::new(),
+            tags: Vec::new(),
+            reward: 1_000,
+            reward_kind: RewardKind::Spl,
+            reward_mint: Pubkey::default(),
+            reward_decimals: 6,
+            solution_hash: None,
+            solution_kind: 0,
+            solution_size: 0,
+            last_rejected_hash: None,
+            agent: None,
+            revision: 0,
+            rejection_count: 0,
+            submission_count: 0,
+            submission_fees: 0,
+            open_submissions: 0,
+            status: BountyStatus::Open,
+            creator: Pubkey::new_unique(),
+            deadline: 0,
+            competition: false,
+            settle_unlock_at: 0,
+            min_tier: ReputationTier::Novice,
+            min_reputation_score: 0,
+            whitelist_root: [0; 32],
+            difficulty: 1,
+            milestones: Vec::new(),
+            contributed: 0,
+            settle_delegate: None,
+            criteria_hash: [3; 32],
+            category_bits: 0,
+            max_reward_cap: 0,
+            claimable_amount: 0,
+            reputation_score_at_submit: 0,
+            tier_at_submit: ReputationTier::Novice,
+            min_payout: 0,
+            submitted_at: 0,
+            settled_promptly: false,
+            required_attestations: 1,
+            reward_usd_at_creation: 0,
+            price_feed_id: [0; 32],
+            vesting_seconds: 0,
+            encryption_scheme: 0,
+            delivery_pubkey: Pubkey::default(),
+            flagged: false,
+            vault_bump: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn summary_of_an_open_bounty() {
+        let bounty = bounty();
+        let summary = bounty.summary();
+
+        assert_eq!(summary.id, 7);
+        assert_eq!(summary.reward, 1_000);
+        assert_eq!(summary.creator, bounty.creator);
+        assert!(summary.status == BountyStatus::Open);
+        assert!(!summary.has_solution);
+    }
+
+    #[test]
+    fn summary_reports_a_submitted_solution() {
+        let bounty = Bounty {
+            solution_hash: Some([1; 32]),
+            status: BountyStatus::Submitted,
+            ..bounty()
+        };
+        let summary = bounty.summary();
+
+        assert!(summary.status == BountyStatus::Submitted);
+        assert!(summary.has_solution);
+    }
+
+    #[test]
+    fn reward_ui_scales_usdc_by_six_decimals() {
+        let bounty = Bounty {
+            reward: 12_500_000,
+            ..bounty()
+        };
+
+        assert_eq!(bounty.reward_ui(6), 12.5);
+        assert_eq!(bounty.reward_ui(bounty.reward_decimals), 12.5);
+    }
+
+    #[test]
+    fn reward_ui_scales_sol_by_nine_decimals() {
+        let bounty = Bounty {
+            reward: 1_500_000_000,
+            reward_kind: RewardKind::Sol,
+            reward_decimals: 9,
+            ..bounty()
+        };
+
+        assert_eq!(bounty.reward_ui(9), 1.5);
+        assert_eq!(bounty.reward_ui(0), 1_500_000_000.0);
+    }
+
+    #[test]
+    fn settlement_is_limited_to_the_creator_and_its_delegate() {
+        let delegate = Pubkey::new_unique();
+        let delegated = Bounty {
+            settle_delegate: Some(delegate),
+            ..bounty()
+        };
+
+        assert!(delegated.can_settle(&delegated.creator));
+        assert!(delegated.can_settle(&delegate));
+        assert!(!delegated.can_settle(&Pubkey::new_unique()));
+        assert!(!bounty().can_settle(&delegate));
+    }
+
+    #[test]
+    fn closing_a_submission_saturates() {
+        let mut bounty = Bounty {
+            open_submissions: 1,
+            ..bounty()
+        };
+
+        bounty.submission_closed();
+        assert_eq!(bounty.open_submissions, 0);
+        bounty.submission_closed();
+        assert_eq!(bounty.open_submissions, 0);
+    }
+
+    #[test]
+    fn active_submissions_follow_the_bounty_kind() {
+        let reopened = Bounty {
+            submission_count: 1,
+            ..bounty()
+        };
+        let submitted = Bounty {
+            solution_hash: Some([1; 32]),
+            ..reopened.clone()
+        };
+        let competing = Bounty {
+            competition: true,
+            ..reopened.clone()
+        };
+
+        assert!(!bounty().has_active_submission());
+        assert!(!reopened.has_active_submission());
+        assert!(submitted.has_active_submission());
+        assert!(competing.has_active_submission());
+    }
+
+    #[test]
+    fn categories_are_read_from_their_bits() {
+        let tagged = Bounty {
+            category_bits: (1 << 0) | (1 << 5) | (1 << 63),
+            ..bounty()
+        };
+
+        assert!(tagged.has_category(0));
+        assert!(tagged.has_category(5));
+        assert!(tagged.has_category(63));
+        assert!(!tagged.has_category(1));
+        assert!(!tagged.has_category(64));
+        assert!(!tagged.has_category(u8::MAX));
+        assert!(!bounty().has_category(0));
+    }
+
+    #[test]
+    fn reward_cap_of_zero_is_unbounded() {
+        let capped = Bounty {
+            max_reward_cap: 1_000,
+            ..bounty()
+        };
+        let overfunded = Bounty {
+            reward: 1_001,
+            ..capped.clone()
+        };
+        let uncapped = Bounty {
+            reward: u64::MAX,
+            ..bounty()
+        };
+
+        assert!(capped.within_reward_cap());
+        assert!(!overfunded.within_reward_cap());
+        assert!(uncapped.within_reward_cap());
+    }
+
+    #[test]
+    fn reprice_keeps_milestones_proportional() {
+        let mut bounty = Bounty {
+            reward: 100,
+            milestones: [30, 30, 40]
+                .into_iter()
+                .map(|amount| Milestone {
+                    amount,
+                    released: false,
+                })
+                .collect(),
+            ..bounty()
+        };
+
+        bounty.reprice(1_000).unwrap();
+        assert_eq!(bounty.reward, 1_000);
+        let amounts: Vec<u64> = bounty.milestones.iter().map(|m| m.amount).collect();
+        assert_eq!(amounts, vec![300, 300, 400]);
+
+        // the last milestone absorbs the rounding
+        bounty.reprice(7).unwrap();
+        let amounts: Vec<u64> = bounty.milestones.iter().map(|m| m.amount).collect();
+        assert_eq!(amounts, vec![2, 2, 3]);
+    }
+
+    #[test]
+    fn transition_only_moves_from_the_expected_status() {
+        let mut bounty = bounty();
+
+        assert!(bounty
+            .transition(BountyStatus::Submitted, BountyStatus::PendingSettlement)
+            .is_err());
+        assert!(bounty.status == BountyStatus::Open);
+
+        bounty
+            .transition(BountyStatus::Open, BountyStatus::Submitted)
+            .unwrap();
+        assert!(bounty.status == BountyStatus::Submitted);
+    }
+
+    #[test]
+    fn min_payout_of_zero_accepts_any_payout() {
+        assert!(bounty().check_min_payout(0).is_ok());
+
+        let guarded = Bounty {
+            min_payout: 500,
+            ..bounty()
+        };
+        assert!(guarded.check_min_payout(500).is_ok());
+        assert!(guarded.check_min_payout(499).is_err());
+    }
 }
@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Commitment {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub commitment: [u8; 32], // keccak(solution_hash || agent || nonce)
+    pub committed_at: i64,
+    pub bump: u8,
+}
+
+impl Commitment {
+    /// Hash an agent commits to before revealing its solution hash.
+    pub fn compute(solution_hash: &[u8; 32], agent: &Pubkey, nonce: &[u8; 32]) -> [u8; 32] {
+        keccak::hashv(&[solution_hash, agent.as_ref(), nonce]).to_bytes()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commitment_is_bound_to_the_agent() {
+        let solution_hash = [7u8; 32];
+        let nonce = [9u8; 32];
+        let agent = Pubkey::new_unique();
+        let copycat = Pubkey::new_unique();
+
+        let commitment = Commitment::compute(&solution_hash, &agent, &nonce);
+
+        assert_eq!(
+            commitment,
+            Commitment::compute(&solution_hash, &agent, &nonce)
+        );
+        assert_ne!(
+            commitment,
+            Commitment::compute(&solution_hash, &copycat, &nonce)
+        );
+        assert_ne!(
+            commitment,
+            Commitment::compute(&solution_hash, &agent, &[0u8; 32])
+        );
+    }
+}
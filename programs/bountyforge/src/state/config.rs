@@ -0,0 +1,317 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::{BPS_DENOMINATOR, MAX_MODERATORS},
+    errors::BountyForgeError,
+    state::ReputationTier,
+};
+
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub admin: Pubkey,
+    pub verifier: Pubkey,          // authorized to verify attestations
+    pub arbiter: Pubkey,           // decides disputed settlements
+    pub treasury: Pubkey,          // owner of the fee token accounts
+    pub fee_bps: u16,              // platform fee taken on settlement
+    pub fee_bps_for_tier: [u16; 4], // fee cap per ReputationTier, Novice to Elite
+    pub referral_bps: u16,         // share of SOL bounty fees credited to the agent's referrer
+    pub dispute_window: i64,       // seconds between settle_bounty and finalize_settlement
+    pub reputation_half_life: i64, // seconds for an idle score to halve, 0 disables decay
+    pub stake_amount: u64,         // lamports an agent locks per submission
+    pub min_reward: u64,           // smallest reward post_bounty accepts
+    pub max_reward: u64,           // largest reward post_bounty accepts
+    pub reveal_delay: i64,         // seconds between commit_solution and reveal_solution
+    pub submission_timeout: i64,   // seconds before a creator can reopen an unreviewed submission
+    pub grace_period: i64,         // seconds after the deadline before expire_submitted pays out
+    pub submission_cooldown: i64,  // seconds an agent waits between reveals, 0 disables
+    pub min_attestation_age: i64,  // seconds between attest_solution and revealing against it
+    pub score_on_submit: u64,      // score per difficulty point gained on reveal
+    pub score_on_settle: u64,      // score per difficulty point gained on settlement
+    pub submission_fee: u64,       // lamports an agent pays into the bounty per reveal
+    pub rejection_penalty: u64,    // score reject_solution takes from an agent with no failures
+    pub rejection_penalty_step: u64, // extra score taken per bounty the agent already failed
+    pub max_rejection_penalty: u64,  // ceiling on the escalated rejection penalty
+    pub forfeit_penalty: u64,      // score taken from the agent by forfeit_submission
+    pub max_rejections: u8,        // rejections that auto-cancel a bounty, 0 disables
+    pub enable_memos: bool,        // tags settlement payouts with a bf:settle memo
+    pub max_concurrent_submissions: u16, // open submissions an agent may hold, 0 is unlimited
+    pub max_price_age: i64,        // seconds a price feed may lag behind post_bounty
+    pub max_price_conf_bps: u16,   // widest price confidence interval accepted, in bps of the price
+    pub prompt_settle_window: i64, // seconds after submission within which settle_bounty earns a bonus, 0 disables
+    pub prompt_settle_bonus_bps: u16, // share of the fee paid to the agent instead of the treasury on prompt settlements
+    pub paused: bool,              // blocks new activity, refunds stay available
+    pub total_escrowed: u64,       // reward still held across all bounties, base units of any mint
+    pub bounty_counter: u64,       // id post_bounty assigns to the next bounty
+    #[max_len(MAX_MODERATORS)]
+    pub moderators: Vec<Pubkey>,   // may flag bounties alongside the admin
+    pub bump: u8,
+}
+
+impl Config {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.fee_bps as u64 <= BPS_DENOMINATOR,
+            BountyForgeError::FeeTooHigh
+        );
+        require!(
+            self.fee_bps_for_tier
+                .iter()
+                .all(|bps| *bps as u64 <= BPS_DENOMINATOR),
+            BountyForgeError::FeeTooHigh
+        );
+        require!(
+            self.referral_bps as u64 <= BPS_DENOMINATOR,
+            BountyForgeError::InvalidReferralBps
+        );
+        require!(
+            self.dispute_window >= 0,
+            BountyForgeError::InvalidDisputeWindow
+        );
+        require!(
+            self.min_reward <= self.max_reward,
+            BountyForgeError::InvalidRewardBounds
+        );
+        require!(self.reveal_delay >= 0, BountyForgeError::InvalidRevealDelay);
+        require!(
+            self.submission_timeout >= 0,
+            BountyForgeError::InvalidSubmissionTimeout
+        );
+        require!(self.grace_period >= 0, BountyForgeError::InvalidGracePeriod);
+        require!(
+            self.submission_cooldown >= 0,
+            BountyForgeError::InvalidSubmissionCooldown
+        );
+        require!(
+            self.min_attestation_age >= 0,
+            BountyForgeError::InvalidMinAttestationAge
+        );
+        require!(
+            self.forfeit_penalty <= self.rejection_penalty,
+            BountyForgeError::InvalidForfeitPenalty
+        );
+        require!(
+            self.rejection_penalty <= self.max_rejection_penalty,
+            BountyForgeError::InvalidRejectionPenalty
+        );
+        require!(
+            self.max_price_age >= 0 && self.max_price_conf_bps as u64 <= BPS_DENOMINATOR,
+            BountyForgeError::InvalidOracleSettings
+        );
+        require!(
+            self.prompt_settle_window >= 0
+                && self.prompt_settle_bonus_bps as u64 <= BPS_DENOMINATOR,
+            BountyForgeError::InvalidPromptSettleSettings
+        );
+        require!(
+            self.moderators.len() <= MAX_MODERATORS,
+            BountyForgeError::TooManyModerators
+        );
+
+        Ok(())
+    }
+
+    /// Counts `amount` of reward entering a bounty's escrow.
+    pub fn add_escrowed(&mut self, amount: u64) -> Result<()> {
+        self.total_escrowed = self
+            .total_escrowed
+            .checked_add(amount)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Counts `amount` of reward leaving a bounty's escrow, whether paid out,
+    /// taken as a fee or refunded.
+    pub fn sub_escrowed(&mut self, amount: u64) -> Result<()> {
+        self.total_escrowed = self
+            .total_escrowed
+            .checked_sub(amount)
+            .ok_or(BountyForgeError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    pub fn fee_for(&self, reward: u64) -> Result<u64> {
+        Self::fee_at(reward, self.fee_bps)
+    }
+
+    /// Fee on a payout to an agent of `tier`. The tier's entry in
+    /// `fee_bps_for_tier` can only lower the flat `fee_bps`, never raise it.
+    pub fn fee_for_tier(&self, reward: u64, tier: ReputationTier) -> Result<u64> {
+        let bps = self.fee_bps.min(self.fee_bps_for_tier[tier as usize]);
+        Self::fee_at(reward, bps)
+    }
+
+    fn fee_at(reward: u64, bps: u16) -> Result<u64> {
+        let fee = (reward as u128)
+            .checked_mul(bps as u128)
+            .ok_or(BountyForgeError::MathOverflow)?
+            / BPS_DENOMINATOR as u128;
+
+        Ok(fee as u64)
+    }
+
+    /// Score a reveal earns on a bounty of `difficulty`.
+    pub fn submit_score(&self, difficulty: u8) -> Result<u64> {
+        self.score_on_submit
+            .checked_mul(difficulty as u64)
+            .ok_or(error!(BountyForgeError::ReputationScoreOverflow))
+    }
+
+    /// Score a settlement earns on a bounty of `difficulty`.
+    pub fn settle_score(&self, difficulty: u8) -> Result<u64> {
+        self.score_on_settle
+            .checked_mul(difficulty as u64)
+            .ok_or(error!(BountyForgeError::ReputationScoreOverflow))
+    }
+
+    /// Score reject_solution, or a dispute resolved for the creator, takes from
+    /// an agent that already failed `failed_bounties` bounties, growing by
+    /// `rejection_penalty_step` per failure up to `max_rejection_penalty`.
+    pub fn rejection_penalty_for(&self, failed_bounties: u64) -> u64 {
+        failed_bounties
+            .saturating_mul(self.rejection_penalty_step)
+            .saturating_add(self.rejection_penalty)
+            .min(self.max_rejection_penalty)
+    }
+
+    pub fn referral_cut(&self, fee: u64) -> Result<u64> {
+        let cut = (fee as u128)
+            .checked_mul(self.referral_bps as u128)
+            .ok_or(BountyForgeError::MathOverflow)?
+            / BPS_DENOMINATOR as u128;
+
+        Ok(cut as u64)
+    }
+
+    /// Whether a settlement proposed at `now` on a solution submitted at
+    /// `submitted_at` came within `prompt_settle_window`, window end included.
+    pub fn is_prompt_settlement(&self, submitted_at: i64, now: i64) -> bool {
+        self.prompt_settle_window > 0
+            && now.saturating_sub(submitted_at) <= self.prompt_settle_window
+    }
+
+    /// Part of `fee` handed to the agent on a prompt settlement.
+    pub fn prompt_settle_bonus(&self, fee: u64) -> Result<u64> {
+        let bonus = (fee as u128)
+            .checked_mul(self.prompt_settle_bonus_bps as u128)
+            .ok_or(BountyForgeError::MathOverflow)?
+            / BPS_DENOMINATOR as u128;
+
+        Ok(bonus as u64)
+    }
+
+    /// Whether `key` may flag and unflag bounties, the admin always can.
+    pub fn is_moderator(&self, key: &Pubkey) -> bool {
+        *key == self.admin || self.moderators.contains(key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        Config {
+            admin: Pubkey::default(),
+            verifier: Pubkey::default(),
+            arbiter: Pubkey::default(),
+            treasury: Pubkey::default(),
+            fee_bps: 0,
+            fee_bps_for_tier: [0; 4],
+            referral_bps: 0,
+            dispute_window: 0,
+            reputation_half_life: 0,
+            stake_amount: 0,
+            min_reward: 0,
+            max_reward: u64::MAX,
+            reveal_delay: 0,
+            submission_timeout: 0,
+            grace_period: 0,
+            submission_cooldown: 0,
+            min_attestation_age: 0,
+            score_on_submit: 0,
+            score_on_settle: 0,
+            submission_fee: 0,
+            rejection_penalty: 2,
+            rejection_penalty_step: 3,
+            max_rejection_penalty: 10,
+            forfeit_penalty: 0,
+            max_rejections: 0,
+            enable_memos: false,
+            max_concurrent_submissions: 0,
+            max_price_age: 0,
+            max_price_conf_bps: 0,
+            prompt_settle_window: 0,
+            prompt_settle_bonus_bps: 0,
+            paused: false,
+            total_escrowed: 0,
+            bounty_counter: 0,
+            moderators: vec![],
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn rejection_penalty_escalates_with_failures_up_to_the_cap() {
+        let config = config();
+
+        assert_eq!(config.rejection_penalty_for(0), 2);
+        assert_eq!(config.rejection_penalty_for(1), 5);
+        assert_eq!(config.rejection_penalty_for(2), 8);
+        assert_eq!(config.rejection_penalty_for(3), 10);
+        assert_eq!(config.rejection_penalty_for(u64::MAX), 10);
+    }
+
+    #[test]
+    fn flat_rejection_penalty_without_a_step() {
+        let config = Config {
+            rejection_penalty_step: 0,
+            ..config()
+        };
+
+        assert_eq!(config.rejection_penalty_for(0), 2);
+        assert_eq!(config.rejection_penalty_for(50), 2);
+    }
+
+    #[test]
+    fn prompt_settlement_window_includes_its_end() {
+        let prompt = Config {
+            prompt_settle_window: 100,
+            ..config()
+        };
+
+        assert!(prompt.is_prompt_settlement(1_000, 1_000));
+        assert!(prompt.is_prompt_settlement(1_000, 1_100));
+        assert!(!prompt.is_prompt_settlement(1_000, 1_101));
+        // a zero window turns the bonus off
+        assert!(!config().is_prompt_settlement(1_000, 1_000));
+    }
+
+    #[test]
+    fn prompt_settle_bonus_is_a_share_of_the_fee() {
+        let prompt = Config {
+            prompt_settle_bonus_bps: 2_500,
+            ..config()
+        };
+
+        assert_eq!(prompt.prompt_settle_bonus(1_000).unwrap(), 250);
+        assert_eq!(prompt.prompt_settle_bonus(3).unwrap(), 0);
+        assert_eq!(config().prompt_settle_bonus(1_000).unwrap(), 0);
+    }
+
+    #[test]
+    fn admin_and_listed_moderators_may_flag() {
+        let moderator = Pubkey::new_unique();
+        let config = Config {
+            admin: Pubkey::new_unique(),
+            moderators: vec![moderator],
+            ..config()
+        };
+
+        assert!(config.is_moderator(&config.admin));
+        assert!(config.is_moderator(&moderator));
+        assert!(!config.is_moderator(&Pubkey::new_unique()));
+    }
+}
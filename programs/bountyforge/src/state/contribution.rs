@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Contribution {
+    pub bounty_id: u64,
+    pub funder: Pubkey,
+    pub amount: u64, // total added to the bounty reward by this funder
+    pub bump: u8,
+}
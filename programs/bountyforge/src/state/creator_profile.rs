@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+
+/// Track record of a bounty creator, the counterpart to an agent's `Reputation`.
+#[account]
+#[derive(InitSpace)]
+pub struct CreatorProfile {
+    pub creator: Pubkey,
+    pub bounties_created: u64,
+    pub bounties_settled: u64,
+    pub bounties_cancelled: u64,
+    pub total_paid: u64, // rewards of settled bounties, fees included
+    pub bump: u8,
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+
+use crate::constants::MAX_HISTORY_ENTRIES;
+use crate::state::BountyStatus;
+
+/// On-chain timeline of a bounty's status transitions, kept alongside the
+/// bounty so its lifecycle survives log pruning.
+#[account]
+#[derive(InitSpace)]
+pub struct BountyHistory {
+    pub bounty_id: u64,
+    #[max_len(MAX_HISTORY_ENTRIES)]
+    pub entries: Vec<HistoryEntry>, // oldest first, the oldest is evicted once full
+    pub total_recorded: u32, // entries ever recorded, evicted ones included
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct HistoryEntry {
+    pub status: BountyStatus, // status the bounty moved to
+    pub timestamp: i64,
+    pub actor: Pubkey, // account that triggered the transition
+}
+
+impl BountyHistory {
+    /// Appends a transition, evicting the oldest entry when the log is full.
+    pub fn record(&mut self, status: BountyStatus, timestamp: i64, actor: Pubkey) {
+        if self.entries.len() >= MAX_HISTORY_ENTRIES {
+            self.entries.remove(0);
+        }
+        self.entries.push(HistoryEntry {
+            status,
+            timestamp,
+            actor,
+        });
+        self.total_recorded = self.total_recorded.saturating_add(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history() -> BountyHistory {
+        BountyHistory {
+            bounty_id: 7,
+            entries: Vec::new(),
+            total_recorded: 0,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn record_appends_in_order() {
+        let mut history = history();
+        let creator = Pubkey::new_unique();
+        let agent = Pubkey::new_unique();
+
+        history.record(BountyStatus::Open, 1, creator);
+        history.record(BountyStatus::Submitted, 2, agent);
+
+        assert_eq!(history.entries.len(), 2);
+        assert!(history.entries[0].status == BountyStatus::Open);
+        assert_eq!(history.entries[0].actor, creator);
+        assert!(history.entries[1].status == BountyStatus::Submitted);
+        assert_eq!(history.entries[1].timestamp, 2);
+        assert_eq!(history.total_recorded, 2);
+    }
+
+    #[test]
+    fn record_evicts_the_oldest_entry_once_full() {
+        let mut history = history();
+        let actor = Pubkey::new_unique();
+
+        for timestamp in 0..MAX_HISTORY_ENTRIES as i64 + 3 {
+            history.record(BountyStatus::Open, timestamp, actor);
+        }
+
+        assert_eq!(history.entries.len(), MAX_HISTORY_ENTRIES);
+        assert_eq!(history.entries[0].timestamp, 3);
+        assert_eq!(
+            history.entries[MAX_HISTORY_ENTRIES - 1].timestamp,
+            MAX_HISTORY_ENTRIES as i64 + 2
+        );
+        assert_eq!(history.total_recorded, MAX_HISTORY_ENTRIES as u32 + 3);
+    }
+}
@@ -1,7 +1,25 @@
+pub mod agent_profile;
 pub mod attestation;
 pub mod bounty;
+pub mod commitment;
+pub mod config;
+pub mod contribution;
+pub mod creator_profile;
+pub mod history;
 pub mod reputation;
+pub mod stake;
+pub mod submission;
+pub mod vesting;
 
+pub use agent_profile::*;
 pub use attestation::*;
 pub use bounty::*;
-pub use reputation::*;
\ No newline at end of file
+pub use commitment::*;
+pub use config::*;
+pub use contribution::*;
+pub use creator_profile::*;
+pub use history::*;
+pub use reputation::*;
+pub use stake::*;
+pub use submission::*;
+pub use vesting::*;
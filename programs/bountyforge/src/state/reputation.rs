@@ -1,12 +1,507 @@
 use anchor_lang::prelude::*;
 
+use crate::constants::{
+    BADGE_THRESHOLDS, BPS_DENOMINATOR, MAX_WITHDRAW_WHITELIST, RANK_EARNINGS_DIVISOR,
+    RANK_SCORE_WEIGHT, RANK_SUCCESS_WEIGHT, REPUTATION_SNAPSHOT_VERSION,
+};
+use crate::errors::BountyForgeError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Reputation {
     pub agent: Pubkey,
-    pub score: u64, // +1 per successful bounty
+    pub score: u64, // score_on_submit and score_on_settle per difficulty point, less penalties, decaying
     pub successful_bounties: u64,
     pub failed_bounties: u64,
-    pub total_earned: u64, // lamports
+    pub total_earned: u64,        // lamports
+    pub last_active: i64,         // unix timestamp score was last brought up to date
+    pub badges_minted: u8,        // badges claimed so far, indexes BADGE_THRESHOLDS
+    pub referrer: Option<Pubkey>, // agent who recruited this one, set when the account is created
+    pub referral_earnings: u64,   // lamports held on this account until claim_referral_rewards
+    pub rank_weight: u64,         // leaderboard sort key, see Reputation::compute_rank_weight
+    pub last_submission_at: i64,  // unix timestamp of the agent's latest reveal
+    pub active_submissions: u16,  // submissions not yet settled, rejected or given up
+    pub locked_until: i64,        // end of the stake_reputation lock, 0 when not staked
+    #[max_len(MAX_WITHDRAW_WHITELIST)]
+    pub withdraw_whitelist: Vec<Pubkey>, // owners payouts may go to, empty for any
     pub bump: u8,
 }
+
+/// Layout-independent copy of a reputation, carried across a migration by
+/// `export_reputation` and `import_reputation`. Fields are only ever appended,
+/// `version` says which of them the exporting program knew about.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ReputationSnapshot {
+    pub version: u8,
+    pub agent: Pubkey,
+    pub score: u64,
+    pub successful_bounties: u64,
+    pub failed_bounties: u64,
+    pub total_earned: u64,
+    pub last_active: i64,
+    pub badges_minted: u8,
+    pub referrer: Option<Pubkey>,
+    pub referral_earnings: u64,
+    pub last_submission_at: i64,
+    pub withdraw_whitelist: Vec<Pubkey>,
+}
+
+/// Coarse trust level derived from a reputation, ordered from least to most trusted.
+#[derive(
+    AnchorSerialize,
+    AnchorDeserialize,
+    Clone,
+    Copy,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    InitSpace,
+)]
+pub enum ReputationTier {
+    Novice,
+    Verified,
+    Expert,
+    Elite,
+}
+
+impl ReputationTier {
+    /// Minimum (successful_bounties, score) required to reach the tier.
+    pub const fn threshold(&self) -> (u64, u64) {
+        match self {
+            ReputationTier::Novice => (0, 0),
+            ReputationTier::Verified => (3, 10),
+            ReputationTier::Expert => (15, 50),
+            ReputationTier::Elite => (50, 200),
+        }
+    }
+}
+
+impl Reputation {
+    /// Highest tier whose successful bounty and score thresholds are both met.
+    pub fn tier(&self) -> ReputationTier {
+        [
+            ReputationTier::Elite,
+            ReputationTier::Expert,
+            ReputationTier::Verified,
+        ]
+        .into_iter()
+        .find(|tier| {
+            let (successful_bounties, score) = tier.threshold();
+            self.successful_bounties >= successful_bounties && self.score >= score
+        })
+        .unwrap_or(ReputationTier::Novice)
+    }
+
+    /// Score after halving once per `half_life` seconds elapsed since
+    /// `last_active`, interpolated linearly within a partial half-life.
+    /// A non-positive `half_life` disables decay, and time before
+    /// `locked_until` never counts.
+    pub fn decayed_score(&self, now: i64, half_life: i64) -> u64 {
+        if half_life <= 0 {
+            return self.score;
+        }
+
+        let since = self.last_active.max(self.locked_until);
+        let elapsed = now.saturating_sub(since).max(0) as u64;
+        let half_life = half_life as u64;
+
+        let halvings = elapsed / half_life;
+        if halvings >= u64::BITS as u64 {
+            return 0;
+        }
+        let score = self.score >> halvings;
+
+        let remainder = elapsed % half_life;
+        let partial = (score as u128 * remainder as u128) / (2 * half_life as u128);
+
+        score.saturating_sub(partial as u64)
+    }
+
+    /// Share of settled attempts that succeeded, in basis points rounded
+    /// down. An agent with no attempts has a rate of 0.
+    pub fn success_rate_bps(&self) -> u16 {
+        let successful = self.successful_bounties as u128;
+        let attempts = successful + self.failed_bounties as u128;
+        if attempts == 0 {
+            return 0;
+        }
+
+        (successful * BPS_DENOMINATOR as u128 / attempts) as u16
+    }
+
+    /// Successful bounty count the next badge unlocks at, or `None` once
+    /// every badge has been minted.
+    pub fn next_badge_threshold(&self) -> Option<u64> {
+        BADGE_THRESHOLDS.get(self.badges_minted as usize).copied()
+    }
+
+    /// Single sort key blending the reputation for leaderboards:
+    ///
+    /// `score * RANK_SCORE_WEIGHT + successful_bounties * RANK_SUCCESS_WEIGHT
+    ///     + total_earned / RANK_EARNINGS_DIVISOR`
+    ///
+    /// Score carries the most weight, settled work and earnings separate
+    /// agents with similar scores. Saturates rather than overflowing.
+    pub fn compute_rank_weight(&self) -> u64 {
+        self.score
+            .saturating_mul(RANK_SCORE_WEIGHT)
+            .saturating_add(self.successful_bounties.saturating_mul(RANK_SUCCESS_WEIGHT))
+            .saturating_add(self.total_earned / RANK_EARNINGS_DIVISOR)
+    }
+
+    /// Stores `compute_rank_weight` so indexers can sort on `rank_weight`.
+    /// Called after every change to the fields it blends.
+    pub fn refresh_rank_weight(&mut self) {
+        self.rank_weight = self.compute_rank_weight();
+    }
+
+    pub fn snapshot(&self) -> ReputationSnapshot {
+        ReputationSnapshot {
+            version: REPUTATION_SNAPSHOT_VERSION,
+            agent: self.agent,
+            score: self.score,
+            successful_bounties: self.successful_bounties,
+            failed_bounties: self.failed_bounties,
+            total_earned: self.total_earned,
+            last_active: self.last_active,
+            badges_minted: self.badges_minted,
+            referrer: self.referrer,
+            referral_earnings: self.referral_earnings,
+            last_submission_at: self.last_submission_at,
+            withdraw_whitelist: self.withdraw_whitelist.clone(),
+        }
+    }
+
+    /// Rebuilds a reputation from `snapshot`. `rank_weight` is derived, so it
+    /// is recomputed rather than carried over.
+    pub fn from_snapshot(snapshot: &ReputationSnapshot, bump: u8) -> Self {
+        let mut reputation = Reputation {
+            agent: snapshot.agent,
+            score: snapshot.score,
+            successful_bounties: snapshot.successful_bounties,
+            failed_bounties: snapshot.failed_bounties,
+            total_earned: snapshot.total_earned,
+            last_active: snapshot.last_active,
+            badges_minted: snapshot.badges_minted,
+            referrer: snapshot.referrer,
+            referral_earnings: snapshot.referral_earnings,
+            rank_weight: 0,
+            last_submission_at: snapshot.last_submission_at,
+            active_submissions: 0,
+            locked_until: 0,
+            withdraw_whitelist: snapshot.withdraw_whitelist.clone(),
+            bump,
+        };
+        reputation.refresh_rank_weight();
+        reputation
+    }
+
+    /// Moderation correction applied by `adjust_reputation`. Increases are
+    /// checked, decreases saturate at zero.
+    pub fn adjust(
+        &mut self,
+        score_delta: i64,
+        successful_bounties_delta: i64,
+        failed_bounties_delta: i64,
+    ) -> Result<()> {
+        self.score = apply_delta(self.score, score_delta)
+            .ok_or(BountyForgeError::ReputationScoreOverflow)?;
+        self.successful_bounties = apply_delta(self.successful_bounties, successful_bounties_delta)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+        self.failed_bounties = apply_delta(self.failed_bounties, failed_bounties_delta)
+            .ok_or(BountyForgeError::ReputationOverflow)?;
+        self.refresh_rank_weight();
+
+        Ok(())
+    }
+
+    /// Frees one of the agent's submission slots once its submission is settled,
+    /// rejected or given up. Saturates so a reputation restored by `import_reputation` while
+    /// submissions were open cannot underflow.
+    pub fn release_submission(&mut self) {
+        self.active_submissions = self.active_submissions.saturating_sub(1);
+    }
+
+    /// Whether a payout may go to an account owned by `owner`, checked by
+    /// claim_reward and claim_vested and by every settlement that pushes the
+    /// payout to the agent. An empty whitelist allows any owner.
+    pub fn allows_withdrawal_to(&self, owner: &Pubkey) -> bool {
+        self.withdraw_whitelist.is_empty() || self.withdraw_whitelist.contains(owner)
+    }
+
+    /// Whether the agent staked its reputation and the lock is still running
+    /// at `now`, which earns its submissions priority in auto_settle.
+    pub fn is_locked(&self, now: i64) -> bool {
+        now < self.locked_until
+    }
+
+    /// Applies decay up to `now` and refreshes `last_active`.
+    pub fn apply_decay(&mut self, now: i64, half_life: i64) {
+        self.score = self.decayed_score(now, half_life);
+        self.last_active = now;
+        self.refresh_rank_weight();
+    }
+}
+
+fn apply_delta(value: u64, delta: i64) -> Option<u64> {
+    if delta >= 0 {
+        value.checked_add(delta as u64)
+    } else {
+        Some(value.saturating_sub(delta.unsigned_abs()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+    const HALF_LIFE: i64 = 30 * DAY;
+
+    fn reputation(score: u64, last_active: i64) -> Reputation {
+        reputation_with(0, score, last_active)
+    }
+
+    fn reputation_with(successful_bounties: u64, score: u64, last_active: i64) -> Reputation {
+        Reputation {
+            agent: Pubkey::default(),
+            score,
+            successful_bounties,
+            failed_bounties: 0,
+            total_earned: 0,
+            last_active,
+            badges_minted: 0,
+            referrer: None,
+            referral_earnings: 0,
+            rank_weight: 0,
+            last_submission_at: 0,
+            active_submissions: 0,
+            locked_until: 0,
+            withdraw_whitelist: Vec::new(),
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn short_gap_produces_no_decay() {
+        let rep = reputation(10, 1_000);
+        assert_eq!(rep.decayed_score(1_000 + 60 * 60, HALF_LIFE), 10);
+    }
+
+    #[test]
+    fn long_gap_halves_per_half_life() {
+        let rep = reputation(100, 0);
+        assert_eq!(rep.decayed_score(HALF_LIFE, HALF_LIFE), 50);
+        assert_eq!(rep.decayed_score(2 * HALF_LIFE, HALF_LIFE), 25);
+        assert_eq!(rep.decayed_score(365 * DAY, HALF_LIFE), 0);
+    }
+
+    #[test]
+    fn partial_half_life_interpolates() {
+        let rep = reputation(100, 0);
+        assert_eq!(rep.decayed_score(HALF_LIFE / 2, HALF_LIFE), 75);
+    }
+
+    #[test]
+    fn extreme_gap_never_underflows() {
+        let rep = reputation(u64::MAX, i64::MIN);
+        assert_eq!(rep.decayed_score(i64::MAX, 1), 0);
+    }
+
+    #[test]
+    fn clock_skew_and_disabled_decay_keep_score() {
+        let rep = reputation(42, 10_000);
+        assert_eq!(rep.decayed_score(0, HALF_LIFE), 42);
+        assert_eq!(rep.decayed_score(10_000 + 10 * HALF_LIFE, 0), 42);
+    }
+
+    #[test]
+    fn apply_decay_refreshes_last_active() {
+        let mut rep = reputation(100, 0);
+        rep.apply_decay(HALF_LIFE, HALF_LIFE);
+        assert_eq!(rep.score, 50);
+        assert_eq!(rep.last_active, HALF_LIFE);
+    }
+
+    #[test]
+    fn locked_reputation_does_not_decay() {
+        let rep = Reputation {
+            locked_until: 2 * HALF_LIFE,
+            ..reputation(100, 0)
+        };
+        assert!(rep.is_locked(HALF_LIFE));
+        assert_eq!(rep.decayed_score(HALF_LIFE, HALF_LIFE), 100);
+        assert_eq!(rep.decayed_score(2 * HALF_LIFE, HALF_LIFE), 100);
+
+        // decay resumes from the end of the lock, not from last_active
+        assert!(!rep.is_locked(2 * HALF_LIFE));
+        assert_eq!(rep.decayed_score(3 * HALF_LIFE, HALF_LIFE), 50);
+    }
+
+    #[test]
+    fn activity_during_a_lock_keeps_the_score() {
+        let mut rep = Reputation {
+            locked_until: HALF_LIFE,
+            ..reputation(100, 0)
+        };
+        rep.apply_decay(HALF_LIFE / 2, HALF_LIFE);
+        assert_eq!(rep.score, 100);
+        assert_eq!(rep.last_active, HALF_LIFE / 2);
+        assert_eq!(rep.decayed_score(2 * HALF_LIFE, HALF_LIFE), 50);
+    }
+
+    fn rate_of(successful_bounties: u64, failed_bounties: u64) -> u16 {
+        Reputation {
+            failed_bounties,
+            ..reputation_with(successful_bounties, 0, 0)
+        }
+        .success_rate_bps()
+    }
+
+    #[test]
+    fn success_rate_without_attempts_is_zero() {
+        assert_eq!(rate_of(0, 0), 0);
+    }
+
+    #[test]
+    fn success_rate_boundaries() {
+        assert_eq!(rate_of(7, 0), 10_000);
+        assert_eq!(rate_of(0, 7), 0);
+        assert_eq!(rate_of(u64::MAX, 0), 10_000);
+        assert_eq!(rate_of(0, u64::MAX), 0);
+    }
+
+    #[test]
+    fn success_rate_rounds_down() {
+        assert_eq!(rate_of(1, 2), 3_333);
+        assert_eq!(rate_of(2, 1), 6_666);
+        assert_eq!(rate_of(u64::MAX, u64::MAX), 5_000);
+    }
+
+    fn tier_of(successful_bounties: u64, score: u64) -> ReputationTier {
+        reputation_with(successful_bounties, score, 0).tier()
+    }
+
+    #[test]
+    fn new_reputation_is_novice() {
+        assert_eq!(tier_of(0, 0), ReputationTier::Novice);
+    }
+
+    #[test]
+    fn tier_boundaries() {
+        for tier in [
+            ReputationTier::Verified,
+            ReputationTier::Expert,
+            ReputationTier::Elite,
+        ] {
+            let (successful_bounties, score) = tier.threshold();
+            assert_eq!(tier_of(successful_bounties, score), tier);
+            assert!(tier_of(successful_bounties - 1, score) < tier);
+            assert!(tier_of(successful_bounties, score - 1) < tier);
+        }
+    }
+
+    #[test]
+    fn tier_requires_both_thresholds() {
+        assert_eq!(tier_of(2, 1_000), ReputationTier::Novice);
+        assert_eq!(tier_of(1_000, 9), ReputationTier::Novice);
+        assert_eq!(tier_of(49, 1_000), ReputationTier::Expert);
+        assert_eq!(tier_of(u64::MAX, u64::MAX), ReputationTier::Elite);
+    }
+
+    #[test]
+    fn badge_thresholds_advance_with_each_mint() {
+        let mut rep = reputation_with(0, 0, 0);
+        assert_eq!(rep.next_badge_threshold(), Some(10));
+
+        rep.badges_minted = 1;
+        assert_eq!(rep.next_badge_threshold(), Some(50));
+
+        rep.badges_minted = 2;
+        assert_eq!(rep.next_badge_threshold(), Some(100));
+
+        rep.badges_minted = 3;
+        assert_eq!(rep.next_badge_threshold(), None);
+    }
+
+    #[test]
+    fn rank_weight_orders_score_before_successes_and_earnings() {
+        let rep = Reputation {
+            total_earned: 5_000_000,
+            ..reputation_with(3, 7, 0)
+        };
+        assert_eq!(rep.compute_rank_weight(), 7 * 1_000 + 3 * 100 + 5);
+
+        let higher_score = reputation_with(0, 8, 0);
+        assert!(higher_score.compute_rank_weight() > rep.compute_rank_weight());
+    }
+
+    #[test]
+    fn rank_weight_saturates_and_is_stored_on_decay() {
+        let rep = reputation_with(u64::MAX, u64::MAX, 0);
+        assert_eq!(rep.compute_rank_weight(), u64::MAX);
+
+        let mut rep = reputation_with(2, 100, 0);
+        rep.apply_decay(HALF_LIFE, HALF_LIFE);
+        assert_eq!(rep.rank_weight, 50 * 1_000 + 2 * 100);
+    }
+
+    #[test]
+    fn snapshot_round_trips_through_serialization() {
+        let mut rep = Reputation {
+            failed_bounties: 2,
+            total_earned: 7_000_000,
+            badges_minted: 1,
+            referrer: Some(Pubkey::new_unique()),
+            referral_earnings: 500,
+            last_submission_at: 1_234,
+            withdraw_whitelist: vec![Pubkey::new_unique()],
+            bump: 254,
+            agent: Pubkey::new_unique(),
+            ..reputation_with(12, 40, 1_000)
+        };
+        rep.refresh_rank_weight();
+
+        let snapshot = rep.snapshot();
+        assert_eq!(snapshot.version, REPUTATION_SNAPSHOT_VERSION);
+
+        let bytes = snapshot.try_to_vec().unwrap();
+        let decoded = ReputationSnapshot::try_from_slice(&bytes).unwrap();
+        assert_eq!(decoded, snapshot);
+
+        let restored = Reputation::from_snapshot(&decoded, rep.bump);
+        assert_eq!(restored.snapshot(), snapshot);
+        assert_eq!(restored.rank_weight, rep.rank_weight);
+        assert_eq!(restored.bump, 254);
+    }
+
+    #[test]
+    fn adjust_adds_checked_and_subtracts_saturating() {
+        let mut rep = reputation_with(5, 40, 0);
+        rep.adjust(-100, 2, 3).unwrap();
+        assert_eq!(rep.score, 0);
+        assert_eq!(rep.successful_bounties, 7);
+        assert_eq!(rep.failed_bounties, 3);
+        assert_eq!(rep.rank_weight, rep.compute_rank_weight());
+
+        let mut rep = reputation_with(0, u64::MAX, 0);
+        assert!(rep.adjust(1, 0, 0).is_err());
+        assert!(rep.adjust(i64::MIN, 0, 0).is_ok());
+        assert_eq!(rep.score, u64::MAX - i64::MIN.unsigned_abs());
+    }
+
+    #[test]
+    fn empty_withdraw_whitelist_allows_any_owner() {
+        let owner = Pubkey::new_unique();
+        let mut rep = reputation(0, 0);
+        assert!(rep.allows_withdrawal_to(&owner));
+
+        rep.withdraw_whitelist.push(Pubkey::new_unique());
+        assert!(!rep.allows_withdrawal_to(&owner));
+
+        rep.withdraw_whitelist.push(owner);
+        assert!(rep.allows_withdrawal_to(&owner));
+    }
+}
@@ -0,0 +1,10 @@
+use anchor_lang::prelude::*;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Stake {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub amount: u64, // lamports held above rent, returned or slashed
+    pub bump: u8,
+}
@@ -0,0 +1,21 @@
+use anchor_lang::prelude::*;
+
+use crate::state::ReputationTier;
+
+#[account]
+#[derive(InitSpace)]
+pub struct Submission {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub solution_hash: [u8; 32],
+    pub solution_kind: u8,  // format of the off-chain artifact, see MAX_SOLUTION_KIND
+    pub solution_size: u32, // byte length of the off-chain artifact
+    pub min_payout: u64,    // smallest net payout the agent accepts at settlement, 0 for any
+    pub timestamp: i64,
+    pub reputation_score_at_submit: u64, // agent's score right after revealing, ranks auto_settle
+    pub tier_at_submit: ReputationTier,  // agent's tier right after revealing, prices the fee
+    pub staked_at_submit: bool,          // agent's reputation was locked at reveal, wins auto_settle ties
+    pub delivery_pubkey: Pubkey,         // key-exchange pubkey the creator encrypts delivery to, default when public
+    pub won: bool,                       // paid by settle_multi, a single winner is recorded as bounty.agent
+    pub bump: u8,
+}
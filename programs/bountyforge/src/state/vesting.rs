@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+
+/// Linear release of a settled payout, opened when a bounty posted with
+/// `vesting_seconds` settles and drawn down with claim_vested.
+#[account]
+#[derive(InitSpace)]
+pub struct Vesting {
+    pub bounty_id: u64,
+    pub agent: Pubkey,
+    pub total: u64,      // payout released over the schedule
+    pub claimed: u64,    // part already pulled with claim_vested
+    pub start: i64,      // unix timestamp of the settlement
+    pub duration: i64,   // seconds until the whole payout has vested
+    pub completed: bool, // set once claimed reaches total
+    pub bump: u8,
+}
+
+impl Vesting {
+    /// Part of `total` unlocked at `now`, growing linearly from `start` and
+    /// rounded down until the schedule ends.
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.start).max(0);
+        if self.duration <= 0 || elapsed >= self.duration {
+            return self.total;
+        }
+
+        (self.total as u128 * elapsed as u128 / self.duration as u128) as u64
+    }
+
+    /// Vested part the agent has not claimed yet.
+    pub fn claimable_amount(&self, now: i64) -> u64 {
+        self.vested_amount(now).saturating_sub(self.claimed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY: i64 = 24 * 60 * 60;
+
+    // 1_000 tokens vesting over 100 days from t = 1_000
+    fn vesting() -> Vesting {
+        Vesting {
+            bounty_id: 0,
+            agent: Pubkey::default(),
+            total: 1_000_000_000,
+            claimed: 0,
+            start: 1_000,
+            duration: 100 * DAY,
+            completed: false,
+            bump: 0,
+        }
+    }
+
+    #[test]
+    fn vests_linearly_along_the_schedule() {
+        let vesting = vesting();
+
+        assert_eq!(vesting.vested_amount(1_000), 0);
+        assert_eq!(vesting.vested_amount(1_000 + DAY), 10_000_000);
+        assert_eq!(vesting.vested_amount(1_000 + 25 * DAY), 250_000_000);
+        assert_eq!(vesting.vested_amount(1_000 + 50 * DAY), 500_000_000);
+        assert_eq!(vesting.vested_amount(1_000 + 99 * DAY), 990_000_000);
+        assert_eq!(vesting.vested_amount(1_000 + 100 * DAY), 1_000_000_000);
+    }
+
+    #[test]
+    fn nothing_vests_before_the_start_and_all_after_the_end() {
+        let vesting = vesting();
+
+        assert_eq!(vesting.vested_amount(0), 0);
+        assert_eq!(vesting.vested_amount(i64::MIN), 0);
+        assert_eq!(vesting.vested_amount(1_000 + 365 * DAY), vesting.total);
+        assert_eq!(vesting.vested_amount(i64::MAX), vesting.total);
+    }
+
+    #[test]
+    fn partial_seconds_round_down() {
+        let vesting = Vesting {
+            total: 10,
+            duration: 3,
+            ..vesting()
+        };
+
+        assert_eq!(vesting.vested_amount(1_001), 3);
+        assert_eq!(vesting.vested_amount(1_002), 6);
+        assert_eq!(vesting.vested_amount(1_003), 10);
+    }
+
+    #[test]
+    fn claims_are_capped_at_the_vested_part() {
+        let mut vesting = vesting();
+        let midway = 1_000 + 50 * DAY;
+
+        assert_eq!(vesting.claimable_amount(midway), 500_000_000);
+
+        vesting.claimed = 500_000_000;
+        assert_eq!(vesting.claimable_amount(midway), 0);
+        assert_eq!(vesting.claimable_amount(1_000 + 75 * DAY), 250_000_000);
+        assert_eq!(vesting.claimable_amount(1_000 + 200 * DAY), 500_000_000);
+
+        vesting.claimed = vesting.total;
+        assert_eq!(vesting.claimable_amount(i64::MAX), 0);
+    }
+
+    #[test]
+    fn large_payouts_do_not_overflow() {
+        let vesting = Vesting {
+            total: u64::MAX,
+            ..vesting()
+        };
+
+        assert_eq!(vesting.vested_amount(1_000 + 50 * DAY), u64::MAX / 2);
+    }
+}
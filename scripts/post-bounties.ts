@@ -22,8 +22,28 @@ function deriveBountyPda(
     );
 }
 
+function deriveVaultPda(
+    programId: PublicKey,
+    bountyId: number
+): [PublicKey, number] {
+    const bountyIdBuffer = Buffer.allocUnsafe(8);
+    bountyIdBuffer.writeBigUInt64LE(BigInt(bountyId), 0);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), bountyIdBuffer],
+        programId
+    );
+}
+
+function deriveConfigPda(programId: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        programId
+    );
+}
+
+const BOUNTY_DURATION_SECONDS = 7 * 24 * 60 * 60;
+
 interface BountyData {
-    id: number;
     description: string;
     reward: number;
 }
@@ -36,24 +56,18 @@ async function postBounty(
     creatorTokenAccount: PublicKey,
     bountyData: BountyData
 ): Promise<PublicKey> {
-    const [bountyPda, _bump] = deriveBountyPda(program.programId, bountyData.id);
-
-    const bountyAccountInfo = await connection.getAccountInfo(bountyPda);
-    if (bountyAccountInfo !== null) {
-        try {
-            const bounty = await program.account.bounty.fetch(bountyPda);
-            console.log(`Bounty #${bountyData.id} already exists, skipping...`);
-            return bountyPda;
-        } catch (e) {
-            console.error(`Bounty PDA ${bountyPda.toString()} exists but is corrupted. Cannot post bounty #${bountyData.id}.`);
-            console.error(`   Error: ${e instanceof Error ? e.message : String(e)}`);
-            console.error(`   You may need to close this account or use a different bounty ID.`);
-            throw new Error(`Cannot post bounty #${bountyData.id}: account exists but is corrupted`);
-        }
-    }
+    // The program assigns ids from the config counter, so the next bounty
+    // always lands at the counter's current value.
+    const config = await program.account.config.fetch(
+        deriveConfigPda(program.programId)[0]
+    );
+    const bountyId = config.bountyCounter.toNumber();
+    const [bountyPda, _bump] = deriveBountyPda(program.programId, bountyId);
+    const [vaultPda] = deriveVaultPda(program.programId, bountyId);
     const bountyTokenAccount = getAssociatedTokenAddressSync(
         usdcMint,
-        bountyPda
+        vaultPda,
+        true
     );
 
     const tokenAccountInfo = await connection.getAccountInfo(bountyTokenAccount);
@@ -66,7 +80,7 @@ async function postBounty(
             createAssociatedTokenAccountInstruction(
                 creator.publicKey,
                 bountyTokenAccount,
-                bountyPda,
+                vaultPda,
                 usdcMint,
                 TOKEN_PROGRAM_ID,
                 ASSOCIATED_TOKEN_PROGRAM_ID
@@ -76,14 +90,26 @@ async function postBounty(
 
     const tx = await program.methods
         .postBounty(
-            new anchor.BN(bountyData.id),
-            bountyData.description,
-            new anchor.BN(bountyData.reward)
+            {
+                description: bountyData.description,
+                title: bountyData.description,
+                descriptionUri: "",
+                tags: [],
+                reward: new anchor.BN(bountyData.reward),
+                deadline: new anchor.BN(Math.floor(Date.now() / 1000) + BOUNTY_DURATION_SECONDS),
+                competition: false,
+                minTier: { novice: {} },
+                minReputationScore: new anchor.BN(0),
+                rewardKind: { spl: {} },
+                milestones: [],
+                whitelistRoot: Array(32).fill(0),
+                difficulty: 1,
+            }
         )
         .accountsPartial({
             creator: creator.publicKey,
             bounty: bountyPda,
-            usdcMint: usdcMint,
+            rewardMint: usdcMint,
             creatorTokenAccount: creatorTokenAccount,
             bountyTokenAccount: bountyTokenAccount,
             tokenProgram: TOKEN_PROGRAM_ID,
@@ -94,7 +120,7 @@ async function postBounty(
         .signers([creator])
         .rpc();
 
-    console.log(`Posted bounty #${bountyData.id}: ${bountyData.description}`);
+    console.log(`Posted bounty #${bountyId}: ${bountyData.description}`);
     console.log(`   Reward: ${bountyData.reward / 1e6} USDC`);
     console.log(`   Bounty PDA: ${bountyPda.toString()}`);
     console.log(`   Transaction: ${tx}`);
@@ -175,54 +201,21 @@ async function main() {
         process.exit(1);
     }
 
-    async function checkBountyExists(bountyId: number): Promise<boolean> {
-        try {
-            const [bountyPda] = deriveBountyPda(program.programId, bountyId);
-            const accountInfo = await connection.getAccountInfo(bountyPda);
-            return accountInfo !== null;
-        } catch {
-            return false;
-        }
-    }
-
-    const baseBounties: BountyData[] = [
+    const bounties: BountyData[] = [
         {
-            id: 1,
             description: "Solve Solana puzzle: Calculate the average SOL/USD price over the last 24 hours using Switchboard oracle data",
             reward: 1 * 1e6,
         },
         {
-            id: 2,
             description: "Debug smart contract: Find and fix the overflow bug in the token transfer function",
             reward: 2 * 1e6,
         },
         {
-            id: 3,
             description: "Data analysis: Analyze transaction patterns from the last 7 days and identify anomalies",
             reward: 1.5 * 1e6,
         },
     ];
 
-    const bounties: BountyData[] = [];
-    const assignedIds = new Set<number>();
-
-    for (const bounty of baseBounties) {
-        let availableId = bounty.id;
-
-        while (await checkBountyExists(availableId) || assignedIds.has(availableId)) {
-            availableId++;
-        }
-
-        assignedIds.add(availableId);
-        bounties.push({
-            ...bounty,
-            id: availableId
-        });
-        if (availableId !== bounty.id) {
-            console.log(`Bounty ID ${bounty.id} already exists, using ID ${availableId} instead`);
-        }
-    }
-
     console.log(`\nPosting ${bounties.length} bounties...\n`);
 
     for (const bounty of bounties) {
@@ -237,7 +230,7 @@ async function main() {
             );
             console.log("");
         } catch (error: any) {
-            console.error(`Failed to post bounty #${bounty.id}:`);
+            console.error(`Failed to post bounty "${bounty.description}":`);
             if (error.message) {
                 console.error(`   Error: ${error.message}`);
             }
@@ -0,0 +1,90 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  airdropSol,
+  initializeReputation,
+  TestContext,
+} from "./helpers";
+
+describe("adjust_reputation", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let reputationPda: anchor.web3.PublicKey;
+  const reasonHash = Array(32).fill(0x75);
+
+  function adjust(
+    signer: Keypair,
+    scoreDelta: number,
+    successfulBountiesDelta: number,
+    failedBountiesDelta: number,
+    reason: number[] = reasonHash
+  ) {
+    return ctx.program.methods
+      .adjustReputation({
+        scoreDelta: new anchor.BN(scoreDelta),
+        successfulBountiesDelta: new anchor.BN(successfulBountiesDelta),
+        failedBountiesDelta: new anchor.BN(failedBountiesDelta),
+        reasonHash: reason,
+      })
+      .accountsPartial({
+        admin: signer.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        reputation: reputationPda,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    reputationPda = await initializeReputation(ctx, agent);
+  });
+
+  it("Adds and removes reputation and emits the reason", async () => {
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("reputationAdjusted", (e) => resolve(e));
+    });
+
+    await adjust(ctx.admin, 20, 3, 1);
+    await adjust(ctx.admin, -50, -1, 0);
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+    expect(emitted.agent.toString()).to.equal(agent.publicKey.toString());
+    expect(emitted.reasonHash).to.deep.equal(reasonHash);
+
+    const reputation = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputation.score.toNumber()).to.equal(0);
+    expect(reputation.successfulBounties.toNumber()).to.equal(2);
+    expect(reputation.failedBounties.toNumber()).to.equal(1);
+  });
+
+  it("Fails without a reason", async () => {
+    try {
+      await adjust(ctx.admin, 1, 0, 0, Array(32).fill(0));
+
+      expect.fail("Should have failed - missing reason");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidReasonHash");
+    }
+  });
+
+  it("Fails when the caller is not the admin", async () => {
+    try {
+      await adjust(agent, 100, 0, 0);
+
+      expect.fail("Should have failed - unauthorized admin");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("UnauthorizedAdmin");
+    }
+  });
+});
@@ -0,0 +1,119 @@
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveAgentProfilePda,
+  airdropSol,
+  TestContext,
+} from "./helpers";
+
+describe("agent_profile", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+
+  function registerAgent(handle: string | null, metadataUri: string) {
+    return ctx.program.methods
+      .registerAgent(handle, metadataUri)
+      .accountsPartial({
+        agent: agent.publicKey,
+        agentProfile: deriveAgentProfilePda(ctx.program.programId, agent.publicKey)[0],
+      })
+      .signers([agent])
+      .rpc();
+  }
+
+  function updateAgent(handle: string | null, metadataUri: string) {
+    return ctx.program.methods
+      .updateAgent(handle, metadataUri)
+      .accountsPartial({
+        agent: agent.publicKey,
+        agentProfile: deriveAgentProfilePda(ctx.program.programId, agent.publicKey)[0],
+      })
+      .signers([agent])
+      .rpc();
+  }
+
+  function fetchProfile() {
+    return ctx.program.account.agentProfile.fetch(
+      deriveAgentProfilePda(ctx.program.programId, agent.publicKey)[0]
+    );
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+  });
+
+  it("Registers an agent with a handle and metadata", async () => {
+    await registerAgent("solver-9000", "ipfs://agent-card");
+
+    const profile = await fetchProfile();
+    expect(profile.agent.toString()).to.equal(agent.publicKey.toString());
+    expect(profile.handle).to.equal("solver-9000");
+    expect(profile.metadataUri).to.equal("ipfs://agent-card");
+    expect(profile.joinedAt.toNumber()).to.be.greaterThan(0);
+  });
+
+  it("Registers an agent without a handle", async () => {
+    await registerAgent(null, "");
+
+    const profile = await fetchProfile();
+    expect(profile.handle).to.be.null;
+  });
+
+  it("Updates the profile and keeps the join date", async () => {
+    await registerAgent("solver-9000", "ipfs://agent-card");
+    const joinedAt = (await fetchProfile()).joinedAt.toNumber();
+
+    await updateAgent(null, "ipfs://agent-card-v2");
+
+    const profile = await fetchProfile();
+    expect(profile.handle).to.be.null;
+    expect(profile.metadataUri).to.equal("ipfs://agent-card-v2");
+    expect(profile.joinedAt.toNumber()).to.equal(joinedAt);
+  });
+
+  it("Fails to register twice", async () => {
+    await registerAgent("solver-9000", "");
+
+    try {
+      await registerAgent("solver-9001", "");
+      expect.fail("Should have failed - profile already exists");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails with a handle over the length limit", async () => {
+    try {
+      await registerAgent("x".repeat(33), "");
+      expect.fail("Should have failed - handle too long");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("MetadataTooLong");
+    }
+  });
+
+  it("Fails to update another agent's profile", async () => {
+    await registerAgent("solver-9000", "");
+    const other = Keypair.generate();
+    await airdropSol(ctx.connection, other.publicKey);
+
+    try {
+      await ctx.program.methods
+        .updateAgent("impostor", "")
+        .accountsPartial({
+          agent: other.publicKey,
+          agentProfile: deriveAgentProfilePda(ctx.program.programId, agent.publicKey)[0],
+        })
+        .signers([other])
+        .rpc();
+      expect.fail("Should have failed - profile belongs to another agent");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ConstraintSeeds");
+    }
+  });
+});
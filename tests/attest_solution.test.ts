@@ -6,6 +6,7 @@ import {
   deriveAttestationPda,
   airdropSol,
   generateRandomId,
+  nextBountyId,
   generateSolutionHash,
   generateSolutionHashWithValue,
   TestContext,
@@ -14,6 +15,7 @@ import {
 describe("attest_solution", () => {
   let ctx: TestContext;
   let agent: Keypair;
+  let bountyId: number;
   let solutionId: number;
   let attestationPda: anchor.web3.PublicKey;
   let solutionHash: Buffer;
@@ -24,6 +26,7 @@ describe("attest_solution", () => {
 
   beforeEach(() => {
     agent = Keypair.generate();
+    bountyId = await nextBountyId(ctx);
     solutionId = generateRandomId();
     [attestationPda] = deriveAttestationPda(ctx.program.programId, solutionId);
     solutionHash = generateSolutionHash();
@@ -35,7 +38,7 @@ describe("attest_solution", () => {
     const beforeTimestamp = Math.floor(Date.now() / 1000);
 
     await ctx.program.methods
-      .attestSolution(new anchor.BN(solutionId), Array.from(solutionHash))
+      .attestSolution(new anchor.BN(solutionId), new anchor.BN(bountyId), Array.from(solutionHash))
       .accountsPartial({
         agent: agent.publicKey,
         attestation: attestationPda,
@@ -51,6 +54,7 @@ describe("attest_solution", () => {
     );
 
     expect(attestationAccount.solutionId.toNumber()).to.equal(solutionId);
+    expect(attestationAccount.bountyId.toNumber()).to.equal(bountyId);
     expect(Buffer.from(attestationAccount.solutionHash)).to.deep.equal(
       solutionHash
     );
@@ -64,11 +68,31 @@ describe("attest_solution", () => {
     expect(attestationTimestamp).to.be.at.most(afterTimestamp + 5);
   });
 
+  it("Fails when the solution hash is all zeros", async () => {
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    try {
+      await ctx.program.methods
+        .attestSolution(new anchor.BN(solutionId), new anchor.BN(bountyId), Array(32).fill(0))
+        .accountsPartial({
+          agent: agent.publicKey,
+          attestation: attestationPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+      expect.fail("Should have failed - zero solution hash");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidSolutionHash");
+    }
+  });
+
   it("Fails when trying to create duplicate attestation with same solution_id", async () => {
     await airdropSol(ctx.connection, agent.publicKey);
 
     await ctx.program.methods
-      .attestSolution(new anchor.BN(solutionId), Array.from(solutionHash))
+      .attestSolution(new anchor.BN(solutionId), new anchor.BN(bountyId), Array.from(solutionHash))
       .accountsPartial({
         agent: agent.publicKey,
         attestation: attestationPda,
@@ -83,6 +107,7 @@ describe("attest_solution", () => {
       await ctx.program.methods
         .attestSolution(
           new anchor.BN(solutionId),
+          new anchor.BN(bountyId),
           Array.from(differentHash)
         )
         .accountsPartial({
@@ -121,7 +146,7 @@ describe("attest_solution", () => {
     const hash2 = generateSolutionHashWithValue(0x02);
 
     await ctx.program.methods
-      .attestSolution(new anchor.BN(solutionId1), Array.from(hash1))
+      .attestSolution(new anchor.BN(solutionId1), new anchor.BN(bountyId), Array.from(hash1))
       .accountsPartial({
         agent: agent1.publicKey,
         attestation: attestationPda1,
@@ -131,7 +156,7 @@ describe("attest_solution", () => {
       .rpc();
 
     await ctx.program.methods
-      .attestSolution(new anchor.BN(solutionId2), Array.from(hash2))
+      .attestSolution(new anchor.BN(solutionId2), new anchor.BN(bountyId), Array.from(hash2))
       .accountsPartial({
         agent: agent2.publicKey,
         attestation: attestationPda2,
@@ -174,7 +199,7 @@ describe("attest_solution", () => {
     const hash2 = generateSolutionHashWithValue(0xbb);
 
     await ctx.program.methods
-      .attestSolution(new anchor.BN(solutionId1), Array.from(hash1))
+      .attestSolution(new anchor.BN(solutionId1), new anchor.BN(bountyId), Array.from(hash1))
       .accountsPartial({
         agent: agent.publicKey,
         attestation: attestationPda1,
@@ -184,7 +209,7 @@ describe("attest_solution", () => {
       .rpc();
 
     await ctx.program.methods
-      .attestSolution(new anchor.BN(solutionId2), Array.from(hash2))
+      .attestSolution(new anchor.BN(solutionId2), new anchor.BN(bountyId), Array.from(hash2))
       .accountsPartial({
         agent: agent.publicKey,
         attestation: attestationPda2,
@@ -0,0 +1,72 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  sleep,
+  updateConfig,
+  TEST_MIN_ATTESTATION_AGE,
+  TestContext,
+} from "./helpers";
+
+describe("min_attestation_age", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let attestationPda: anchor.web3.PublicKey;
+  const minAge = 3;
+  const solutionHash = generateSolutionHashWithValue(0xa9);
+
+  before(async () => {
+    ctx = await setupTestContext();
+    await updateConfig(ctx, { minAttestationAge: new anchor.BN(minAge) });
+  });
+
+  after(async () => {
+    await updateConfig(ctx, {
+      minAttestationAge: new anchor.BN(TEST_MIN_ATTESTATION_AGE),
+    });
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Attestation age bounty", 10 * 10 ** 6);
+    attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+  });
+
+  it("Rejects a submission against a fresh attestation", async () => {
+    try {
+      await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+      expect.fail("Should have failed - attestation too fresh");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AttestationTooFresh");
+    }
+  });
+
+  it("Accepts the submission once the attestation is old enough", async () => {
+    await sleep((minAge + 1) * 1000);
+
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ submitted: {} });
+  });
+});
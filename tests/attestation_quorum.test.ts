@@ -0,0 +1,162 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("attestation_quorum", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let coAttester: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let attestationPda: anchor.web3.PublicKey;
+  const solutionHash = generateSolutionHashWithValue(0x81);
+
+  async function submitWith(coAttestations: anchor.web3.PublicKey[]) {
+    await submitSolution(
+      ctx,
+      agent,
+      bountyPda,
+      attestationPda,
+      solutionHash,
+      [],
+      null,
+      coAttestations
+    );
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      10 * 10 ** 6
+    );
+
+    agent = Keypair.generate();
+    coAttester = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    await airdropSol(ctx.connection, coAttester.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "High value bounty", 10 * 10 ** 6, {
+      requiredAttestations: 2,
+    });
+    attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+  });
+
+  it("Accepts a reveal backed by attestations from two different agents", async () => {
+    const coAttestation = await createAttestation(
+      ctx,
+      coAttester,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+
+    await submitWith([coAttestation]);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.requiredAttestations).to.equal(2);
+    expect(bounty.status).to.deep.equal({ submitted: {} });
+  });
+
+  it("Fails with fewer attestations than the bounty requires", async () => {
+    try {
+      await submitWith([]);
+
+      expect.fail("Should have failed - quorum not met");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AttestationQuorumNotMet");
+    }
+  });
+
+  it("Fails when the same agent attests twice", async () => {
+    const secondAttestation = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+
+    try {
+      await submitWith([secondAttestation]);
+
+      expect.fail("Should have failed - duplicate attester");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("DuplicateAttester");
+    }
+  });
+
+  it("Fails when a co-attestation is not verified", async () => {
+    const unverified = await createAttestation(
+      ctx,
+      coAttester,
+      bountyId,
+      generateRandomId(),
+      solutionHash,
+      false
+    );
+
+    try {
+      await submitWith([unverified]);
+
+      expect.fail("Should have failed - co-attestation not verified");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AttestationNotVerified");
+    }
+  });
+
+  it("Fails when a co-attestation covers a different solution", async () => {
+    const otherHash = await createAttestation(
+      ctx,
+      coAttester,
+      bountyId,
+      generateRandomId(),
+      generateSolutionHashWithValue(0x82)
+    );
+
+    try {
+      await submitWith([otherHash]);
+
+      expect.fail("Should have failed - co-attestation hash mismatch");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SolutionHashMismatch");
+    }
+  });
+
+  it("Fails to post a bounty requiring no attestations", async () => {
+    try {
+      await postBounty(ctx, await nextBountyId(ctx), "No quorum", 10 * 10 ** 6, {
+        requiredAttestations: 0,
+      });
+
+      expect.fail("Should have failed - invalid quorum");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidAttestationQuorum");
+    }
+  });
+});
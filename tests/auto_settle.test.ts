@@ -0,0 +1,175 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  deriveSubmissionPda,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  settleAndFinalize,
+  TestContext,
+} from "./helpers";
+
+describe("auto_settle", () => {
+  let ctx: TestContext;
+  let agentA: Keypair;
+  let agentB: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+
+  function submission(agent: Keypair) {
+    return {
+      pubkey: deriveSubmissionPda(ctx.program.programId, bountyId, agent.publicKey)[0],
+      isWritable: false,
+      isSigner: false,
+    };
+  }
+
+  function autoSettle(remainingAccounts: anchor.web3.AccountMeta[]) {
+    return ctx.program.methods
+      .autoSettle()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([ctx.creator])
+      .rpc();
+  }
+
+  async function submit(
+    agent: Keypair,
+    id: number,
+    pda: anchor.web3.PublicKey,
+    value: number
+  ): Promise<void> {
+    const solutionHash = generateSolutionHashWithValue(value);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      id,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, pda, attestationPda, solutionHash);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      300 * 10 ** 6
+    );
+
+    agentA = Keypair.generate();
+    agentB = Keypair.generate();
+    await airdropSol(ctx.connection, agentA.publicKey);
+    await airdropSol(ctx.connection, agentB.publicKey);
+
+    // settling a hard bounty elsewhere gives agent B the higher reputation score
+    const hardBountyId = await nextBountyId(ctx);
+    const hardBountyPda = await postBounty(ctx, hardBountyId, "Hard bounty", 10 * 10 ** 6, {
+      difficulty: 5,
+    });
+    await submit(agentB, hardBountyId, hardBountyPda, 0x48);
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agentB,
+      ctx.usdcMint
+    );
+    await settleAndFinalize(ctx, hardBountyPda, agentB, agentTokenAccount);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Auto settled bounty", 100 * 10 ** 6, {
+      competition: true,
+    });
+    await submit(agentA, bountyId, bountyPda, 0x4a);
+    await submit(agentB, bountyId, bountyPda, 0x4b);
+  });
+
+  it("Settles to the agent with the highest reputation score", async () => {
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("winnerSelected", (e) => resolve(e));
+    });
+
+    await autoSettle([submission(agentA), submission(agentB)]);
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+    expect(emitted.agent.toString()).to.equal(agentB.publicKey.toString());
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ pendingSettlement: {} });
+    expect(bounty.agent.toString()).to.equal(agentB.publicKey.toString());
+    expect(Buffer.from(bounty.solutionHash)).to.deep.equal(
+      generateSolutionHashWithValue(0x4b)
+    );
+  });
+
+  it("Ranks agents by their score at submit time, not their live score", async () => {
+    // lifting agent A far above agent B after both submitted must not change the winner
+    await ctx.program.methods
+      .adjustReputation({
+        scoreDelta: new anchor.BN(1_000),
+        successfulBountiesDelta: new anchor.BN(0),
+        failedBountiesDelta: new anchor.BN(0),
+        reasonHash: Array(32).fill(0x79),
+      })
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        reputation: deriveReputationPda(ctx.program.programId, agentA.publicKey)[0],
+      })
+      .signers([ctx.admin])
+      .rpc();
+
+    await autoSettle([submission(agentA), submission(agentB)]);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.agent.toString()).to.equal(agentB.publicKey.toString());
+
+    const winner = await ctx.program.account.submission.fetch(
+      deriveSubmissionPda(ctx.program.programId, bountyId, agentB.publicKey)[0]
+    );
+    expect(bounty.reputationScoreAtSubmit.toNumber()).to.equal(
+      winner.reputationScoreAtSubmit.toNumber()
+    );
+  });
+
+  it("Fails when a submission is left out", async () => {
+    try {
+      await autoSettle([submission(agentA)]);
+
+      expect.fail("Should have failed - agent B's submission is missing");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("IncompleteSubmissionSet");
+    }
+  });
+
+  it("Fails when a submission is passed twice", async () => {
+    try {
+      await autoSettle([submission(agentA), submission(agentA)]);
+
+      expect.fail("Should have failed - duplicate submission");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("IncompleteSubmissionSet");
+    }
+  });
+});
@@ -0,0 +1,170 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveCreatorProfilePda,
+  deriveHistoryPda,
+  deriveSubmissionPda,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("batch_settle", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+
+  async function submittedBounty(value: number) {
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Batched bounty", 10 * 10 ** 6);
+    const solutionHash = generateSolutionHashWithValue(value);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    return { bountyId, bountyPda };
+  }
+
+  function group(bountyId: number, bountyPda: anchor.web3.PublicKey) {
+    return [
+      { pubkey: bountyPda, isWritable: true, isSigner: false },
+      {
+        pubkey: deriveHistoryPda(ctx.program.programId, bountyId)[0],
+        isWritable: true,
+        isSigner: false,
+      },
+      {
+        pubkey: deriveSubmissionPda(
+          ctx.program.programId,
+          bountyId,
+          agent.publicKey
+        )[0],
+        isWritable: false,
+        isSigner: false,
+      },
+      { pubkey: agent.publicKey, isWritable: false, isSigner: false },
+    ];
+  }
+
+  function batchSettle(
+    remainingAccounts: anchor.web3.AccountMeta[],
+    creator: Keypair = ctx.creator
+  ) {
+    return ctx.program.methods
+      .batchSettle()
+      .accountsPartial({
+        creator: creator.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([creator])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      100 * 10 ** 6
+    );
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+  });
+
+  it("Settles several bounties in one transaction", async () => {
+    const first = await submittedBounty(0xb1);
+    const second = await submittedBounty(0xb2);
+
+    await batchSettle([
+      ...group(first.bountyId, first.bountyPda),
+      ...group(second.bountyId, second.bountyPda),
+    ]);
+
+    for (const { bountyPda } of [first, second]) {
+      const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+      expect(bounty.status).to.deep.equal({ pendingSettlement: {} });
+      expect(bounty.agent.toString()).to.equal(agent.publicKey.toString());
+    }
+  });
+
+  it("Leaves every bounty untouched when one group is invalid", async () => {
+    const valid = await submittedBounty(0xb3);
+    const openBountyId = await nextBountyId(ctx);
+    const openBountyPda = await postBounty(
+      ctx,
+      openBountyId,
+      "Nothing submitted",
+      10 * 10 ** 6
+    );
+
+    try {
+      await batchSettle([
+        ...group(valid.bountyId, valid.bountyPda),
+        ...group(openBountyId, openBountyPda),
+      ]);
+
+      expect.fail("Should have failed - second bounty has no submission");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+
+    const bounty = await ctx.program.account.bounty.fetch(valid.bountyPda);
+    expect(bounty.status).to.deep.equal({ submitted: {} });
+  });
+
+  it("Fails when the bounty was handed to its agent", async () => {
+    const owned = await submittedBounty(0xb4);
+    await ctx.program.methods
+      .transferBountyOwnership()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: owned.bountyPda,
+        newCreator: agent.publicKey,
+        newCreatorProfile: deriveCreatorProfilePda(
+          ctx.program.programId,
+          agent.publicKey
+        )[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    try {
+      await batchSettle(group(owned.bountyId, owned.bountyPda), agent);
+
+      expect.fail("Should have failed - agent owns the bounty");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SelfSettlementForbidden");
+    }
+  });
+
+  it("Fails when the batch is empty", async () => {
+    try {
+      await batchSettle([]);
+
+      expect.fail("Should have failed - empty batch");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidBatchSize");
+    }
+  });
+});
@@ -0,0 +1,94 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  airdropSol,
+  createAttestation,
+  verifyAttestation,
+  generateRandomId,
+  generateSolutionHash,
+  TestContext,
+} from "./helpers";
+
+describe("batch_verify", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+
+  function unverifiedAttestation() {
+    return createAttestation(
+      ctx,
+      agent,
+      generateRandomId(),
+      generateRandomId(),
+      generateSolutionHash(),
+      false
+    );
+  }
+
+  function meta(attestationPda: anchor.web3.PublicKey) {
+    return { pubkey: attestationPda, isWritable: true, isSigner: false };
+  }
+
+  function batchVerify(remainingAccounts: anchor.web3.AccountMeta[]) {
+    return ctx.program.methods
+      .batchVerify()
+      .accountsPartial({
+        verifier: ctx.admin.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([ctx.admin])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+  });
+
+  it("Verifies several attestations in one transaction", async () => {
+    const first = await unverifiedAttestation();
+    const second = await unverifiedAttestation();
+
+    await batchVerify([meta(first), meta(second)]);
+
+    for (const attestationPda of [first, second]) {
+      const attestation = await ctx.program.account.attestation.fetch(attestationPda);
+      expect(attestation.verified).to.be.true;
+      expect(attestation.verifiedAt.toNumber()).to.be.greaterThan(0);
+    }
+  });
+
+  it("Leaves every attestation untouched when one is already verified", async () => {
+    const pending = await unverifiedAttestation();
+    const verified = await unverifiedAttestation();
+    await verifyAttestation(ctx, verified);
+
+    try {
+      await batchVerify([meta(pending), meta(verified)]);
+
+      expect.fail("Should have failed - second attestation already verified");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AlreadyVerified");
+    }
+
+    const attestation = await ctx.program.account.attestation.fetch(pending);
+    expect(attestation.verified).to.be.false;
+  });
+
+  it("Fails when the batch is empty", async () => {
+    try {
+      await batchVerify([]);
+
+      expect.fail("Should have failed - empty batch");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidBatchSize");
+    }
+  });
+});
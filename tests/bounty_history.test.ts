@@ -0,0 +1,82 @@
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveHistoryPda,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("bounty_history", () => {
+  let ctx: TestContext;
+  const reward = 10 * 10 ** 6;
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+  });
+
+  it("Records every transition of a settled bounty", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Audited bounty", reward);
+    const [historyPda] = deriveHistoryPda(ctx.program.programId, bountyId);
+
+    let history = await ctx.program.account.bountyHistory.fetch(historyPda);
+    expect(history.bountyId.toNumber()).to.equal(bountyId);
+    expect(history.entries.length).to.equal(1);
+    expect(history.entries[0].status).to.deep.equal({ open: {} });
+    expect(history.entries[0].actor.toString()).to.equal(
+      ctx.creator.publicKey.toString()
+    );
+
+    const solutionHash = generateSolutionHashWithValue(0x59);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+
+    history = await ctx.program.account.bountyHistory.fetch(historyPda);
+    expect(history.entries.map((entry) => entry.status)).to.deep.equal([
+      { open: {} },
+      { submitted: {} },
+      { pendingSettlement: {} },
+      { settled: {} },
+    ]);
+    expect(history.entries[1].actor.toString()).to.equal(
+      agent.publicKey.toString()
+    );
+    expect(history.totalRecorded).to.equal(4);
+
+    const timestamps = history.entries.map((entry) => entry.timestamp.toNumber());
+    expect(timestamps).to.deep.equal([...timestamps].sort((a, b) => a - b));
+  });
+});
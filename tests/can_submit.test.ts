@@ -0,0 +1,91 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  airdropSol,
+  postBounty,
+  buildWhitelist,
+  nextBountyId,
+  TestContext,
+} from "./helpers";
+
+describe("can_submit", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+  });
+
+  function canSubmit(bountyPda: anchor.web3.PublicKey, proof: number[][] = []) {
+    return ctx.program.methods
+      .canSubmit(proof)
+      .accountsPartial({
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        agent: agent.publicKey,
+        reputation: null,
+      })
+      .view();
+  }
+
+  it("Reports a new agent as eligible on an open bounty", async () => {
+    const bountyPda = await postBounty(ctx, await nextBountyId(ctx), "Open bounty", 10 * 10 ** 6);
+
+    const eligibility = await canSubmit(bountyPda);
+
+    expect(eligibility).to.deep.equal({
+      bountyOpen: true,
+      notFlagged: true,
+      beforeDeadline: true,
+      whitelisted: true,
+      cooldownElapsed: true,
+      tierMet: true,
+      scoreMet: true,
+      belowSubmissionCap: true,
+    });
+  });
+
+  it("Reports every guard the agent fails", async () => {
+    const outsider = Keypair.generate();
+    const bountyPda = await postBounty(ctx, await nextBountyId(ctx), "Gated bounty", 10 * 10 ** 6, {
+      whitelistRoot: buildWhitelist([outsider.publicKey]).root,
+      minReputationScore: 5,
+    });
+
+    const eligibility = await canSubmit(bountyPda);
+
+    expect(eligibility.bountyOpen).to.be.true;
+    expect(eligibility.whitelisted).to.be.false;
+    expect(eligibility.scoreMet).to.be.false;
+  });
+
+  it("Rejects a reputation account of another agent", async () => {
+    const bountyPda = await postBounty(ctx, await nextBountyId(ctx), "Open bounty", 10 * 10 ** 6);
+    const other = Keypair.generate();
+
+    try {
+      await ctx.program.methods
+        .canSubmit([])
+        .accountsPartial({
+          config: deriveConfigPda(ctx.program.programId)[0],
+          bounty: bountyPda,
+          agent: agent.publicKey,
+          reputation: deriveReputationPda(ctx.program.programId, other.publicKey)[0],
+        })
+        .view();
+
+      expect.fail("Should have failed - reputation of another agent");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+});
@@ -0,0 +1,206 @@
+import * as anchor from "@coral-xyz/anchor";
+import { createMint, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("cancel_bounty", () => {
+  let ctx: TestContext;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let bountyTokenAccount: anchor.web3.PublicKey;
+  const reward = 100 * 10 ** 6;
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      200 * 10 ** 6
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Bounty to cancel", reward);
+    bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
+  });
+
+  it("Cancels an open bounty and refunds the creator", async () => {
+    const creatorBalanceBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+
+    await ctx.program.methods
+      .cancelBounty()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: bountyTokenAccount,
+        rewardMint: ctx.usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ cancelled: {} });
+
+    const creatorBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+    const bountyBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      bountyTokenAccount
+    );
+
+    expect(bountyBalanceAfter.value.amount).to.equal("0");
+    expect(creatorBalanceAfter.value.amount).to.equal(
+      (Number(creatorBalanceBefore.value.amount) + reward).toString()
+    );
+  });
+
+  it("Fails when caller is not the bounty creator", async () => {
+    const wrongCreator = Keypair.generate();
+    await airdropSol(ctx.connection, wrongCreator.publicKey);
+
+    try {
+      await ctx.program.methods
+        .cancelBounty()
+        .accountsPartial({
+          creator: wrongCreator.publicKey,
+          bounty: bountyPda,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: bountyTokenAccount,
+          rewardMint: ctx.usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([wrongCreator])
+        .rpc();
+
+      expect.fail("Should have failed - unauthorized creator");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails when the reward mint does not match the bounty", async () => {
+    const otherMint = await createMint(
+      ctx.connection,
+      ctx.creator,
+      ctx.creator.publicKey,
+      null,
+      6
+    );
+
+    try {
+      await ctx.program.methods
+        .cancelBounty()
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: bountyPda,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: bountyTokenAccount,
+          rewardMint: otherMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed - reward mint mismatch");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("RewardMintMismatch");
+    }
+  });
+
+  it("Fails once a solution has been submitted", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const solutionHash = generateSolutionHashWithValue(0x3c);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    try {
+      await ctx.program.methods
+        .cancelBounty()
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: bountyPda,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: bountyTokenAccount,
+          rewardMint: ctx.usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed - solution already submitted");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyAlreadySubmitted");
+    }
+  });
+
+  it("Fails once a competition bounty has received a submission", async () => {
+    const competitionId = await nextBountyId(ctx);
+    const competitionPda = await postBounty(ctx, competitionId, "Competition to cancel", reward, {
+      competition: true,
+    });
+
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const solutionHash = generateSolutionHashWithValue(0x3d);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      competitionId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, competitionPda, attestationPda, solutionHash);
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(competitionPda);
+    expect(bountyAccount.status).to.deep.equal({ open: {} });
+    expect(bountyAccount.submissionCount).to.equal(1);
+
+    try {
+      await ctx.program.methods
+        .cancelBounty()
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: competitionPda,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: await fetchEscrowTokenAccount(ctx, competitionPda),
+          rewardMint: ctx.usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed - bounty has submissions");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyHasSubmissions");
+    }
+  });
+});
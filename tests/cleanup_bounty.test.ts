@@ -0,0 +1,179 @@
+import * as anchor from "@coral-xyz/anchor";
+import { mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveStakePda,
+  deriveSubmissionPda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("cleanup_bounty", () => {
+  let ctx: TestContext;
+  let bountyPda: anchor.web3.PublicKey;
+  let bountyTokenAccount: anchor.web3.PublicKey;
+
+  function cleanupBounty() {
+    return ctx.program.methods
+      .cleanupBounty()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        bountyTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+  }
+
+  async function submit(value: number): Promise<Keypair> {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const solutionHash = generateSolutionHashWithValue(value);
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bounty.id.toNumber(),
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    return agent;
+  }
+
+  async function closeSubmission(agent: Keypair): Promise<void> {
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const bountyId = bounty.id.toNumber();
+    await ctx.program.methods
+      .closeSubmission()
+      .accountsPartial({
+        agent: agent.publicKey,
+        bounty: bountyPda,
+        submission: deriveSubmissionPda(ctx.program.programId, bountyId, agent.publicKey)[0],
+        stake: deriveStakePda(ctx.program.programId, bountyId, agent.publicKey)[0],
+      })
+      .signers([agent])
+      .rpc();
+  }
+
+  async function settleWith(agent: Keypair): Promise<void> {
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+  }
+
+  async function settle(): Promise<void> {
+    const agent = await submit(0x80);
+    await settleWith(agent);
+    await closeSubmission(agent);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      10 * 10 ** 6
+    );
+
+    bountyPda = await postBounty(ctx, await nextBountyId(ctx), "Bounty to clean up", 10 * 10 ** 6);
+    bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
+  });
+
+  it("Closes a settled bounty and its escrow and refunds the rent", async () => {
+    await settle();
+
+    const rent =
+      (await ctx.connection.getBalance(bountyPda)) +
+      (await ctx.connection.getBalance(bountyTokenAccount));
+    const creatorBefore = await ctx.connection.getBalance(ctx.creator.publicKey);
+
+    await cleanupBounty();
+
+    expect(await ctx.connection.getAccountInfo(bountyPda)).to.be.null;
+    expect(await ctx.connection.getAccountInfo(bountyTokenAccount)).to.be.null;
+    // the creator also pays the transaction fee
+    expect(await ctx.connection.getBalance(ctx.creator.publicKey)).to.be.greaterThan(
+      creatorBefore + rent - 10_000
+    );
+  });
+
+  it("Fails while the bounty is still open", async () => {
+    try {
+      await cleanupBounty();
+
+      expect.fail("Should have failed - bounty still open");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotClosable");
+    }
+  });
+
+  it("Fails while a losing agent still holds its submission", async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      10 * 10 ** 6
+    );
+    bountyPda = await postBounty(
+      ctx,
+      await nextBountyId(ctx),
+      "Competition to clean up",
+      10 * 10 ** 6,
+      { competition: true }
+    );
+    bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
+    const winner = await submit(0x81);
+    const loser = await submit(0x82);
+    await settleWith(winner);
+    await closeSubmission(winner);
+
+    try {
+      await cleanupBounty();
+
+      expect.fail("Should have failed - loser submission still open");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SubmissionsOutstanding");
+    }
+
+    // once the loser has its stake back the bounty can go
+    await closeSubmission(loser);
+    await cleanupBounty();
+    expect(await ctx.connection.getAccountInfo(bountyPda)).to.be.null;
+  });
+
+  it("Fails while the escrow still holds tokens", async () => {
+    await settle();
+    await mintTo(ctx.connection, ctx.creator, ctx.usdcMint, bountyTokenAccount, ctx.creator, 5);
+
+    try {
+      await cleanupBounty();
+
+      expect.fail("Should have failed - escrow not empty");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("EscrowNotEmpty");
+    }
+  });
+});
@@ -0,0 +1,134 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("close_attestation", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyPda: anchor.web3.PublicKey;
+  let attestationPda: anchor.web3.PublicKey;
+
+  function closeAttestation(signer: Keypair) {
+    return ctx.program.methods
+      .closeAttestation()
+      .accountsPartial({
+        agent: signer.publicKey,
+        bounty: bountyPda,
+        attestation: attestationPda,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async function cancelBounty() {
+    await ctx.program.methods
+      .cancelBounty()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, bountyPda),
+        rewardMint: ctx.usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      100 * 10 ** 6
+    );
+
+    const bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Attested bounty", 10 * 10 ** 6);
+    attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      generateSolutionHashWithValue(0xc7)
+    );
+  });
+
+  it("Returns the attestation rent once the bounty is cancelled", async () => {
+    await cancelBounty();
+
+    const rent = (await ctx.connection.getAccountInfo(attestationPda)).lamports;
+    const balanceBefore = await ctx.connection.getBalance(agent.publicKey);
+
+    await closeAttestation(agent);
+
+    expect(await ctx.connection.getAccountInfo(attestationPda)).to.be.null;
+    const balanceAfter = await ctx.connection.getBalance(agent.publicKey);
+    expect(balanceAfter).to.be.greaterThan(balanceBefore + rent - 10_000);
+  });
+
+  it("Returns the attestation rent once the bounty was cleaned up", async () => {
+    await cancelBounty();
+    await ctx.program.methods
+      .cleanupBounty()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, bountyPda),
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+    expect(await ctx.connection.getAccountInfo(bountyPda)).to.be.null;
+
+    await closeAttestation(agent);
+
+    expect(await ctx.connection.getAccountInfo(attestationPda)).to.be.null;
+  });
+
+  it("Fails while the bounty is still open", async () => {
+    try {
+      await closeAttestation(agent);
+
+      expect.fail("Should have failed - bounty not finalized");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotFinalized");
+    }
+  });
+
+  it("Fails when the attestation belongs to another agent", async () => {
+    await cancelBounty();
+
+    const otherAgent = Keypair.generate();
+    await airdropSol(ctx.connection, otherAgent.publicKey);
+
+    try {
+      await closeAttestation(otherAgent);
+
+      expect.fail("Should have failed - attestation owner mismatch");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AttestationOwnerMismatch");
+    }
+  });
+});
@@ -0,0 +1,166 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveSubmissionPda,
+  deriveStakePda,
+  deriveReputationPda,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TEST_STAKE_AMOUNT,
+  TestContext,
+} from "./helpers";
+
+describe("competition bounties", () => {
+  let ctx: TestContext;
+  let agentA: Keypair;
+  let agentB: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const hashA = generateSolutionHashWithValue(0xa1);
+  const hashB = generateSolutionHashWithValue(0xb2);
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      200 * 10 ** 6
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(
+      ctx,
+      bountyId,
+      "Competition bounty",
+      100 * 10 ** 6,
+      { competition: true }
+    );
+
+    agentA = Keypair.generate();
+    agentB = Keypair.generate();
+    await airdropSol(ctx.connection, agentA.publicKey);
+    await airdropSol(ctx.connection, agentB.publicKey);
+
+    const attestationA = await createAttestation(
+      ctx,
+      agentA,
+      bountyId,
+      generateRandomId(),
+      hashA
+    );
+    const attestationB = await createAttestation(
+      ctx,
+      agentB,
+      bountyId,
+      generateRandomId(),
+      hashB
+    );
+
+    await submitSolution(ctx, agentA, bountyPda, attestationA, hashA);
+    await submitSolution(ctx, agentB, bountyPda, attestationB, hashB);
+  });
+
+  async function settleWith(winner: Keypair): Promise<void> {
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      winner,
+      ctx.usdcMint
+    );
+
+    await settleAndFinalize(ctx, bountyPda, winner, agentTokenAccount);
+  }
+
+  it("Keeps the bounty open while agents compete", async () => {
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ open: {} });
+    expect(bountyAccount.solutionHash).to.be.null;
+
+    const submissionA = await ctx.program.account.submission.fetch(
+      deriveSubmissionPda(ctx.program.programId, bountyId, agentA.publicKey)[0]
+    );
+    expect(Buffer.from(submissionA.solutionHash)).to.deep.equal(hashA);
+    expect(submissionA.agent.toString()).to.equal(agentA.publicKey.toString());
+  });
+
+  it("Settles to the chosen winning submission", async () => {
+    await settleWith(agentB);
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ settled: {} });
+    expect(Buffer.from(bountyAccount.solutionHash)).to.deep.equal(hashB);
+    expect(bountyAccount.agent.toString()).to.equal(agentB.publicKey.toString());
+  });
+
+  it("Lets a non-winning agent close their submission after settlement", async () => {
+    await settleWith(agentB);
+
+    const [submissionA] = deriveSubmissionPda(
+      ctx.program.programId,
+      bountyId,
+      agentA.publicKey
+    );
+    const balanceBefore = await ctx.connection.getBalance(agentA.publicKey);
+
+    await ctx.program.methods
+      .closeSubmission()
+      .accountsPartial({
+        agent: agentA.publicKey,
+        bounty: bountyPda,
+        submission: submissionA,
+        stake: deriveStakePda(ctx.program.programId, bountyId, agentA.publicKey)[0],
+      })
+      .signers([agentA])
+      .rpc();
+
+    expect(await ctx.connection.getAccountInfo(submissionA)).to.be.null;
+    const reputationA = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agentA.publicKey)[0]
+    );
+    expect(reputationA.activeSubmissions).to.equal(0);
+    expect(await ctx.connection.getBalance(agentA.publicKey)).to.be.greaterThan(
+      balanceBefore + TEST_STAKE_AMOUNT
+    );
+  });
+
+  it("Fails to close a submission before the bounty is final", async () => {
+    try {
+      await ctx.program.methods
+        .closeSubmission()
+        .accountsPartial({
+          agent: agentA.publicKey,
+          bounty: bountyPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            bountyId,
+            agentA.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            bountyId,
+            agentA.publicKey
+          )[0],
+        })
+        .signers([agentA])
+        .rpc();
+
+      expect.fail("Should have failed - bounty not finalized");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+});
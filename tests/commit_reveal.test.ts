@@ -0,0 +1,217 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveCommitmentPda,
+  deriveReputationPda,
+  deriveStakePda,
+  deriveSubmissionPda,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  commitSolution,
+  computeCommitment,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHash,
+  generateSolutionHashWithValue,
+  defaultDeadline,
+  sleep,
+  updateConfig,
+  TEST_REVEAL_DELAY,
+  TestContext,
+} from "./helpers";
+
+describe("commit_reveal", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let attestationPda: anchor.web3.PublicKey;
+  let nonce: Buffer;
+  const solutionHash = generateSolutionHashWithValue(0x5c);
+
+  function revealSolution(
+    revealer: Keypair,
+    attestation: anchor.web3.PublicKey,
+    revealNonce: Buffer
+  ) {
+    return ctx.program.methods
+      .revealSolution(Array.from(solutionHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(revealNonce), [])
+      .accountsPartial({
+        agent: revealer.publicKey,
+        bounty: bountyPda,
+        attestation,
+        commitment: deriveCommitmentPda(
+          ctx.program.programId,
+          bountyId,
+          revealer.publicKey
+        )[0],
+        reputation: deriveReputationPda(
+          ctx.program.programId,
+          revealer.publicKey
+        )[0],
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          bountyId,
+          revealer.publicKey
+        )[0],
+        stake: deriveStakePda(
+          ctx.program.programId,
+          bountyId,
+          revealer.publicKey
+        )[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([revealer])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Commit-reveal bounty", 50 * 10 ** 6);
+    attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    nonce = generateSolutionHash();
+  });
+
+  it("Stores the commitment and reveals it into a submission", async () => {
+    const commitmentPda = await commitSolution(
+      ctx,
+      agent,
+      bountyPda,
+      solutionHash,
+      nonce
+    );
+
+    const commitment = await ctx.program.account.commitment.fetch(commitmentPda);
+    expect(commitment.agent.toString()).to.equal(agent.publicKey.toString());
+    expect(commitment.bountyId.toNumber()).to.equal(bountyId);
+    expect(Buffer.from(commitment.commitment)).to.deep.equal(
+      computeCommitment(solutionHash, agent.publicKey, nonce)
+    );
+
+    await revealSolution(agent, attestationPda, nonce);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ submitted: {} });
+    expect(bounty.agent.toString()).to.equal(agent.publicKey.toString());
+
+    const commitmentInfo = await ctx.connection.getAccountInfo(commitmentPda);
+    expect(commitmentInfo).to.be.null;
+  });
+
+  it("Fails when the nonce does not match the commitment", async () => {
+    await commitSolution(ctx, agent, bountyPda, solutionHash, nonce);
+
+    try {
+      await revealSolution(agent, attestationPda, generateSolutionHash());
+
+      expect.fail("Should have failed - wrong nonce");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("CommitmentMismatch");
+    }
+  });
+
+  it("Stops a copycat from reusing another agent's commitment", async () => {
+    const copycat = Keypair.generate();
+    await airdropSol(ctx.connection, copycat.publicKey);
+    const copycatAttestation = await createAttestation(
+      ctx,
+      copycat,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+
+    const commitmentPda = await commitSolution(
+      ctx,
+      agent,
+      bountyPda,
+      solutionHash,
+      nonce
+    );
+    const { commitment } = await ctx.program.account.commitment.fetch(
+      commitmentPda
+    );
+
+    await ctx.program.methods
+      .commitSolution(commitment)
+      .accountsPartial({
+        agent: copycat.publicKey,
+        bounty: bountyPda,
+        commitment: deriveCommitmentPda(
+          ctx.program.programId,
+          bountyId,
+          copycat.publicKey
+        )[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([copycat])
+      .rpc();
+
+    try {
+      await revealSolution(copycat, copycatAttestation, nonce);
+
+      expect.fail("Should have failed - commitment bound to another agent");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("CommitmentMismatch");
+    }
+
+    await revealSolution(agent, attestationPda, nonce);
+  });
+
+  it("Fails when revealing before the reveal delay has elapsed", async () => {
+    await updateConfig(ctx, { revealDelay: new anchor.BN(3600) });
+
+    try {
+      await commitSolution(ctx, agent, bountyPda, solutionHash, nonce);
+      await revealSolution(agent, attestationPda, nonce);
+
+      expect.fail("Should have failed - reveal delay not elapsed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("RevealTooEarly");
+    } finally {
+      await updateConfig(ctx, {
+        revealDelay: new anchor.BN(TEST_REVEAL_DELAY),
+      });
+    }
+  });
+
+  it("Fails when revealing after the deadline", async () => {
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Short-lived bounty", 50 * 10 ** 6, {
+      deadline: defaultDeadline(4),
+    });
+    attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await commitSolution(ctx, agent, bountyPda, solutionHash, nonce);
+    await sleep(6000);
+
+    try {
+      await revealSolution(agent, attestationPda, nonce);
+
+      expect.fail("Should have failed - bounty expired");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyExpired");
+    }
+  });
+});
@@ -0,0 +1,163 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveContributionPda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  nextBountyId,
+  TestContext,
+} from "./helpers";
+
+describe("contribute_to_bounty", () => {
+  let ctx: TestContext;
+  let funder: Keypair;
+  let funderTokenAccount: anchor.web3.PublicKey;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let bountyTokenAccount: anchor.web3.PublicKey;
+  let contributionPda: anchor.web3.PublicKey;
+  const reward = 50 * 10 ** 6;
+  const contribution = 20 * 10 ** 6;
+
+  async function contribute(amount: number) {
+    await ctx.program.methods
+      .contributeToBounty(new anchor.BN(amount))
+      .accountsPartial({
+        funder: funder.publicKey,
+        bounty: bountyPda,
+        contribution: contributionPda,
+        funderTokenAccount,
+        bountyTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([funder])
+      .rpc();
+  }
+
+  async function withdrawContribution() {
+    await ctx.program.methods
+      .withdrawContribution()
+      .accountsPartial({
+        funder: funder.publicKey,
+        bounty: bountyPda,
+        contribution: contributionPda,
+        funderTokenAccount,
+        bountyTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([funder])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    funder = Keypair.generate();
+    await airdropSol(ctx.connection, funder.publicKey);
+    funderTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      funder,
+      ctx.usdcMint
+    );
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      funderTokenAccount,
+      contribution
+    );
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      100 * 10 ** 6
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Pooled bounty", reward);
+    bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
+    [contributionPda] = deriveContributionPda(
+      ctx.program.programId,
+      bountyId,
+      funder.publicKey
+    );
+  });
+
+  it("Adds the contribution to the reward and escrow", async () => {
+    await contribute(contribution);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.reward.toNumber()).to.equal(reward + contribution);
+    expect(bounty.contributed.toNumber()).to.equal(contribution);
+
+    const record = await ctx.program.account.contribution.fetch(contributionPda);
+    expect(record.funder.toString()).to.equal(funder.publicKey.toString());
+    expect(record.amount.toNumber()).to.equal(contribution);
+
+    const escrow = await ctx.connection.getTokenAccountBalance(bountyTokenAccount);
+    expect(escrow.value.amount).to.equal((reward + contribution).toString());
+  });
+
+  it("Refunds the creator and each funder their own share on cancel", async () => {
+    await contribute(contribution);
+
+    const creatorBalanceBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+
+    await ctx.program.methods
+      .cancelBounty()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount,
+        rewardMint: ctx.usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const creatorBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+    expect(
+      Number(creatorBalanceAfter.value.amount) -
+        Number(creatorBalanceBefore.value.amount)
+    ).to.equal(reward);
+
+    await withdrawContribution();
+
+    const funderBalance = await ctx.connection.getTokenAccountBalance(
+      funderTokenAccount
+    );
+    expect(funderBalance.value.amount).to.equal(contribution.toString());
+
+    const escrow = await ctx.connection.getTokenAccountBalance(bountyTokenAccount);
+    expect(escrow.value.amount).to.equal("0");
+    expect(await ctx.connection.getAccountInfo(contributionPda)).to.be.null;
+  });
+
+  it("Fails to withdraw while the bounty is still open", async () => {
+    await contribute(contribution);
+
+    try {
+      await withdrawContribution();
+
+      expect.fail("Should have failed - bounty not refundable");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotRefundable");
+    }
+  });
+});
@@ -0,0 +1,114 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveCreatorProfilePda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("creator_profile", () => {
+  let ctx: TestContext;
+  let creatorProfilePda: anchor.web3.PublicKey;
+  const reward = 20 * 10 ** 6;
+
+  function fetchProfile() {
+    return ctx.program.account.creatorProfile.fetch(creatorProfilePda);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+    [creatorProfilePda] = deriveCreatorProfilePda(
+      ctx.program.programId,
+      ctx.creator.publicKey
+    );
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      3 * reward
+    );
+  });
+
+  it("Counts bounties the creator posts", async () => {
+    await postBounty(ctx, await nextBountyId(ctx), "Profiled bounty", reward);
+    const before = await fetchProfile();
+
+    await postBounty(ctx, await nextBountyId(ctx), "Profiled bounty", reward);
+
+    const after = await fetchProfile();
+    expect(after.creator.toString()).to.equal(ctx.creator.publicKey.toString());
+    expect(after.bountiesCreated.toNumber()).to.equal(
+      before.bountiesCreated.toNumber() + 1
+    );
+  });
+
+  it("Counts cancelled bounties", async () => {
+    const bountyPda = await postBounty(ctx, await nextBountyId(ctx), "Cancelled bounty", reward);
+    const before = await fetchProfile();
+
+    await ctx.program.methods
+      .cancelBounty()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        creatorProfile: creatorProfilePda,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, bountyPda),
+        rewardMint: ctx.usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const after = await fetchProfile();
+    expect(after.bountiesCancelled.toNumber()).to.equal(
+      before.bountiesCancelled.toNumber() + 1
+    );
+  });
+
+  it("Counts settled bounties and the reward paid", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Settled bounty", reward);
+    const solutionHash = generateSolutionHashWithValue(0xcf);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    const before = await fetchProfile();
+
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+
+    const after = await fetchProfile();
+    expect(after.bountiesSettled.toNumber()).to.equal(
+      before.bountiesSettled.toNumber() + 1
+    );
+    expect(after.totalPaid.toNumber()).to.equal(before.totalPaid.toNumber() + reward);
+  });
+});
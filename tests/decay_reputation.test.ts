@@ -0,0 +1,78 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("decay_reputation", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let reputationPda: anchor.web3.PublicKey;
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      100 * 10 ** 6
+    );
+
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const solutionHash = generateSolutionHashWithValue(0xd1);
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(
+      ctx,
+      bountyId,
+      "Decay bounty",
+      50 * 10 ** 6
+    );
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    [reputationPda] = deriveReputationPda(
+      ctx.program.programId,
+      agent.publicKey
+    );
+  });
+
+  it("Leaves the score unchanged after a short gap", async () => {
+    const before = await ctx.program.account.reputation.fetch(reputationPda);
+
+    await ctx.program.methods
+      .decayReputation()
+      .accountsPartial({
+        config: deriveConfigPda(ctx.program.programId)[0],
+        reputation: reputationPda,
+      })
+      .rpc();
+
+    const after = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(after.score.toNumber()).to.equal(before.score.toNumber());
+    expect(after.lastActive.toNumber()).to.be.at.least(
+      before.lastActive.toNumber()
+    );
+  });
+});
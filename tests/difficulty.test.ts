@@ -0,0 +1,80 @@
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveReputationPda,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  settleAndFinalize,
+  TEST_SCORE_ON_SETTLE,
+  TestContext,
+} from "./helpers";
+
+describe("difficulty", () => {
+  let ctx: TestContext;
+  const solutionHash = generateSolutionHashWithValue(0xd1);
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  async function scoreAfterSettling(difficulty: number): Promise<number> {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      10 * 10 ** 6
+    );
+
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(
+      ctx,
+      bountyId,
+      `Difficulty ${difficulty} bounty`,
+      10 * 10 ** 6,
+      { difficulty }
+    );
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.difficulty).to.equal(difficulty);
+
+    const reputation = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agent.publicKey)[0]
+    );
+    return reputation.score.toNumber();
+  }
+
+  it("Awards score in proportion to difficulty", async () => {
+    const easyScore = await scoreAfterSettling(1);
+    const hardScore = await scoreAfterSettling(5);
+
+    expect(easyScore).to.equal(TEST_SCORE_ON_SETTLE);
+    expect(hardScore).to.equal(5 * TEST_SCORE_ON_SETTLE);
+    expect(hardScore).to.be.greaterThan(easyScore);
+  });
+});
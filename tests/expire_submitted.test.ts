@@ -0,0 +1,136 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  deriveStakePda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  defaultDeadline,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  expectedFee,
+  sleep,
+  TEST_GRACE_PERIOD,
+  TestContext,
+} from "./helpers";
+
+describe("expire_submitted", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let caller: Keypair;
+  let agentTokenAccount: anchor.web3.PublicKey;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const deadlineSeconds = 8; // leaves room to submit before the deadline
+  const reward = 40 * 10 ** 6;
+
+  async function expireSubmitted() {
+    return ctx.program.methods
+      .expireSubmitted()
+      .accountsPartial({
+        caller: caller.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        reputation: deriveReputationPda(
+          ctx.program.programId,
+          agent.publicKey
+        )[0],
+        referrerReputation: null,
+        agent: agent.publicKey,
+        stake: deriveStakePda(ctx.program.programId, bountyId, agent.publicKey)[0],
+        agentTokenAccount,
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, bountyPda),
+        treasuryTokenAccount: ctx.treasuryTokenAccount,
+        treasury: null,
+        vesting: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: null,
+      })
+      .signers([caller])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    caller = Keypair.generate();
+    await airdropSol(ctx.connection, caller.publicKey);
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      100 * 10 ** 6
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Creator goes quiet", reward, {
+      deadline: defaultDeadline(deadlineSeconds),
+    });
+
+    const solutionHash = generateSolutionHashWithValue(0xe5);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  });
+
+  it("Fails before the grace period has elapsed", async () => {
+    try {
+      await expireSubmitted();
+
+      expect.fail("Should have failed - grace period not elapsed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("GracePeriodNotElapsed");
+    }
+  });
+
+  it("Pays the agent and credits reputation after the grace period", async () => {
+    await sleep((deadlineSeconds + TEST_GRACE_PERIOD + 2) * 1000);
+
+    await expireSubmitted();
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ settled: {} });
+
+    const agentBalance = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
+    expect(agentBalance.value.amount).to.equal(
+      (reward - expectedFee(reward)).toString()
+    );
+
+    const reputation = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agent.publicKey)[0]
+    );
+    expect(reputation.successfulBounties.toNumber()).to.equal(1);
+    expect(reputation.totalEarned.toNumber()).to.equal(
+      reward - expectedFee(reward)
+    );
+  });
+});
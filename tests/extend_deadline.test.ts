@@ -0,0 +1,131 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("extend_deadline", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+
+  function extendDeadline(deadline: anchor.BN, coSigner: Keypair | null = null) {
+    return ctx.program.methods
+      .extendDeadline(deadline)
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        agent: coSigner ? coSigner.publicKey : null,
+        bounty: bountyPda,
+      })
+      .signers(coSigner ? [ctx.creator, coSigner] : [ctx.creator])
+      .rpc();
+  }
+
+  async function laterDeadline(seconds: number) {
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    return bounty.deadline.add(new anchor.BN(seconds));
+  }
+
+  async function submit() {
+    const solutionHash = generateSolutionHashWithValue(0x62);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      10 * 10 ** 6
+    );
+
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Extended bounty", 10 * 10 ** 6);
+  });
+
+  it("Lets the creator alone extend an open bounty", async () => {
+    const deadline = await laterDeadline(60 * 60);
+
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("deadlineExtended", (e) => resolve(e));
+    });
+
+    await extendDeadline(deadline);
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.deadline.toNumber()).to.equal(deadline.toNumber());
+    expect(emitted.newDeadline.toNumber()).to.equal(deadline.toNumber());
+    expect(emitted.previousDeadline.toNumber()).to.equal(deadline.toNumber() - 60 * 60);
+  });
+
+  it("Fails to shorten the deadline", async () => {
+    try {
+      await extendDeadline(await laterDeadline(-60));
+
+      expect.fail("Should have failed - deadline moved earlier");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("DeadlineNotExtended");
+    }
+  });
+
+  it("Requires the submitting agent to co-sign once submitted", async () => {
+    await submit();
+    const deadline = await laterDeadline(60 * 60);
+
+    try {
+      await extendDeadline(deadline);
+
+      expect.fail("Should have failed - agent did not co-sign");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AgentConsentRequired");
+    }
+
+    await extendDeadline(deadline, agent);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.deadline.toNumber()).to.equal(deadline.toNumber());
+  });
+
+  it("Fails when another agent co-signs a submitted bounty", async () => {
+    await submit();
+    const stranger = Keypair.generate();
+
+    try {
+      await extendDeadline(await laterDeadline(60 * 60), stranger);
+
+      expect.fail("Should have failed - co-signer is not the submitting agent");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AgentMismatch");
+    }
+  });
+});
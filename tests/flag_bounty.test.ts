@@ -0,0 +1,170 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  updateConfig,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("flag_bounty", () => {
+  let ctx: TestContext;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const moderator = Keypair.generate();
+  const reasonHash = Array(32).fill(0x64);
+  const reward = 10 * 10 ** 6;
+
+  async function flagBounty(signer: Keypair, reason: number[] = reasonHash): Promise<string> {
+    return ctx.program.methods
+      .flagBounty(reason)
+      .accountsPartial({
+        moderator: signer.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async function unflagBounty(signer: Keypair): Promise<string> {
+    return ctx.program.methods
+      .unflagBounty()
+      .accountsPartial({
+        moderator: signer.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  async function submit(value: number): Promise<void> {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const solutionHash = generateSolutionHashWithValue(value);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+    await airdropSol(ctx.connection, moderator.publicKey);
+    await updateConfig(ctx, { moderators: [moderator.publicKey] });
+  });
+
+  after(async () => {
+    await updateConfig(ctx, { moderators: [] });
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Suspicious bounty", reward);
+  });
+
+  it("Blocks submissions while flagged and takes them again once unflagged", async () => {
+    await flagBounty(moderator);
+    expect((await ctx.program.account.bounty.fetch(bountyPda)).flagged).to.be.true;
+
+    try {
+      await submit(0x64);
+      expect.fail("Should have failed - bounty is flagged");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyFlagged");
+    }
+
+    await unflagBounty(moderator);
+    await submit(0x65);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.flagged).to.be.false;
+    expect(bounty.submissionCount.toNumber()).to.equal(1);
+  });
+
+  it("Lets the admin flag without being listed as a moderator", async () => {
+    await flagBounty(ctx.admin);
+    expect((await ctx.program.account.bounty.fetch(bountyPda)).flagged).to.be.true;
+  });
+
+  it("Keeps the reward reclaimable by the creator", async () => {
+    await flagBounty(moderator);
+
+    const creatorBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+    await ctx.program.methods
+      .cancelBounty()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, bountyPda),
+        rewardMint: ctx.usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const creatorAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+    expect(
+      Number(creatorAfter.value.amount) - Number(creatorBefore.value.amount)
+    ).to.equal(reward);
+  });
+
+  it("Fails when the signer is not a moderator", async () => {
+    const outsider = Keypair.generate();
+    await airdropSol(ctx.connection, outsider.publicKey);
+
+    try {
+      await flagBounty(outsider);
+      expect.fail("Should have failed - not a moderator");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("UnauthorizedModerator");
+    }
+  });
+
+  it("Fails without a reason hash", async () => {
+    try {
+      await flagBounty(moderator, Array(32).fill(0));
+      expect.fail("Should have failed - zero reason hash");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidReasonHash");
+    }
+  });
+
+  it("Fails to unflag a bounty that is not flagged", async () => {
+    try {
+      await unflagBounty(moderator);
+      expect.fail("Should have failed - bounty is not flagged");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotFlagged");
+    }
+  });
+});
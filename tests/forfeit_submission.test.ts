@@ -0,0 +1,120 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  deriveSubmissionPda,
+  deriveStakePda,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  updateConfig,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TEST_REJECTION_PENALTY,
+  TEST_STAKE_AMOUNT,
+  TestContext,
+} from "./helpers";
+
+describe("forfeit_submission", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Bounty to forfeit", 100 * 10 ** 6);
+
+    const solutionHash = generateSolutionHashWithValue(0x58);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  });
+
+  function forfeit(signer: Keypair, owner: Keypair = agent) {
+    return ctx.program.methods
+      .forfeitSubmission()
+      .accountsPartial({
+        agent: signer.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        reputation: deriveReputationPda(ctx.program.programId, owner.publicKey)[0],
+        submission: deriveSubmissionPda(ctx.program.programId, bountyId, owner.publicKey)[0],
+        stake: deriveStakePda(ctx.program.programId, bountyId, owner.publicKey)[0],
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  it("Reopens the bounty and returns the stake", async () => {
+    const [reputationPda] = deriveReputationPda(ctx.program.programId, agent.publicKey);
+    const reputationBefore = await ctx.program.account.reputation.fetch(reputationPda);
+    const balanceBefore = await ctx.connection.getBalance(agent.publicKey);
+
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("solutionForfeited", (e) => resolve(e));
+    });
+
+    await forfeit(agent);
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+    expect(emitted.agent.toString()).to.equal(agent.publicKey.toString());
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ open: {} });
+    expect(bounty.solutionHash).to.be.null;
+    expect(bounty.agent).to.be.null;
+
+    const balanceAfter = await ctx.connection.getBalance(agent.publicKey);
+    expect(balanceAfter).to.be.greaterThan(balanceBefore + TEST_STAKE_AMOUNT - 10_000);
+
+    const reputationAfter = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputationAfter.failedBounties.toNumber()).to.equal(
+      reputationBefore.failedBounties.toNumber()
+    );
+  });
+
+  it("Fails when called by someone other than the submitting agent", async () => {
+    const other = Keypair.generate();
+    await airdropSol(ctx.connection, other.publicKey);
+
+    try {
+      await forfeit(other, agent);
+
+      expect.fail("Should have failed - not the submitting agent");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails to configure a forfeit penalty above the rejection penalty", async () => {
+    try {
+      await updateConfig(ctx, {
+        forfeitPenalty: new anchor.BN(TEST_REJECTION_PENALTY + 1),
+      });
+
+      expect.fail("Should have failed - forfeit penalty too high");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidForfeitPenalty");
+    }
+  });
+});
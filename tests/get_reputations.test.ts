@@ -0,0 +1,88 @@
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  airdropSol,
+  initializeReputation,
+  TestContext,
+} from "./helpers";
+
+describe("get_reputations", () => {
+  let ctx: TestContext;
+  const agents = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+
+  function reputationAccounts(keys: Keypair[]) {
+    return keys.map((agent) => ({
+      pubkey: deriveReputationPda(ctx.program.programId, agent.publicKey)[0],
+      isWritable: false,
+      isSigner: false,
+    }));
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+    for (const agent of agents) {
+      await airdropSol(ctx.connection, agent.publicKey);
+      await initializeReputation(ctx, agent);
+    }
+  });
+
+  it("Reports a page of reputations in one event", async () => {
+    const { events } = await ctx.program.methods
+      .getReputations()
+      .accountsPartial({
+        config: deriveConfigPda(ctx.program.programId)[0],
+      })
+      .remainingAccounts(reputationAccounts(agents))
+      .simulate();
+
+    const read = events.find((event) => event.name === "reputationsRead");
+    expect(read).to.exist;
+    const entries = read!.data.entries as any[];
+    expect(entries.map((entry) => entry.agent.toString())).to.deep.equal(
+      agents.map((agent) => agent.publicKey.toString())
+    );
+    for (const entry of entries) {
+      expect(entry.score.toNumber()).to.equal(0);
+      expect(entry.successfulBounties.toNumber()).to.equal(0);
+      expect(entry.failedBounties.toNumber()).to.equal(0);
+    }
+  });
+
+  it("Fails on an empty page", async () => {
+    try {
+      await ctx.program.methods
+        .getReputations()
+        .accountsPartial({
+          config: deriveConfigPda(ctx.program.programId)[0],
+        })
+        .rpc();
+      expect.fail("Should have failed - no reputations passed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidBatchSize");
+    }
+  });
+
+  it("Fails on an account that is not a reputation", async () => {
+    try {
+      await ctx.program.methods
+        .getReputations()
+        .accountsPartial({
+          config: deriveConfigPda(ctx.program.programId)[0],
+        })
+        .remainingAccounts([
+          {
+            pubkey: deriveConfigPda(ctx.program.programId)[0],
+            isWritable: false,
+            isSigner: false,
+          },
+        ])
+        .rpc();
+      expect.fail("Should have failed - config is not a reputation");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AccountDiscriminatorMismatch");
+    }
+  });
+});
@@ -8,21 +8,60 @@ import {
     mintTo,
 } from "@solana/spl-token";
 import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { keccak_256 } from "@noble/hashes/sha3";
 
 export const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey(
     "ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"
 );
 
+export const TEST_FEE_BPS = 250;
+export const TEST_FEE_BPS_FOR_TIER = [250, 250, 250, 250]; // no tier discounts
+export const TEST_REFERRAL_BPS = 2_000;
+export const TEST_DISPUTE_WINDOW = 2;
+export const TEST_REPUTATION_HALF_LIFE = 30 * 24 * 60 * 60;
+export const TEST_STAKE_AMOUNT = 10_000_000; // 0.01 SOL
+export const TEST_MIN_REWARD = 0;
+export const TEST_MAX_REWARD = "18446744073709551615"; // u64::MAX, no cap
+export const TEST_REVEAL_DELAY = 0;
+export const TEST_SUBMISSION_TIMEOUT = 2;
+export const TEST_GRACE_PERIOD = 2;
+export const TEST_SUBMISSION_COOLDOWN = 0;
+export const TEST_MIN_ATTESTATION_AGE = 0;
+export const TEST_SCORE_ON_SUBMIT = 0;
+export const TEST_SCORE_ON_SETTLE = 1;
+export const TEST_SUBMISSION_FEE = 0;
+export const TEST_REJECTION_PENALTY = 1;
+export const TEST_REJECTION_PENALTY_STEP = 0; // flat unless a test opts in
+export const TEST_MAX_REJECTION_PENALTY = 1_000;
+export const TEST_FORFEIT_PENALTY = 0;
+export const TEST_MAX_REJECTIONS = 0;
+export const TEST_ENABLE_MEMOS = false;
+export const TEST_MAX_CONCURRENT_SUBMISSIONS = 0;
+export const TEST_MAX_PRICE_AGE = 60;
+export const TEST_MAX_PRICE_CONF_BPS = 100;
+export const TEST_PROMPT_SETTLE_WINDOW = 0;
+export const TEST_PROMPT_SETTLE_BONUS_BPS = 0;
+
+// Pyth price update loaded from tests/fixtures at $150.00 +/- $0.15, published
+// long before the tests run so it only passes with a raised max price age
+export const MOCK_PRICE_FEED = new PublicKey("EfasBXD1TxspV3AJBBpuiouX9CyaCG3ZgzomF4RfXCu9");
+
 export interface TestContext {
     provider: anchor.AnchorProvider;
     program: Program<Bountyforge>;
     connection: anchor.web3.Connection;
     creator: Keypair;
+    admin: Keypair;
     usdcMint: PublicKey;
     creatorTokenAccount: PublicKey;
+    treasuryTokenAccount: PublicKey;
+    tokenProgram: PublicKey;
 }
 
-export async function setupTestContext(): Promise<TestContext> {
+// pass TOKEN_2022_PROGRAM_ID to run against a Token-2022 reward mint
+export async function setupTestContext(
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): Promise<TestContext> {
     const provider = anchor.AnchorProvider.env();
     anchor.setProvider(provider);
 
@@ -30,6 +69,9 @@ export async function setupTestContext(): Promise<TestContext> {
     const connection = provider.connection;
 
     const creator = Keypair.generate();
+    const admin = (provider.wallet as anchor.Wallet).payer;
+
+    await ensureConfig(program, admin);
 
     const airdropSignature = await connection.requestAirdrop(
         creator.publicKey,
@@ -37,19 +79,27 @@ export async function setupTestContext(): Promise<TestContext> {
     );
     await connection.confirmTransaction(airdropSignature);
 
+    // the creator can freeze accounts, to exercise settlement into a frozen account
     const usdcMint = await createMint(
         connection,
         creator,
         creator.publicKey,
-        null,
-        6
+        creator.publicKey,
+        6,
+        undefined,
+        undefined,
+        tokenProgram
     );
 
     const creatorTokenAccountInfo = await getOrCreateAssociatedTokenAccount(
         connection,
         creator,
         usdcMint,
-        creator.publicKey
+        creator.publicKey,
+        false,
+        undefined,
+        undefined,
+        tokenProgram
     );
     const creatorTokenAccount = creatorTokenAccountInfo.address;
 
@@ -60,25 +110,156 @@ export async function setupTestContext(): Promise<TestContext> {
         usdcMint,
         creatorTokenAccount,
         creator,
-        mintAmount
+        mintAmount,
+        [],
+        undefined,
+        tokenProgram
+    );
+
+    const treasuryTokenAccountInfo = await getOrCreateAssociatedTokenAccount(
+        connection,
+        creator,
+        usdcMint,
+        admin.publicKey,
+        false,
+        undefined,
+        undefined,
+        tokenProgram
     );
+    const treasuryTokenAccount = treasuryTokenAccountInfo.address;
 
     return {
         provider,
         program,
         connection,
         creator,
+        admin,
         usdcMint,
         creatorTokenAccount,
+        treasuryTokenAccount,
+        tokenProgram,
     };
 }
 
+export function deriveConfigPda(programId: PublicKey): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("config")],
+        programId
+    );
+}
+
+// The config PDA is a singleton shared by every test file, so it is created
+// once with the provider wallet acting as admin, verifier, arbiter and treasury.
+export async function ensureConfig(
+    program: Program<Bountyforge>,
+    admin: Keypair
+): Promise<void> {
+    const [configPda] = deriveConfigPda(program.programId);
+    const configInfo = await program.provider.connection.getAccountInfo(configPda);
+    if (configInfo !== null) {
+        return;
+    }
+
+    await program.methods
+        .initializeConfig({
+            verifier: admin.publicKey,
+            arbiter: admin.publicKey,
+            treasury: admin.publicKey,
+            feeBps: TEST_FEE_BPS,
+            feeBpsForTier: TEST_FEE_BPS_FOR_TIER,
+            referralBps: TEST_REFERRAL_BPS,
+            disputeWindow: new anchor.BN(TEST_DISPUTE_WINDOW),
+            reputationHalfLife: new anchor.BN(TEST_REPUTATION_HALF_LIFE),
+            stakeAmount: new anchor.BN(TEST_STAKE_AMOUNT),
+            minReward: new anchor.BN(TEST_MIN_REWARD),
+            maxReward: new anchor.BN(TEST_MAX_REWARD),
+            revealDelay: new anchor.BN(TEST_REVEAL_DELAY),
+            submissionTimeout: new anchor.BN(TEST_SUBMISSION_TIMEOUT),
+            gracePeriod: new anchor.BN(TEST_GRACE_PERIOD),
+            submissionCooldown: new anchor.BN(TEST_SUBMISSION_COOLDOWN),
+            minAttestationAge: new anchor.BN(TEST_MIN_ATTESTATION_AGE),
+            scoreOnSubmit: new anchor.BN(TEST_SCORE_ON_SUBMIT),
+            scoreOnSettle: new anchor.BN(TEST_SCORE_ON_SETTLE),
+            submissionFee: new anchor.BN(TEST_SUBMISSION_FEE),
+            rejectionPenalty: new anchor.BN(TEST_REJECTION_PENALTY),
+            rejectionPenaltyStep: new anchor.BN(TEST_REJECTION_PENALTY_STEP),
+            maxRejectionPenalty: new anchor.BN(TEST_MAX_REJECTION_PENALTY),
+            forfeitPenalty: new anchor.BN(TEST_FORFEIT_PENALTY),
+            maxRejections: TEST_MAX_REJECTIONS,
+            enableMemos: TEST_ENABLE_MEMOS,
+            maxConcurrentSubmissions: TEST_MAX_CONCURRENT_SUBMISSIONS,
+            maxPriceAge: new anchor.BN(TEST_MAX_PRICE_AGE),
+            maxPriceConfBps: TEST_MAX_PRICE_CONF_BPS,
+            promptSettleWindow: new anchor.BN(TEST_PROMPT_SETTLE_WINDOW),
+            promptSettleBonusBps: TEST_PROMPT_SETTLE_BONUS_BPS,
+        })
+        .accountsPartial({
+            admin: admin.publicKey,
+            config: configPda,
+            systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+}
+
+// Fields left out keep their current value.
+export async function updateConfig(
+    ctx: TestContext,
+    changes: Record<string, unknown>
+): Promise<void> {
+    await ctx.program.methods
+        .updateConfig({
+            admin: null,
+            verifier: null,
+            arbiter: null,
+            treasury: null,
+            feeBps: null,
+            feeBpsForTier: null,
+            referralBps: null,
+            disputeWindow: null,
+            reputationHalfLife: null,
+            stakeAmount: null,
+            minReward: null,
+            maxReward: null,
+            revealDelay: null,
+            submissionTimeout: null,
+            gracePeriod: null,
+            submissionCooldown: null,
+            minAttestationAge: null,
+            scoreOnSubmit: null,
+            scoreOnSettle: null,
+            submissionFee: null,
+            rejectionPenalty: null,
+            rejectionPenaltyStep: null,
+            maxRejectionPenalty: null,
+            forfeitPenalty: null,
+            maxRejections: null,
+            enableMemos: null,
+            maxConcurrentSubmissions: null,
+            maxPriceAge: null,
+            maxPriceConfBps: null,
+            promptSettleWindow: null,
+            promptSettleBonusBps: null,
+            paused: null,
+            bountyCounter: null,
+            moderators: null,
+            ...changes,
+        })
+        .accountsPartial({
+            admin: ctx.admin.publicKey,
+            config: deriveConfigPda(ctx.program.programId)[0],
+        })
+        .signers([ctx.admin])
+        .rpc();
+}
+
 export function getAssociatedTokenAddressSync(
     mint: PublicKey,
-    owner: PublicKey
+    owner: PublicKey,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): PublicKey {
     const [address] = PublicKey.findProgramAddressSync(
-        [owner.toBuffer(), TOKEN_PROGRAM_ID.toBuffer(), mint.toBuffer()],
+        [owner.toBuffer(), tokenProgram.toBuffer(), mint.toBuffer()],
         ASSOCIATED_TOKEN_PROGRAM_ID
     );
     return address;
@@ -96,6 +277,43 @@ export function deriveBountyPda(
     );
 }
 
+export function deriveVaultPda(
+    programId: PublicKey,
+    bountyId: number
+): [PublicKey, number] {
+    const bountyIdBuffer = Buffer.allocUnsafe(8);
+    bountyIdBuffer.writeBigUInt64LE(BigInt(bountyId), 0);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("vault"), bountyIdBuffer],
+        programId
+    );
+}
+
+// The bounty's escrow: the vault's associated token account for `mint`.
+export function deriveEscrowTokenAccount(
+    programId: PublicKey,
+    bountyId: number,
+    mint: PublicKey,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
+): PublicKey {
+    const [vaultPda] = deriveVaultPda(programId, bountyId);
+    return getAssociatedTokenAddressSync(mint, vaultPda, tokenProgram);
+}
+
+// Escrow token account of an already posted bounty, looked up from its address.
+export async function fetchEscrowTokenAccount(
+    ctx: TestContext,
+    bountyPda: PublicKey
+): Promise<PublicKey> {
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    return deriveEscrowTokenAccount(
+        ctx.program.programId,
+        bounty.id.toNumber(),
+        bounty.rewardMint,
+        ctx.tokenProgram
+    );
+}
+
 export function deriveAttestationPda(
     programId: PublicKey,
     solutionId: number
@@ -108,6 +326,42 @@ export function deriveAttestationPda(
     );
 }
 
+export function deriveCommitmentPda(
+    programId: PublicKey,
+    bountyId: number,
+    agent: PublicKey
+): [PublicKey, number] {
+    const bountyIdBuffer = Buffer.allocUnsafe(8);
+    bountyIdBuffer.writeBigUInt64LE(BigInt(bountyId), 0);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("commitment"), bountyIdBuffer, agent.toBuffer()],
+        programId
+    );
+}
+
+export function deriveContributionPda(
+    programId: PublicKey,
+    bountyId: number,
+    funder: PublicKey
+): [PublicKey, number] {
+    const bountyIdBuffer = Buffer.allocUnsafe(8);
+    bountyIdBuffer.writeBigUInt64LE(BigInt(bountyId), 0);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("contrib"), bountyIdBuffer, funder.toBuffer()],
+        programId
+    );
+}
+
+export function deriveCreatorProfilePda(
+    programId: PublicKey,
+    creator: PublicKey
+): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("creator"), creator.toBuffer()],
+        programId
+    );
+}
+
 export function deriveReputationPda(
     programId: PublicKey,
     agent: PublicKey
@@ -118,12 +372,84 @@ export function deriveReputationPda(
     );
 }
 
+export function deriveAgentProfilePda(
+    programId: PublicKey,
+    agent: PublicKey
+): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("agent"), agent.toBuffer()],
+        programId
+    );
+}
+
+export function deriveBadgeMintPda(
+    programId: PublicKey,
+    agent: PublicKey,
+    level: number
+): [PublicKey, number] {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("badge"), agent.toBuffer(), Buffer.from([level])],
+        programId
+    );
+}
+
+export function deriveStakePda(
+    programId: PublicKey,
+    bountyId: number,
+    agent: PublicKey
+): [PublicKey, number] {
+    const bountyIdBuffer = Buffer.allocUnsafe(8);
+    bountyIdBuffer.writeBigUInt64LE(BigInt(bountyId), 0);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("stake"), bountyIdBuffer, agent.toBuffer()],
+        programId
+    );
+}
+
+export function deriveVestingPda(
+    programId: PublicKey,
+    bountyId: number
+): [PublicKey, number] {
+    const bountyIdBuffer = Buffer.allocUnsafe(8);
+    bountyIdBuffer.writeBigUInt64LE(BigInt(bountyId), 0);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("vesting"), bountyIdBuffer],
+        programId
+    );
+}
+
+export function deriveSubmissionPda(
+    programId: PublicKey,
+    bountyId: number,
+    agent: PublicKey
+): [PublicKey, number] {
+    const bountyIdBuffer = Buffer.allocUnsafe(8);
+    bountyIdBuffer.writeBigUInt64LE(BigInt(bountyId), 0);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("submission"), bountyIdBuffer, agent.toBuffer()],
+        programId
+    );
+}
+
+export function deriveHistoryPda(
+    programId: PublicKey,
+    bountyId: number
+): [PublicKey, number] {
+    const bountyIdBuffer = Buffer.allocUnsafe(8);
+    bountyIdBuffer.writeBigUInt64LE(BigInt(bountyId), 0);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from("history"), bountyIdBuffer],
+        programId
+    );
+}
+
 export async function ensureCreatorBalance(
     connection: anchor.web3.Connection,
     creator: Keypair,
     usdcMint: PublicKey,
     creatorTokenAccount: PublicKey,
-    requiredAmount: number
+    requiredAmount: number,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<void> {
     const balance = await connection.getTokenAccountBalance(creatorTokenAccount);
     if (Number(balance.value.amount) < requiredAmount) {
@@ -134,24 +460,34 @@ export async function ensureCreatorBalance(
             usdcMint,
             creatorTokenAccount,
             creator,
-            mintAmount
+            mintAmount,
+            [],
+            undefined,
+            tokenProgram
         );
     }
 }
 
+// Creates the escrow token account owned by the vault of bounty `bountyId`.
 export async function createBountyTokenAccount(
     connection: anchor.web3.Connection,
     creator: Keypair,
     usdcMint: PublicKey,
-    bountyPda: PublicKey
+    programId: PublicKey,
+    bountyId: number,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<void> {
+    const [vaultPda] = deriveVaultPda(programId, bountyId);
     try {
         await getOrCreateAssociatedTokenAccount(
             connection,
             creator,
             usdcMint,
-            bountyPda,
-            true
+            vaultPda,
+            true,
+            undefined,
+            undefined,
+            tokenProgram
         );
     } catch (e) {
         // Account might already exist
@@ -173,21 +509,46 @@ export async function airdropSol(
 export async function createAgentTokenAccount(
     connection: anchor.web3.Connection,
     agent: Keypair,
-    usdcMint: PublicKey
+    usdcMint: PublicKey,
+    tokenProgram: PublicKey = TOKEN_PROGRAM_ID
 ): Promise<PublicKey> {
     const accountInfo = await getOrCreateAssociatedTokenAccount(
         connection,
         agent,
         usdcMint,
-        agent.publicKey
+        agent.publicKey,
+        false,
+        undefined,
+        undefined,
+        tokenProgram
     );
     return accountInfo.address;
 }
 
+export function defaultDeadline(secondsFromNow: number = 24 * 60 * 60): anchor.BN {
+    return new anchor.BN(Math.floor(Date.now() / 1000) + secondsFromNow);
+}
+
+export function sleep(ms: number): Promise<void> {
+    return new Promise((resolve) => setTimeout(resolve, ms));
+}
+
+export function expectedFee(reward: number): number {
+    return Math.floor((reward * TEST_FEE_BPS) / 10_000);
+}
+
 export function generateRandomId(): number {
     return Math.floor(Math.random() * 1000000);
 }
 
+// Id the program will assign to the next posted bounty.
+export async function nextBountyId(ctx: TestContext): Promise<number> {
+    const config = await ctx.program.account.config.fetch(
+        deriveConfigPda(ctx.program.programId)[0]
+    );
+    return config.bountyCounter.toNumber();
+}
+
 export function generateSolutionHash(): Buffer {
     return Buffer.from(Array.from({ length: 32 }, () => Math.floor(Math.random() * 256)));
 }
@@ -196,34 +557,109 @@ export function generateSolutionHashWithValue(value: number): Buffer {
     return Buffer.from(Array.from({ length: 32 }, () => value));
 }
 
+export const TIER_NOVICE = { novice: {} };
+export const TIER_VERIFIED = { verified: {} };
+
+// any non-zero hash, the criteria document itself never reaches the program
+export const DEFAULT_CRITERIA_HASH = Array(32).fill(0xc1);
+
+export const REWARD_SPL = { spl: {} };
+export const REWARD_SOL = { sol: {} };
+
+export interface PostBountyOptions {
+    title?: string;
+    descriptionUri?: string;
+    tags?: number[];
+    deadline?: anchor.BN;
+    competition?: boolean;
+    minTier?: object;
+    minReputationScore?: number;
+    rewardKind?: object;
+    milestones?: number[];
+    whitelistRoot?: number[];
+    difficulty?: number;
+    settleDelegate?: PublicKey;
+    criteriaHash?: number[];
+    categoryBits?: anchor.BN;
+    maxRewardCap?: number;
+    requiredAttestations?: number;
+    vestingSeconds?: number;
+    encryptionScheme?: number;
+    priceFeed?: PublicKey; // account only, not part of the args
+}
+
+export function postBountyArgs(
+    description: string,
+    reward: number,
+    options: PostBountyOptions = {}
+) {
+    return {
+        description,
+        title: options.title ?? description,
+        descriptionUri: options.descriptionUri ?? "",
+        tags: options.tags ?? [],
+        reward: new anchor.BN(reward),
+        deadline: options.deadline ?? defaultDeadline(),
+        competition: options.competition ?? false,
+        minTier: (options.minTier ?? TIER_NOVICE) as any,
+        minReputationScore: new anchor.BN(options.minReputationScore ?? 0),
+        rewardKind: (options.rewardKind ?? REWARD_SPL) as any,
+        milestones: (options.milestones ?? []).map((amount) => new anchor.BN(amount)),
+        whitelistRoot: options.whitelistRoot ?? Array(32).fill(0),
+        difficulty: options.difficulty ?? 1,
+        settleDelegate: options.settleDelegate ?? null,
+        criteriaHash: options.criteriaHash ?? DEFAULT_CRITERIA_HASH,
+        categoryBits: options.categoryBits ?? new anchor.BN(0),
+        maxRewardCap: new anchor.BN(options.maxRewardCap ?? 0),
+        requiredAttestations: options.requiredAttestations ?? 1,
+        vestingSeconds: new anchor.BN(options.vestingSeconds ?? 0),
+        encryptionScheme: options.encryptionScheme ?? 0,
+    };
+}
+
 export async function postBounty(
     ctx: TestContext,
     bountyId: number,
     description: string,
-    reward: number
+    reward: number,
+    options: PostBountyOptions = {}
 ): Promise<anchor.web3.PublicKey> {
     const [bountyPda] = deriveBountyPda(ctx.program.programId, bountyId);
-    const bountyTokenAccount = getAssociatedTokenAddressSync(
-        ctx.usdcMint,
-        bountyPda
-    );
+    const isSol = options.rewardKind === REWARD_SOL;
 
-    await createBountyTokenAccount(
-        ctx.connection,
-        ctx.creator,
-        ctx.usdcMint,
-        bountyPda
-    );
+    let bountyTokenAccount: PublicKey | null = null;
+    if (!isSol) {
+        bountyTokenAccount = deriveEscrowTokenAccount(
+            ctx.program.programId,
+            bountyId,
+            ctx.usdcMint,
+            ctx.tokenProgram
+        );
+        await createBountyTokenAccount(
+            ctx.connection,
+            ctx.creator,
+            ctx.usdcMint,
+            ctx.program.programId,
+            bountyId,
+            ctx.tokenProgram
+        );
+    }
 
     await ctx.program.methods
-        .postBounty(new anchor.BN(bountyId), description, new anchor.BN(reward))
+        .postBounty(postBountyArgs(description, reward, options))
         .accountsPartial({
             creator: ctx.creator.publicKey,
             bounty: bountyPda,
-            usdcMint: ctx.usdcMint,
-            creatorTokenAccount: ctx.creatorTokenAccount,
+            history: deriveHistoryPda(ctx.program.programId, bountyId)[0],
+            creatorProfile: deriveCreatorProfilePda(
+                ctx.program.programId,
+                ctx.creator.publicKey
+            )[0],
+            rewardMint: isSol ? null : ctx.usdcMint,
+            creatorTokenAccount: isSol ? null : ctx.creatorTokenAccount,
             bountyTokenAccount: bountyTokenAccount,
-            tokenProgram: TOKEN_PROGRAM_ID,
+            priceFeed: options.priceFeed ?? null,
+            tokenProgram: ctx.tokenProgram,
             systemProgram: SystemProgram.programId,
         })
         .signers([ctx.creator])
@@ -235,8 +671,10 @@ export async function postBounty(
 export async function createAttestation(
     ctx: TestContext,
     agent: Keypair,
+    bountyId: number,
     solutionId: number,
-    solutionHash: Buffer
+    solutionHash: Buffer,
+    verify: boolean = true
 ): Promise<anchor.web3.PublicKey> {
     const [attestationPda] = deriveAttestationPda(
         ctx.program.programId,
@@ -244,7 +682,11 @@ export async function createAttestation(
     );
 
     await ctx.program.methods
-        .attestSolution(new anchor.BN(solutionId), Array.from(solutionHash))
+        .attestSolution(
+            new anchor.BN(solutionId),
+            new anchor.BN(bountyId),
+            Array.from(solutionHash)
+        )
         .accountsPartial({
             agent: agent.publicKey,
             attestation: attestationPda,
@@ -253,31 +695,350 @@ export async function createAttestation(
         .signers([agent])
         .rpc();
 
+    if (verify) {
+        await verifyAttestation(ctx, attestationPda);
+    }
+
     return attestationPda;
 }
 
+export async function verifyAttestation(
+    ctx: TestContext,
+    attestationPda: anchor.web3.PublicKey
+): Promise<void> {
+    await ctx.program.methods
+        .verifyAttestation()
+        .accountsPartial({
+            verifier: ctx.admin.publicKey,
+            config: deriveConfigPda(ctx.program.programId)[0],
+            attestation: attestationPda,
+        })
+        .signers([ctx.admin])
+        .rpc();
+}
+
+export function computeCommitment(
+    solutionHash: Buffer,
+    agent: PublicKey,
+    nonce: Buffer
+): Buffer {
+    return Buffer.from(
+        keccak_256(Buffer.concat([solutionHash, agent.toBuffer(), nonce]))
+    );
+}
+
+function hashPair(a: Buffer, b: Buffer): Buffer {
+    const [left, right] = Buffer.compare(a, b) <= 0 ? [a, b] : [b, a];
+    return Buffer.from(keccak_256(Buffer.concat([left, right])));
+}
+
+// Builds a sorted-pair keccak Merkle tree over the agents and returns the
+// root together with the proof of every agent, in input order.
+export function buildWhitelist(agents: PublicKey[]): {
+    root: number[];
+    proofs: number[][][];
+} {
+    let level = agents.map((agent) =>
+        Buffer.from(keccak_256(agent.toBuffer()))
+    );
+    const positions = agents.map((_, i) => i);
+    const proofs: number[][][] = agents.map(() => []);
+
+    while (level.length > 1) {
+        positions.forEach((position, i) => {
+            const sibling = position ^ 1;
+            if (sibling < level.length) {
+                proofs[i].push(Array.from(level[sibling]));
+            }
+            positions[i] = position >> 1;
+        });
+
+        const next: Buffer[] = [];
+        for (let i = 0; i < level.length; i += 2) {
+            next.push(
+                i + 1 < level.length ? hashPair(level[i], level[i + 1]) : level[i]
+            );
+        }
+        level = next;
+    }
+
+    return { root: Array.from(level[0]), proofs };
+}
+
+// Creates the agent's reputation unless it already exists.
+export async function initializeReputation(
+    ctx: TestContext,
+    agent: Keypair,
+    referrer: PublicKey | null = null
+): Promise<PublicKey> {
+    const [reputationPda] = deriveReputationPda(
+        ctx.program.programId,
+        agent.publicKey
+    );
+    if (await ctx.connection.getAccountInfo(reputationPda)) {
+        return reputationPda;
+    }
+
+    await ctx.program.methods
+        .initializeReputation()
+        .accountsPartial({
+            agent: agent.publicKey,
+            reputation: reputationPda,
+            referrerReputation: referrer
+                ? deriveReputationPda(ctx.program.programId, referrer)[0]
+                : null,
+            systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+    return reputationPda;
+}
+
+export async function commitSolution(
+    ctx: TestContext,
+    agent: Keypair,
+    bountyPda: anchor.web3.PublicKey,
+    solutionHash: Buffer,
+    nonce: Buffer
+): Promise<PublicKey> {
+    await initializeReputation(ctx, agent);
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const [commitmentPda] = deriveCommitmentPda(
+        ctx.program.programId,
+        bounty.id.toNumber(),
+        agent.publicKey
+    );
+
+    await ctx.program.methods
+        .commitSolution(
+            Array.from(computeCommitment(solutionHash, agent.publicKey, nonce))
+        )
+        .accountsPartial({
+            agent: agent.publicKey,
+            bounty: bountyPda,
+            commitment: commitmentPda,
+            systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+    return commitmentPda;
+}
+
+// Runs the full commit-reveal flow, relying on the test config's zero reveal delay.
 export async function submitSolution(
     ctx: TestContext,
     agent: Keypair,
     bountyPda: anchor.web3.PublicKey,
     attestationPda: anchor.web3.PublicKey,
-    solutionHash: Buffer
+    solutionHash: Buffer,
+    proof: number[][] = [],
+    referrer: PublicKey | null = null,
+    coAttestations: PublicKey[] = [],
+    minPayout = 0,
+    deliveryPubkey: PublicKey = PublicKey.default
 ): Promise<void> {
     const [reputationPda] = deriveReputationPda(
         ctx.program.programId,
         agent.publicKey
     );
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const [submissionPda] = deriveSubmissionPda(
+        ctx.program.programId,
+        bounty.id.toNumber(),
+        agent.publicKey
+    );
+    const [stakePda] = deriveStakePda(
+        ctx.program.programId,
+        bounty.id.toNumber(),
+        agent.publicKey
+    );
+
+    await initializeReputation(ctx, agent, referrer);
+    const nonce = generateSolutionHash();
+    const commitmentPda = await commitSolution(
+        ctx,
+        agent,
+        bountyPda,
+        solutionHash,
+        nonce
+    );
 
     await ctx.program.methods
-        .submitSolution(Array.from(solutionHash))
+        .revealSolution(
+            Array.from(solutionHash),
+            0,
+            0,
+            new anchor.BN(minPayout),
+            deliveryPubkey,
+            Array.from(nonce),
+            proof
+        )
         .accountsPartial({
             agent: agent.publicKey,
             bounty: bountyPda,
             attestation: attestationPda,
+            commitment: commitmentPda,
             reputation: reputationPda,
+            submission: submissionPda,
+            stake: stakePda,
             systemProgram: SystemProgram.programId,
         })
+        .remainingAccounts(
+            coAttestations.map((pubkey) => ({ pubkey, isWritable: false, isSigner: false }))
+        )
         .signers([agent])
         .rpc();
 }
 
+
+export async function settleBounty(
+    ctx: TestContext,
+    bountyPda: anchor.web3.PublicKey,
+    agent: PublicKey,
+    signer: Keypair = ctx.creator
+): Promise<void> {
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const [submissionPda] = deriveSubmissionPda(
+        ctx.program.programId,
+        bounty.id.toNumber(),
+        agent
+    );
+
+    await ctx.program.methods
+        .settleBounty()
+        .accountsPartial({
+            creator: signer.publicKey,
+            config: deriveConfigPda(ctx.program.programId)[0],
+            bounty: bountyPda,
+            history: deriveHistoryPda(ctx.program.programId, bounty.id.toNumber())[0],
+            submission: submissionPda,
+            agent: agent,
+        })
+        .signers([signer])
+        .rpc();
+}
+
+export async function finalizeSettlement(
+    ctx: TestContext,
+    bountyPda: anchor.web3.PublicKey,
+    agent: PublicKey,
+    agentTokenAccount: PublicKey | null
+): Promise<string> {
+    const [reputationPda] = deriveReputationPda(ctx.program.programId, agent);
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const [stakePda] = deriveStakePda(
+        ctx.program.programId,
+        bounty.id.toNumber(),
+        agent
+    );
+    const { referrer } = await ctx.program.account.reputation.fetch(reputationPda);
+    // SOL bounties hold lamports and take no token accounts, the agent's own
+    // token account is only paid on claimReward
+    const isSol = agentTokenAccount === null;
+    // a vesting payout opens its schedule, paid for by the creator
+    const vests = bounty.vestingSeconds.toNumber() > 0;
+
+    return ctx.program.methods
+        .finalizeSettlement()
+        .accountsPartial({
+            config: deriveConfigPda(ctx.program.programId)[0],
+            bounty: bountyPda,
+            history: deriveHistoryPda(ctx.program.programId, bounty.id.toNumber())[0],
+            creatorProfile: deriveCreatorProfilePda(
+                ctx.program.programId,
+                bounty.creator
+            )[0],
+            reputation: reputationPda,
+            referrerReputation: referrer
+                ? deriveReputationPda(ctx.program.programId, referrer)[0]
+                : null,
+            agent: agent,
+            stake: stakePda,
+            bountyTokenAccount: isSol
+                ? null
+                : deriveEscrowTokenAccount(
+                      ctx.program.programId,
+                      bounty.id.toNumber(),
+                      ctx.usdcMint,
+                      ctx.tokenProgram
+                  ),
+            treasuryTokenAccount: isSol ? null : ctx.treasuryTokenAccount,
+            treasury: ctx.admin.publicKey,
+            rewardMint: isSol ? null : ctx.usdcMint,
+            payer: vests ? ctx.creator.publicKey : null,
+            vesting: vests
+                ? deriveVestingPda(ctx.program.programId, bounty.id.toNumber())[0]
+                : null,
+            tokenProgram: ctx.tokenProgram,
+            systemProgram: vests ? SystemProgram.programId : null,
+        })
+        .signers(vests ? [ctx.creator] : [])
+        .rpc();
+}
+
+export async function releaseMilestone(
+    ctx: TestContext,
+    bountyPda: anchor.web3.PublicKey,
+    agent: PublicKey,
+    agentTokenAccount: PublicKey,
+    index: number
+): Promise<void> {
+    const [reputationPda] = deriveReputationPda(ctx.program.programId, agent);
+
+    await ctx.program.methods
+        .releaseMilestone(index)
+        .accountsPartial({
+            creator: ctx.creator.publicKey,
+            config: deriveConfigPda(ctx.program.programId)[0],
+            bounty: bountyPda,
+            reputation: reputationPda,
+            referrerReputation: null,
+            agent: agent,
+            agentTokenAccount: agentTokenAccount,
+            bountyTokenAccount: await fetchEscrowTokenAccount(ctx, bountyPda),
+            treasuryTokenAccount: ctx.treasuryTokenAccount,
+            treasury: null,
+            tokenProgram: ctx.tokenProgram,
+        })
+        .signers([ctx.creator])
+        .rpc();
+}
+
+export async function claimReward(
+    ctx: TestContext,
+    bountyPda: anchor.web3.PublicKey,
+    agent: Keypair,
+    recipientTokenAccount: PublicKey | null
+): Promise<string> {
+    // SOL bounties pay the agent's wallet
+    const isSol = recipientTokenAccount === null;
+
+    return ctx.program.methods
+        .claimReward()
+        .accountsPartial({
+            agent: agent.publicKey,
+            config: deriveConfigPda(ctx.program.programId)[0],
+            bounty: bountyPda,
+            reputation: deriveReputationPda(ctx.program.programId, agent.publicKey)[0],
+            recipient: isSol ? agent.publicKey : null,
+            recipientTokenAccount: recipientTokenAccount,
+            bountyTokenAccount: isSol ? null : await fetchEscrowTokenAccount(ctx, bountyPda),
+            tokenProgram: ctx.tokenProgram,
+        })
+        .signers([agent])
+        .rpc();
+}
+
+export async function settleAndFinalize(
+    ctx: TestContext,
+    bountyPda: anchor.web3.PublicKey,
+    agent: Keypair,
+    agentTokenAccount: PublicKey | null
+): Promise<void> {
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+    await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+    await finalizeSettlement(ctx, bountyPda, agent.publicKey, agentTokenAccount);
+    await claimReward(ctx, bountyPda, agent, agentTokenAccount);
+}
@@ -0,0 +1,161 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("increase_reward", () => {
+  let ctx: TestContext;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let bountyTokenAccount: anchor.web3.PublicKey;
+  const reward = 50 * 10 ** 6;
+  const topUp = 25 * 10 ** 6;
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      200 * 10 ** 6
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Bounty to top up", reward);
+    bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
+  });
+
+  function increaseReward(amount: number) {
+    return ctx.program.methods
+      .increaseReward(new anchor.BN(amount))
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: bountyTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.creator]);
+  }
+
+  it("Adds to the reward and keeps the escrow in sync", async () => {
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("rewardIncreased", (e) => resolve(e));
+    });
+
+    await increaseReward(topUp).rpc();
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.reward.toNumber()).to.equal(reward + topUp);
+
+    const bountyBalance = await ctx.connection.getTokenAccountBalance(
+      bountyTokenAccount
+    );
+    expect(bountyBalance.value.amount).to.equal((reward + topUp).toString());
+
+    expect(emitted.bountyId.toNumber()).to.equal(bountyId);
+    expect(emitted.amount.toNumber()).to.equal(topUp);
+    expect(emitted.reward.toNumber()).to.equal(reward + topUp);
+  });
+
+  it("Tops up to the bounty's reward cap but not past it", async () => {
+    bountyPda = await postBounty(ctx, await nextBountyId(ctx), "Capped bounty", reward, {
+      maxRewardCap: reward + topUp,
+    });
+    bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
+
+    await increaseReward(topUp).rpc();
+
+    try {
+      await increaseReward(1).rpc();
+
+      expect.fail("Should have failed - above the reward cap");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("RewardAboveMaximum");
+    }
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.reward.toNumber()).to.equal(reward + topUp);
+  });
+
+  it("Fails to post a bounty whose reward already exceeds its cap", async () => {
+    try {
+      await postBounty(ctx, await nextBountyId(ctx), "Overfunded bounty", reward, {
+        maxRewardCap: reward - 1,
+      });
+
+      expect.fail("Should have failed - above the reward cap");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("RewardAboveMaximum");
+    }
+  });
+
+  it("Fails when caller is not the bounty creator", async () => {
+    const wrongCreator = Keypair.generate();
+    await airdropSol(ctx.connection, wrongCreator.publicKey);
+
+    try {
+      await ctx.program.methods
+        .increaseReward(new anchor.BN(topUp))
+        .accountsPartial({
+          creator: wrongCreator.publicKey,
+          bounty: bountyPda,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: bountyTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([wrongCreator])
+        .rpc();
+
+      expect.fail("Should have failed - unauthorized creator");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails once the bounty is no longer open", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const solutionHash = generateSolutionHashWithValue(0x7e);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    try {
+      await increaseReward(topUp).rpc();
+
+      expect.fail("Should have failed - bounty not open");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotOpen");
+    }
+  });
+});
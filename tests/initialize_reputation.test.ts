@@ -0,0 +1,140 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveCommitmentPda,
+  deriveReputationPda,
+  deriveStakePda,
+  deriveSubmissionPda,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  computeCommitment,
+  initializeReputation,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHash,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("initialize_reputation", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let reputationPda: anchor.web3.PublicKey;
+
+  function initialize(signer: Keypair) {
+    return ctx.program.methods
+      .initializeReputation()
+      .accountsPartial({
+        agent: signer.publicKey,
+        reputation: deriveReputationPda(ctx.program.programId, signer.publicKey)[0],
+        referrerReputation: null,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    [reputationPda] = deriveReputationPda(ctx.program.programId, agent.publicKey);
+  });
+
+  it("Creates a reputation with clean defaults", async () => {
+    await initialize(agent);
+
+    const reputation = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputation.agent.toString()).to.equal(agent.publicKey.toString());
+    expect(reputation.score.toNumber()).to.equal(0);
+    expect(reputation.successfulBounties.toNumber()).to.equal(0);
+    expect(reputation.failedBounties.toNumber()).to.equal(0);
+    expect(reputation.referrer).to.be.null;
+  });
+
+  it("Fails to initialize the same reputation twice", async () => {
+    await initialize(agent);
+
+    try {
+      await initialize(agent);
+
+      expect.fail("Should have failed - already initialized");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Records the referrer given at initialization", async () => {
+    const referrer = Keypair.generate();
+    await airdropSol(ctx.connection, referrer.publicKey);
+    await initializeReputation(ctx, referrer);
+
+    await initializeReputation(ctx, agent, referrer.publicKey);
+
+    const reputation = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputation.referrer.toString()).to.equal(referrer.publicKey.toString());
+  });
+
+  it("Fails to reveal before the reputation is initialized", async () => {
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Uninitialized agent", 10 * 10 ** 6);
+    const solutionHash = generateSolutionHashWithValue(0x72);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    const nonce = generateSolutionHash();
+    const commitmentPda = deriveCommitmentPda(
+      ctx.program.programId,
+      bountyId,
+      agent.publicKey
+    )[0];
+
+    await ctx.program.methods
+      .commitSolution(
+        Array.from(computeCommitment(solutionHash, agent.publicKey, nonce))
+      )
+      .accountsPartial({
+        agent: agent.publicKey,
+        bounty: bountyPda,
+        commitment: commitmentPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent])
+      .rpc();
+
+    try {
+      await ctx.program.methods
+        .revealSolution(Array.from(solutionHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
+        .accountsPartial({
+          agent: agent.publicKey,
+          bounty: bountyPda,
+          attestation: attestationPda,
+          commitment: commitmentPda,
+          reputation: reputationPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            bountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(ctx.program.programId, bountyId, agent.publicKey)[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+      expect.fail("Should have failed - reputation not initialized");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ReputationNotInitialized");
+    }
+  });
+});
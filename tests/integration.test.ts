@@ -1,19 +1,25 @@
 import * as anchor from "@coral-xyz/anchor";
-import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
-import { Keypair, SystemProgram } from "@solana/web3.js";
+import { Keypair } from "@solana/web3.js";
 import { expect } from "chai";
 import {
   setupTestContext,
-  getAssociatedTokenAddressSync,
+  fetchEscrowTokenAccount,
   deriveReputationPda,
   airdropSol,
   createAgentTokenAccount,
   ensureCreatorBalance,
   postBounty,
   createAttestation,
+  verifyAttestation,
   submitSolution,
+  settleBounty,
+  settleAndFinalize,
   generateRandomId,
+  nextBountyId,
+  expectedFee,
   generateSolutionHashWithValue,
+  TEST_SCORE_ON_SETTLE,
+  TEST_SCORE_ON_SUBMIT,
   TestContext,
 } from "./helpers";
 
@@ -36,7 +42,7 @@ describe("integration", () => {
     );
   });
 
-  it("Complete bounty flow: post -> attest -> submit -> settle", async () => {
+  it("Complete bounty flow: post -> attest -> verify -> submit -> settle -> finalize", async () => {
     await ensureCreatorBalance(
       ctx.connection,
       creator,
@@ -45,7 +51,7 @@ describe("integration", () => {
       200 * 10 ** 6
     );
 
-    const bountyId = generateRandomId();
+    const bountyId = await nextBountyId(ctx);
     const reward = 150 * 10 ** 6;
     const description = "Solve this complex problem";
 
@@ -58,10 +64,7 @@ describe("integration", () => {
     expect(bountyAccount.status).to.deep.equal({ open: {} });
     expect(bountyAccount.creator.toString()).to.equal(creator.publicKey.toString());
 
-    const bountyTokenAccount = getAssociatedTokenAddressSync(
-      ctx.usdcMint,
-      bountyPda
-    );
+    const bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
     const bountyBalance = await ctx.connection.getTokenAccountBalance(
       bountyTokenAccount
     );
@@ -73,8 +76,10 @@ describe("integration", () => {
     const attestationPda = await createAttestation(
       ctx,
       agent,
+      bountyId,
       solutionId,
-      solutionHash
+      solutionHash,
+      false
     );
 
     const attestationAccount = await ctx.program.account.attestation.fetch(
@@ -89,6 +94,14 @@ describe("integration", () => {
     );
     expect(attestationAccount.verified).to.be.false;
 
+    await verifyAttestation(ctx, attestationPda);
+
+    const verifiedAttestation = await ctx.program.account.attestation.fetch(
+      attestationPda
+    );
+    expect(verifiedAttestation.verified).to.be.true;
+    expect(verifiedAttestation.verifiedAt.toNumber()).to.be.greaterThan(0);
+
     const [reputationPda] = deriveReputationPda(
       ctx.program.programId,
       agent.publicKey
@@ -110,7 +123,7 @@ describe("integration", () => {
     expect(reputationAfterSubmit.agent.toString()).to.equal(
       agent.publicKey.toString()
     );
-    expect(reputationAfterSubmit.score.toNumber()).to.equal(1);
+    expect(reputationAfterSubmit.score.toNumber()).to.equal(TEST_SCORE_ON_SUBMIT);
     expect(reputationAfterSubmit.successfulBounties.toNumber()).to.equal(0);
     expect(reputationAfterSubmit.totalEarned.toNumber()).to.equal(0);
 
@@ -118,21 +131,7 @@ describe("integration", () => {
       agentTokenAccount
     );
 
-    await ctx.program.methods
-      .settleBounty()
-      .accountsPartial({
-        creator: creator.publicKey,
-        bounty: bountyPda,
-        reputation: reputationPda,
-        agent: agent.publicKey,
-        agentTokenAccount: agentTokenAccount,
-        bountyTokenAccount: bountyTokenAccount,
-        usdcMint: ctx.usdcMint,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([creator])
-      .rpc();
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
 
     const bountyAccountAfterSettle = await ctx.program.account.bounty.fetch(
       bountyPda
@@ -148,15 +147,19 @@ describe("integration", () => {
       agentTokenAccount
     );
     expect(agentBalanceAfter.value.amount).to.equal(
-      (Number(agentBalanceBefore.value.amount) + reward).toString()
+      (Number(agentBalanceBefore.value.amount) + reward - expectedFee(reward)).toString()
     );
 
     const reputationAfterSettle = await ctx.program.account.reputation.fetch(
       reputationPda
     );
     expect(reputationAfterSettle.successfulBounties.toNumber()).to.equal(1);
-    expect(reputationAfterSettle.totalEarned.toNumber()).to.equal(reward);
-    expect(reputationAfterSettle.score.toNumber()).to.equal(1);
+    expect(reputationAfterSettle.totalEarned.toNumber()).to.equal(
+      reward - expectedFee(reward)
+    );
+    expect(reputationAfterSettle.score.toNumber()).to.equal(
+      TEST_SCORE_ON_SUBMIT + TEST_SCORE_ON_SETTLE
+    );
   });
 
   it("Fails when trying to bypass required steps in bounty flow", async () => {
@@ -168,7 +171,7 @@ describe("integration", () => {
       200 * 10 ** 6
     );
 
-    const bountyId = generateRandomId();
+    const bountyId = await nextBountyId(ctx);
     const reward = 100 * 10 ** 6;
     const bountyPda = await postBounty(
       ctx,
@@ -180,10 +183,7 @@ describe("integration", () => {
     const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
     expect(bountyAccount.status).to.deep.equal({ open: {} });
 
-    const bountyTokenAccount = getAssociatedTokenAddressSync(
-      ctx.usdcMint,
-      bountyPda
-    );
+    const bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
 
     const [reputationPda] = deriveReputationPda(
       ctx.program.programId,
@@ -191,21 +191,7 @@ describe("integration", () => {
     );
 
     try {
-      await ctx.program.methods
-        .settleBounty()
-        .accountsPartial({
-          creator: creator.publicKey,
-          bounty: bountyPda,
-          reputation: reputationPda,
-          agent: agent.publicKey,
-          agentTokenAccount: agentTokenAccount,
-          bountyTokenAccount: bountyTokenAccount,
-          usdcMint: ctx.usdcMint,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([creator])
-        .rpc();
+      await settleBounty(ctx, bountyPda, agent.publicKey);
 
       expect.fail("Should have failed - cannot settle bounty that hasn't been submitted");
     } catch (err) {
@@ -219,6 +205,7 @@ describe("integration", () => {
     const attestationPda = await createAttestation(
       ctx,
       agent,
+      bountyId,
       solutionId,
       solutionHash
     );
@@ -245,6 +232,7 @@ describe("integration", () => {
     const attestationPda2 = await createAttestation(
       ctx,
       differentAgent,
+      bountyId,
       solutionId2,
       solutionHash2
     );
@@ -269,21 +257,7 @@ describe("integration", () => {
     await airdropSol(ctx.connection, wrongCreator.publicKey);
 
     try {
-      await ctx.program.methods
-        .settleBounty()
-        .accountsPartial({
-          creator: wrongCreator.publicKey,
-          bounty: bountyPda,
-          reputation: reputationPda,
-          agent: agent.publicKey,
-          agentTokenAccount: agentTokenAccount,
-          bountyTokenAccount: bountyTokenAccount,
-          usdcMint: ctx.usdcMint,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([wrongCreator])
-        .rpc();
+      await settleBounty(ctx, bountyPda, agent.publicKey, wrongCreator);
 
       expect.fail("Should have failed - only creator can settle bounty");
     } catch (err) {
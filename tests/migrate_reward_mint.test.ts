@@ -0,0 +1,179 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  createMint,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+} from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveEscrowTokenAccount,
+  airdropSol,
+  ensureCreatorBalance,
+  createBountyTokenAccount,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("migrate_reward_mint", () => {
+  let ctx: TestContext;
+  let newMint: PublicKey;
+  let newCreatorTokenAccount: PublicKey;
+  let bountyId: number;
+  let bountyPda: PublicKey;
+  const reward = 50 * 10 ** 6;
+  const newReward = 80 * 10 ** 6;
+
+  async function migrateRewardMint(amount: number): Promise<string> {
+    await createBountyTokenAccount(
+      ctx.connection,
+      ctx.creator,
+      newMint,
+      ctx.program.programId,
+      bountyId,
+      ctx.tokenProgram
+    );
+
+    return ctx.program.methods
+      .migrateRewardMint(new anchor.BN(amount))
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: deriveEscrowTokenAccount(
+          ctx.program.programId,
+          bountyId,
+          ctx.usdcMint,
+          ctx.tokenProgram
+        ),
+        newRewardMint: newMint,
+        newCreatorTokenAccount,
+        newBountyTokenAccount: deriveEscrowTokenAccount(
+          ctx.program.programId,
+          bountyId,
+          newMint,
+          ctx.tokenProgram
+        ),
+        tokenProgram: ctx.tokenProgram,
+        newTokenProgram: ctx.tokenProgram,
+      })
+      .signers([ctx.creator])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    newMint = await createMint(
+      ctx.connection,
+      ctx.creator,
+      ctx.creator.publicKey,
+      null,
+      6,
+      undefined,
+      undefined,
+      ctx.tokenProgram
+    );
+    newCreatorTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        ctx.connection,
+        ctx.creator,
+        newMint,
+        ctx.creator.publicKey,
+        false,
+        undefined,
+        undefined,
+        ctx.tokenProgram
+      )
+    ).address;
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+    await mintTo(
+      ctx.connection,
+      ctx.creator,
+      newMint,
+      newCreatorTokenAccount,
+      ctx.creator,
+      newReward,
+      [],
+      undefined,
+      ctx.tokenProgram
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Mint migration bounty", reward, {
+      milestones: [reward / 2, reward / 2],
+    });
+  });
+
+  it("Refunds the old reward and escrows the new one", async () => {
+    const creatorBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+
+    await migrateRewardMint(newReward);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.rewardMint.toString()).to.equal(newMint.toString());
+    expect(bounty.reward.toNumber()).to.equal(newReward);
+    expect(bounty.milestones.map((m) => m.amount.toNumber())).to.deep.equal([
+      newReward / 2,
+      newReward / 2,
+    ]);
+
+    const creatorAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+    expect(
+      Number(creatorAfter.value.amount) - Number(creatorBefore.value.amount)
+    ).to.equal(reward);
+
+    const newEscrow = await ctx.connection.getTokenAccountBalance(
+      deriveEscrowTokenAccount(ctx.program.programId, bountyId, newMint, ctx.tokenProgram)
+    );
+    expect(Number(newEscrow.value.amount)).to.equal(newReward);
+
+    // the old escrow is closed
+    const oldEscrow = await ctx.connection.getAccountInfo(
+      deriveEscrowTokenAccount(ctx.program.programId, bountyId, ctx.usdcMint, ctx.tokenProgram)
+    );
+    expect(oldEscrow).to.be.null;
+  });
+
+  it("Fails once a solution was submitted", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const solutionHash = generateSolutionHashWithValue(0x97);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    try {
+      await migrateRewardMint(newReward);
+      expect.fail("Should have failed - bounty has a submission");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotOpen");
+    }
+  });
+});
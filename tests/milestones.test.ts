@@ -0,0 +1,211 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveReputationPda,
+  deriveStakePda,
+  deriveSubmissionPda,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  finalizeSettlement,
+  releaseMilestone,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  expectedFee,
+  sleep,
+  TEST_DISPUTE_WINDOW,
+  TestContext,
+} from "./helpers";
+
+describe("milestones", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let agentTokenAccount: anchor.web3.PublicKey;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const milestones = [30 * 10 ** 6, 70 * 10 ** 6];
+  const reward = milestones[0] + milestones[1];
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      200 * 10 ** 6
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Staged bounty", reward, {
+      milestones,
+    });
+
+    const solutionHash = generateSolutionHashWithValue(0x4d);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  });
+
+  it("Fails when milestone amounts don't add up to the reward", async () => {
+    try {
+      await postBounty(ctx, await nextBountyId(ctx), "Bad milestones", reward, {
+        milestones: [milestones[0], milestones[1] - 1],
+      });
+
+      expect.fail("Should have failed - milestone sum mismatch");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("MilestoneSumMismatch");
+    }
+  });
+
+  it("Pays each milestone and settles once all are released", async () => {
+    const balanceBefore = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
+
+    await releaseMilestone(ctx, bountyPda, agent.publicKey, agentTokenAccount, 0);
+
+    let bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.milestones[0].released).to.equal(true);
+    expect(bounty.milestones[1].released).to.equal(false);
+
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+    await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+
+    try {
+      await finalizeSettlement(ctx, bountyPda, agent.publicKey, agentTokenAccount);
+
+      expect.fail("Should have failed - milestones outstanding");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("MilestonesOutstanding");
+    }
+
+    await releaseMilestone(ctx, bountyPda, agent.publicKey, agentTokenAccount, 1);
+    await finalizeSettlement(ctx, bountyPda, agent.publicKey, agentTokenAccount);
+
+    bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ settled: {} });
+
+    const netPaid =
+      milestones[0] -
+      expectedFee(milestones[0]) +
+      milestones[1] -
+      expectedFee(milestones[1]);
+    const balanceAfter = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
+    expect(
+      Number(balanceAfter.value.amount) - Number(balanceBefore.value.amount)
+    ).to.equal(netPaid);
+
+    const reputation = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agent.publicKey)[0]
+    );
+    expect(reputation.successfulBounties.toNumber()).to.equal(1);
+    expect(reputation.totalEarned.toNumber()).to.equal(netPaid);
+  });
+
+  it("Fails to release a milestone paying less than the agent's minimum", async () => {
+    // a fresh bounty whose agent asks for at least the first milestone before fees
+    const guardedId = await nextBountyId(ctx);
+    const guardedPda = await postBounty(ctx, guardedId, "Guarded milestones", reward, {
+      milestones,
+    });
+    const solutionHash = generateSolutionHashWithValue(0x4e);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      guardedId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(
+      ctx,
+      agent,
+      guardedPda,
+      attestationPda,
+      solutionHash,
+      [],
+      null,
+      [],
+      milestones[0]
+    );
+
+    try {
+      await releaseMilestone(ctx, guardedPda, agent.publicKey, agentTokenAccount, 0);
+
+      expect.fail("Should have failed - payout below the agent's minimum");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("PayoutBelowMinimum");
+    }
+  });
+
+  it("Fails when releasing the same milestone twice", async () => {
+    await releaseMilestone(ctx, bountyPda, agent.publicKey, agentTokenAccount, 0);
+
+    try {
+      await releaseMilestone(ctx, bountyPda, agent.publicKey, agentTokenAccount, 0);
+
+      expect.fail("Should have failed - milestone already released");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("MilestoneAlreadyReleased");
+    }
+  });
+
+  it("Fails when rejecting after a milestone was released", async () => {
+    await releaseMilestone(ctx, bountyPda, agent.publicKey, agentTokenAccount, 0);
+
+    try {
+      await ctx.program.methods
+        .rejectSolution()
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: bountyPda,
+          reputation: deriveReputationPda(
+            ctx.program.programId,
+            agent.publicKey
+          )[0],
+          agent: agent.publicKey,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            bountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(ctx.program.programId, bountyId, agent.publicKey)[0],
+          treasury: ctx.admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed - milestones partially released");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("MilestonesPartiallyReleased");
+    }
+  });
+});
@@ -0,0 +1,144 @@
+import * as anchor from "@coral-xyz/anchor";
+import { getAccount } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveBadgeMintPda,
+  deriveReputationPda,
+  getAssociatedTokenAddressSync,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  finalizeSettlement,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  sleep,
+  TEST_DISPUTE_WINDOW,
+  TestContext,
+} from "./helpers";
+
+describe("mint_reputation_badge", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let agentTokenAccount: anchor.web3.PublicKey;
+  let reputationPda: anchor.web3.PublicKey;
+  const reward = 1 * 10 ** 6;
+
+  function mintBadge(level: number) {
+    const [badgeMint] = deriveBadgeMintPda(
+      ctx.program.programId,
+      agent.publicKey,
+      level
+    );
+
+    return ctx.program.methods
+      .mintReputationBadge()
+      .accountsPartial({
+        agent: agent.publicKey,
+        reputation: reputationPda,
+        badgeMint,
+        agentBadgeAccount: getAssociatedTokenAddressSync(badgeMint, agent.publicKey),
+      })
+      .signers([agent])
+      .rpc();
+  }
+
+  async function completeBounties(count: number) {
+    const bountyPdas: anchor.web3.PublicKey[] = [];
+    for (let i = 0; i < count; i++) {
+      const bountyId = await nextBountyId(ctx);
+      const solutionHash = generateSolutionHashWithValue(i + 1);
+      const bountyPda = await postBounty(ctx, bountyId, `Badge bounty ${i}`, reward);
+      const attestationPda = await createAttestation(
+        ctx,
+        agent,
+        bountyId,
+        generateRandomId(),
+        solutionHash
+      );
+      await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+      await settleBounty(ctx, bountyPda, agent.publicKey);
+      bountyPdas.push(bountyPda);
+    }
+
+    await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+    for (const bountyPda of bountyPdas) {
+      await finalizeSettlement(ctx, bountyPda, agent.publicKey, agentTokenAccount);
+    }
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey, 5);
+    agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+    [reputationPda] = deriveReputationPda(ctx.program.programId, agent.publicKey);
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      20 * reward
+    );
+  });
+
+  it("Fails before the first threshold is reached", async () => {
+    await completeBounties(1);
+
+    try {
+      await mintBadge(0);
+
+      expect.fail("Should have failed - threshold not reached");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BadgeThresholdNotReached");
+    }
+  });
+
+  it("Mints a frozen badge once the agent has 10 successful bounties", async () => {
+    await completeBounties(9);
+
+    await mintBadge(0);
+
+    const [badgeMint] = deriveBadgeMintPda(ctx.program.programId, agent.publicKey, 0);
+    const badgeAccount = await getAccount(
+      ctx.connection,
+      getAssociatedTokenAddressSync(badgeMint, agent.publicKey)
+    );
+    expect(Number(badgeAccount.amount)).to.equal(1);
+    expect(badgeAccount.isFrozen).to.be.true;
+
+    const reputation = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputation.successfulBounties.toNumber()).to.equal(10);
+    expect(reputation.badgesMinted).to.equal(1);
+  });
+
+  it("Fails to mint the same badge twice", async () => {
+    try {
+      await mintBadge(0);
+
+      expect.fail("Should have failed - badge already minted");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ConstraintSeeds");
+    }
+
+    try {
+      await mintBadge(1);
+
+      expect.fail("Should have failed - next threshold not reached");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BadgeThresholdNotReached");
+    }
+  });
+});
@@ -0,0 +1,121 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveAttestationPda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  updateConfig,
+  TestContext,
+} from "./helpers";
+
+describe("pause", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let attestationPda: anchor.web3.PublicKey;
+  const solutionHash = generateSolutionHashWithValue(0x9a);
+  const reward = 40 * 10 ** 6;
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      100 * 10 ** 6
+    );
+
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Bounty during pause", reward);
+    attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+
+    await updateConfig(ctx, { paused: true });
+  });
+
+  after(async () => {
+    await updateConfig(ctx, { paused: false });
+  });
+
+  it("Blocks submissions while paused", async () => {
+    try {
+      await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+      expect.fail("Should have failed - program paused");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ProgramPaused");
+    }
+  });
+
+  it("Blocks attestations while paused", async () => {
+    const solutionId = generateRandomId();
+
+    try {
+      await ctx.program.methods
+        .attestSolution(
+          new anchor.BN(solutionId),
+          new anchor.BN(bountyId),
+          Array.from(solutionHash)
+        )
+        .accountsPartial({
+          agent: agent.publicKey,
+          attestation: deriveAttestationPda(ctx.program.programId, solutionId)[0],
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+      expect.fail("Should have failed - program paused");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ProgramPaused");
+    }
+  });
+
+  it("Still lets the creator pull funds out", async () => {
+    const bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
+    const creatorBalanceBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+
+    await ctx.program.methods
+      .cancelBounty()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: bountyTokenAccount,
+        rewardMint: ctx.usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const creatorBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+    expect(creatorBalanceAfter.value.amount).to.equal(
+      (Number(creatorBalanceBefore.value.amount) + reward).toString()
+    );
+  });
+});
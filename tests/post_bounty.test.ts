@@ -9,8 +9,18 @@ import { expect } from "chai";
 import {
   setupTestContext,
   deriveBountyPda,
-  getAssociatedTokenAddressSync,
+  deriveEscrowTokenAccount,
   createBountyTokenAccount,
+  defaultDeadline,
+  postBountyArgs,
+  postBounty,
+  nextBountyId,
+  updateConfig,
+  DEFAULT_CRITERIA_HASH,
+  MOCK_PRICE_FEED,
+  TEST_MAX_PRICE_AGE,
+  TEST_MIN_REWARD,
+  TEST_MAX_REWARD,
   TestContext,
 } from "./helpers";
 
@@ -24,12 +34,13 @@ describe("post_bounty", () => {
     ctx = await setupTestContext();
   });
 
-  beforeEach(() => {
-    bountyId = Math.floor(Math.random() * 1000000);
+  beforeEach(async () => {
+    bountyId = await nextBountyId(ctx);
     [bountyPda] = deriveBountyPda(ctx.program.programId, bountyId);
-    bountyTokenAccount = getAssociatedTokenAddressSync(
-      ctx.usdcMint,
-      bountyPda
+    bountyTokenAccount = deriveEscrowTokenAccount(
+      ctx.program.programId,
+      bountyId,
+      ctx.usdcMint
     );
   });
 
@@ -45,19 +56,16 @@ describe("post_bounty", () => {
       ctx.connection,
       ctx.creator,
       ctx.usdcMint,
-      bountyPda
+      ctx.program.programId,
+      bountyId
     );
 
     await ctx.program.methods
-      .postBounty(
-        new anchor.BN(bountyId),
-        description,
-        new anchor.BN(reward)
-      )
+      .postBounty(postBountyArgs(description, reward))
       .accountsPartial({
         creator: ctx.creator.publicKey,
         bounty: bountyPda,
-        usdcMint: ctx.usdcMint,
+        rewardMint: ctx.usdcMint,
         creatorTokenAccount: ctx.creatorTokenAccount,
         bountyTokenAccount: bountyTokenAccount,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -75,6 +83,16 @@ describe("post_bounty", () => {
       ctx.creator.publicKey.toString()
     );
     expect(bountyAccount.solutionHash).to.be.null;
+    expect(bountyAccount.rewardMint.toString()).to.equal(
+      ctx.usdcMint.toString()
+    );
+    expect(bountyAccount.rewardDecimals).to.equal(6);
+    expect(bountyAccount.criteriaHash).to.deep.equal(DEFAULT_CRITERIA_HASH);
+    expect(bountyAccount.minTier).to.deep.equal({ novice: {} });
+    expect(bountyAccount.title).to.equal(description);
+    expect(bountyAccount.descriptionUri).to.equal("");
+    expect(bountyAccount.tags).to.deep.equal([]);
+    expect(bountyAccount.minReputationScore.toNumber()).to.equal(0);
 
     const creatorBalanceAfter = await ctx.connection.getTokenAccountBalance(
       ctx.creatorTokenAccount
@@ -89,20 +107,70 @@ describe("post_bounty", () => {
     expect(bountyBalance.value.amount).to.equal(reward.toString());
   });
 
+  it("Emits a BountyCreated event", async () => {
+    const reward = 10 * 10 ** 6;
+
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("bountyCreated", (e) => resolve(e));
+    });
+
+    await postBounty(ctx, bountyId, "Evented bounty", reward);
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+
+    expect(emitted.bountyId.toNumber()).to.equal(bountyId);
+    expect(emitted.creator.toString()).to.equal(
+      ctx.creator.publicKey.toString()
+    );
+    expect(emitted.reward.toNumber()).to.equal(reward);
+    expect(emitted.categoryBits.toNumber()).to.equal(0);
+  });
+
+  it("Stores and emits several category bits", async () => {
+    // categories 0, 3 and 63
+    const categoryBits = new anchor.BN(1)
+      .shln(63)
+      .or(new anchor.BN(0b1001));
+
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("bountyCreated", (e) => resolve(e));
+    });
+
+    const categorisedBountyPda = await postBounty(
+      ctx,
+      await nextBountyId(ctx),
+      "Categorised bounty",
+      10 * 10 ** 6,
+      { categoryBits }
+    );
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+    expect(emitted.categoryBits.toString()).to.equal(categoryBits.toString());
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(
+      categorisedBountyPda
+    );
+    expect(bountyAccount.categoryBits.toString()).to.equal(categoryBits.toString());
+    for (const bit of [0, 3, 63]) {
+      expect(bountyAccount.categoryBits.testn(bit)).to.be.true;
+    }
+    expect(bountyAccount.categoryBits.testn(1)).to.be.false;
+  });
+
   it("Fails when creator has insufficient USDC", async () => {
     const reward = 10000 * 10 ** 6;
 
     try {
       await ctx.program.methods
-        .postBounty(
-          new anchor.BN(bountyId),
-          "Expensive bounty",
-          new anchor.BN(reward)
-        )
+        .postBounty(postBountyArgs("Expensive bounty", reward))
         .accountsPartial({
           creator: ctx.creator.publicKey,
           bounty: bountyPda,
-          usdcMint: ctx.usdcMint,
+          rewardMint: ctx.usdcMint,
           creatorTokenAccount: ctx.creatorTokenAccount,
           bountyTokenAccount: bountyTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -135,15 +203,11 @@ describe("post_bounty", () => {
 
     try {
       await ctx.program.methods
-        .postBounty(
-          new anchor.BN(bountyId),
-          "Test bounty",
-          new anchor.BN(100 * 10 ** 6)
-        )
+        .postBounty(postBountyArgs("Test bounty", 100 * 10 ** 6))
         .accountsPartial({
           creator: ctx.creator.publicKey,
           bounty: bountyPda,
-          usdcMint: ctx.usdcMint,
+          rewardMint: ctx.usdcMint,
           creatorTokenAccount: wrongTokenAccount.address,
           bountyTokenAccount: bountyTokenAccount,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -158,6 +222,217 @@ describe("post_bounty", () => {
     }
   });
 
+  it("Fails when deadline is in the past", async () => {
+    await createBountyTokenAccount(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.program.programId,
+      bountyId
+    );
+
+    try {
+      await ctx.program.methods
+        .postBounty(
+          postBountyArgs("Late bounty", 100 * 10 ** 6, {
+            deadline: defaultDeadline(-60),
+          })
+        )
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: bountyPda,
+          rewardMint: ctx.usdcMint,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: bountyTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed with invalid deadline");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails when the acceptance criteria hash is all zeros", async () => {
+    await createBountyTokenAccount(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.program.programId,
+      bountyId
+    );
+
+    try {
+      await ctx.program.methods
+        .postBounty(
+          postBountyArgs("Unanchored bounty", 100 * 10 ** 6, {
+            criteriaHash: Array(32).fill(0),
+          })
+        )
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: bountyPda,
+          rewardMint: ctx.usdcMint,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: bountyTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed with a zero criteria hash");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidCriteriaHash");
+    }
+  });
+
+  it("Stores title, description URI and tags", async () => {
+    const metadataBountyId = await nextBountyId(ctx);
+    const metadataBountyPda = await postBounty(
+      ctx,
+      metadataBountyId,
+      "Metadata bounty",
+      10 * 10 ** 6,
+      {
+        title: "Index Jupiter swaps",
+        descriptionUri: "https://example.com/bounties/jupiter.md",
+        tags: [1, 7, 42],
+      }
+    );
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(
+      metadataBountyPda
+    );
+    expect(bountyAccount.title).to.equal("Index Jupiter swaps");
+    expect(bountyAccount.descriptionUri).to.equal(
+      "https://example.com/bounties/jupiter.md"
+    );
+    expect(bountyAccount.tags).to.deep.equal([1, 7, 42]);
+  });
+
+  it("Fails when the title is too long", async () => {
+    await createBountyTokenAccount(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.program.programId,
+      bountyId
+    );
+
+    try {
+      await ctx.program.methods
+        .postBounty(
+          postBountyArgs("Long title bounty", 100 * 10 ** 6, {
+            title: "x".repeat(65),
+          })
+        )
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: bountyPda,
+          rewardMint: ctx.usdcMint,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: bountyTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed with metadata too long");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("MetadataTooLong");
+    }
+  });
+
+  it("Fails when the difficulty is out of range", async () => {
+    await createBountyTokenAccount(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.program.programId,
+      bountyId
+    );
+
+    for (const difficulty of [0, 6]) {
+      try {
+        await ctx.program.methods
+          .postBounty(postBountyArgs("Unrated bounty", 100 * 10 ** 6, { difficulty }))
+          .accountsPartial({
+            creator: ctx.creator.publicKey,
+            bounty: bountyPda,
+            rewardMint: ctx.usdcMint,
+            creatorTokenAccount: ctx.creatorTokenAccount,
+            bountyTokenAccount: bountyTokenAccount,
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([ctx.creator])
+          .rpc();
+
+        expect.fail(`Should have failed with difficulty ${difficulty}`);
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("DifficultyOutOfRange");
+      }
+    }
+  });
+
+  it("Enforces the configured reward bounds", async () => {
+    await createBountyTokenAccount(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.program.programId,
+      bountyId
+    );
+
+    const attempt = (reward: number) =>
+      ctx.program.methods
+        .postBounty(postBountyArgs("Bounded bounty", reward))
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: bountyPda,
+          rewardMint: ctx.usdcMint,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: bountyTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+    await updateConfig(ctx, {
+      minReward: new anchor.BN(10 * 10 ** 6),
+      maxReward: new anchor.BN(20 * 10 ** 6),
+    });
+
+    try {
+      try {
+        await attempt(5 * 10 ** 6);
+
+        expect.fail("Should have failed - reward below minimum");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("RewardBelowMinimum");
+      }
+
+      try {
+        await attempt(25 * 10 ** 6);
+
+        expect.fail("Should have failed - reward above maximum");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("RewardAboveMaximum");
+      }
+    } finally {
+      await updateConfig(ctx, {
+        minReward: new anchor.BN(TEST_MIN_REWARD),
+        maxReward: new anchor.BN(TEST_MAX_REWARD),
+      });
+    }
+  });
+
   it("Fails when bounty token account owner is wrong", async () => {
     const wrongBountyTokenAccount = await getOrCreateAssociatedTokenAccount(
       ctx.connection,
@@ -168,15 +443,11 @@ describe("post_bounty", () => {
 
     try {
       await ctx.program.methods
-        .postBounty(
-          new anchor.BN(bountyId),
-          "Test bounty",
-          new anchor.BN(100 * 10 ** 6)
-        )
+        .postBounty(postBountyArgs("Test bounty", 100 * 10 ** 6))
         .accountsPartial({
           creator: ctx.creator.publicKey,
           bounty: bountyPda,
-          usdcMint: ctx.usdcMint,
+          rewardMint: ctx.usdcMint,
           creatorTokenAccount: ctx.creatorTokenAccount,
           bountyTokenAccount: wrongBountyTokenAccount.address,
           tokenProgram: TOKEN_PROGRAM_ID,
@@ -192,41 +463,45 @@ describe("post_bounty", () => {
   });
 
   it("Can post multiple bounties", async () => {
-    const bountyId1 = 1;
-    const bountyId2 = 2;
+    const bountyId1 = await nextBountyId(ctx);
+    const bountyId2 = bountyId1 + 1;
     const reward = 50 * 10 ** 6;
 
     const [bountyPda1] = deriveBountyPda(ctx.program.programId, bountyId1);
     const [bountyPda2] = deriveBountyPda(ctx.program.programId, bountyId2);
 
-    const bountyTokenAccount1 = getAssociatedTokenAddressSync(
-      ctx.usdcMint,
-      bountyPda1
+    const bountyTokenAccount1 = deriveEscrowTokenAccount(
+      ctx.program.programId,
+      bountyId1,
+      ctx.usdcMint
     );
-    const bountyTokenAccount2 = getAssociatedTokenAddressSync(
-      ctx.usdcMint,
-      bountyPda2
+    const bountyTokenAccount2 = deriveEscrowTokenAccount(
+      ctx.program.programId,
+      bountyId2,
+      ctx.usdcMint
     );
 
     await createBountyTokenAccount(
       ctx.connection,
       ctx.creator,
       ctx.usdcMint,
-      bountyPda1
+      ctx.program.programId,
+      bountyId1
     );
     await createBountyTokenAccount(
       ctx.connection,
       ctx.creator,
       ctx.usdcMint,
-      bountyPda2
+      ctx.program.programId,
+      bountyId2
     );
 
     await ctx.program.methods
-      .postBounty(new anchor.BN(bountyId1), "Bounty 1", new anchor.BN(reward))
+      .postBounty(postBountyArgs("Bounty 1", reward))
       .accountsPartial({
         creator: ctx.creator.publicKey,
         bounty: bountyPda1,
-        usdcMint: ctx.usdcMint,
+        rewardMint: ctx.usdcMint,
         creatorTokenAccount: ctx.creatorTokenAccount,
         bountyTokenAccount: bountyTokenAccount1,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -236,11 +511,11 @@ describe("post_bounty", () => {
       .rpc();
 
     await ctx.program.methods
-      .postBounty(new anchor.BN(bountyId2), "Bounty 2", new anchor.BN(reward))
+      .postBounty(postBountyArgs("Bounty 2", reward))
       .accountsPartial({
         creator: ctx.creator.publicKey,
         bounty: bountyPda2,
-        usdcMint: ctx.usdcMint,
+        rewardMint: ctx.usdcMint,
         creatorTokenAccount: ctx.creatorTokenAccount,
         bountyTokenAccount: bountyTokenAccount2,
         tokenProgram: TOKEN_PROGRAM_ID,
@@ -254,8 +529,46 @@ describe("post_bounty", () => {
 
     expect(bounty1.id.toNumber()).to.equal(bountyId1);
     expect(bounty2.id.toNumber()).to.equal(bountyId2);
+    expect(await nextBountyId(ctx)).to.equal(bountyId2 + 1);
     expect(bounty1.reward.toNumber()).to.equal(reward);
     expect(bounty2.reward.toNumber()).to.equal(reward);
   });
-});
 
+  it("Prices the reward in USD from a price feed", async () => {
+    const reward = 100 * 10 ** 6;
+
+    // the fixture price is old, so the age limit is lifted for this post
+    await updateConfig(ctx, { maxPriceAge: new anchor.BN(1_000_000_000_000) });
+    try {
+      bountyPda = await postBounty(ctx, bountyId, "Priced bounty", reward, {
+        priceFeed: MOCK_PRICE_FEED,
+      });
+    } finally {
+      await updateConfig(ctx, { maxPriceAge: new anchor.BN(TEST_MAX_PRICE_AGE) });
+    }
+
+    // 100 tokens at $150.00, in millionths of a dollar
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.rewardUsdAtCreation.toNumber()).to.equal(15_000 * 10 ** 6);
+    expect(bounty.priceFeedId).to.not.deep.equal(Array(32).fill(0));
+  });
+
+  it("Leaves the USD value empty without a price feed", async () => {
+    bountyPda = await postBounty(ctx, bountyId, "Unpriced bounty", 100 * 10 ** 6);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.rewardUsdAtCreation.toNumber()).to.equal(0);
+    expect(bounty.priceFeedId).to.deep.equal(Array(32).fill(0));
+  });
+
+  it("Fails with a stale price feed", async () => {
+    try {
+      await postBounty(ctx, bountyId, "Stale priced bounty", 100 * 10 ** 6, {
+        priceFeed: MOCK_PRICE_FEED,
+      });
+      expect.fail("Should have failed - stale price feed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("StalePriceFeed");
+    }
+  });
+});
@@ -0,0 +1,114 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveSubmissionPda,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("private_delivery", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const reward = 10 * 10 ** 6;
+  const deliveryKey = Keypair.generate().publicKey;
+
+  async function submit(value: number, deliveryPubkey: PublicKey): Promise<void> {
+    const solutionHash = generateSolutionHashWithValue(value);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(
+      ctx,
+      agent,
+      bountyPda,
+      attestationPda,
+      solutionHash,
+      [],
+      null,
+      [],
+      0,
+      deliveryPubkey
+    );
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Private delivery bounty", reward, {
+      encryptionScheme: 1,
+    });
+  });
+
+  it("Records the agent's delivery pubkey through settlement", async () => {
+    await submit(0x99, deliveryKey);
+
+    const submission = await ctx.program.account.submission.fetch(
+      deriveSubmissionPda(ctx.program.programId, bountyId, agent.publicKey)[0]
+    );
+    expect(submission.deliveryPubkey.toString()).to.equal(deliveryKey.toString());
+
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.encryptionScheme).to.equal(1);
+    expect(bounty.deliveryPubkey.toString()).to.equal(deliveryKey.toString());
+  });
+
+  it("Fails to reveal to a private bounty without a delivery pubkey", async () => {
+    try {
+      await submit(0x9a, PublicKey.default);
+      expect.fail("Should have failed - delivery pubkey is zero");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("MissingDeliveryPubkey");
+    }
+  });
+
+  it("Fails to post a bounty with an unknown encryption scheme", async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+
+    try {
+      await postBounty(ctx, await nextBountyId(ctx), "Unknown scheme", reward, {
+        encryptionScheme: 3,
+      });
+      expect.fail("Should have failed - unsupported encryption scheme");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidEncryptionScheme");
+    }
+  });
+});
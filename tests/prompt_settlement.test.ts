@@ -0,0 +1,150 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  settleAndFinalize,
+  updateConfig,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  expectedFee,
+  sleep,
+  TEST_PROMPT_SETTLE_WINDOW,
+  TEST_PROMPT_SETTLE_BONUS_BPS,
+  TestContext,
+} from "./helpers";
+
+describe("prompt_settlement", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let agentTokenAccount: anchor.web3.PublicKey;
+  let bountyPda: anchor.web3.PublicKey;
+  const reward = 100 * 10 ** 6;
+  const bonusBps = 5_000;
+
+  function promptBonus(amount: number): number {
+    return Math.floor((expectedFee(amount) * bonusBps) / 10_000);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+    await updateConfig(ctx, {
+      promptSettleWindow: new anchor.BN(60),
+      promptSettleBonusBps: bonusBps,
+    });
+  });
+
+  after(async () => {
+    await updateConfig(ctx, {
+      promptSettleWindow: new anchor.BN(TEST_PROMPT_SETTLE_WINDOW),
+      promptSettleBonusBps: TEST_PROMPT_SETTLE_BONUS_BPS,
+    });
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+
+    const bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Prompt settlement bounty", reward);
+
+    const solutionHash = generateSolutionHashWithValue(0x92);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  });
+
+  it("Records when the solution was submitted", async () => {
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.submittedAt.toNumber()).to.be.closeTo(Date.now() / 1000, 30);
+    expect(bounty.settledPromptly).to.equal(false);
+  });
+
+  it("Pays the agent part of the fee when settled inside the window", async () => {
+    const treasuryBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.treasuryTokenAccount
+    );
+
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.settledPromptly).to.equal(true);
+
+    const bonus = promptBonus(reward);
+    expect(bonus).to.be.greaterThan(0);
+    const agentBalance = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
+    expect(agentBalance.value.amount).to.equal(
+      (reward - expectedFee(reward) + bonus).toString()
+    );
+    const treasuryAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.treasuryTokenAccount
+    );
+    expect(
+      Number(treasuryAfter.value.amount) - Number(treasuryBefore.value.amount)
+    ).to.equal(expectedFee(reward) - bonus);
+
+    const escrow = await ctx.connection.getTokenAccountBalance(
+      await fetchEscrowTokenAccount(ctx, bountyPda)
+    );
+    expect(escrow.value.amount).to.equal("0");
+  });
+
+  it("Pays no bonus once the window has passed", async () => {
+    await updateConfig(ctx, { promptSettleWindow: new anchor.BN(1) });
+    try {
+      // the window is inclusive, so wait until it is clearly over
+      await sleep(3_000);
+      await settleBounty(ctx, bountyPda, agent.publicKey);
+    } finally {
+      await updateConfig(ctx, { promptSettleWindow: new anchor.BN(60) });
+    }
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.settledPromptly).to.equal(false);
+  });
+
+  it("Pays no bonus with the window turned off", async () => {
+    await updateConfig(ctx, { promptSettleWindow: new anchor.BN(0) });
+    try {
+      await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+    } finally {
+      await updateConfig(ctx, { promptSettleWindow: new anchor.BN(60) });
+    }
+
+    const agentBalance = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
+    expect(agentBalance.value.amount).to.equal(
+      (reward - expectedFee(reward)).toString()
+    );
+  });
+});
@@ -0,0 +1,251 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  fetchEscrowTokenAccount,
+  deriveReputationPda,
+  deriveStakePda,
+  deriveSubmissionPda,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  defaultDeadline,
+  sleep,
+  TestContext,
+} from "./helpers";
+
+describe("reclaim_expired", () => {
+  let ctx: TestContext;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let bountyTokenAccount: anchor.web3.PublicKey;
+  const reward = 100 * 10 ** 6;
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      200 * 10 ** 6
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(
+      ctx,
+      bountyId,
+      "Short-lived bounty",
+      reward,
+      { deadline: defaultDeadline(2) }
+    );
+    bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
+  });
+
+  it("Refunds the creator once the deadline has passed", async () => {
+    await sleep(4000);
+
+    const creatorBalanceBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+
+    await ctx.program.methods
+      .reclaimExpired()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: bountyTokenAccount,
+        rewardMint: ctx.usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ expired: {} });
+
+    const creatorBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+    const bountyBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      bountyTokenAccount
+    );
+
+    expect(bountyBalanceAfter.value.amount).to.equal("0");
+    expect(creatorBalanceAfter.value.amount).to.equal(
+      (Number(creatorBalanceBefore.value.amount) + reward).toString()
+    );
+  });
+
+  it("Fails when the deadline has not passed", async () => {
+    const openBountyId = await nextBountyId(ctx);
+    const openBountyPda = await postBounty(
+      ctx,
+      openBountyId,
+      "Long-lived bounty",
+      reward
+    );
+
+    try {
+      await ctx.program.methods
+        .reclaimExpired()
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: openBountyPda,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: await fetchEscrowTokenAccount(ctx, openBountyPda),
+          rewardMint: ctx.usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed - bounty not expired");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails when a solution was already submitted", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const solutionHash = generateSolutionHashWithValue(0x5a);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    await sleep(4000);
+
+    try {
+      await ctx.program.methods
+        .reclaimExpired()
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: bountyPda,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: bountyTokenAccount,
+          rewardMint: ctx.usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed - bounty not open");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails while a competition submission awaits a decision", async () => {
+    const competitionId = await nextBountyId(ctx);
+    const competitionPda = await postBounty(
+      ctx,
+      competitionId,
+      "Short-lived competition",
+      reward,
+      { deadline: defaultDeadline(2), competition: true }
+    );
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const solutionHash = generateSolutionHashWithValue(0x73);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      competitionId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, competitionPda, attestationPda, solutionHash);
+
+    await sleep(4000);
+
+    try {
+      await ctx.program.methods
+        .reclaimExpired()
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: competitionPda,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: await fetchEscrowTokenAccount(ctx, competitionPda),
+          rewardMint: ctx.usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed - active submission");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ActiveSubmissionExists");
+    }
+  });
+
+  it("Refunds the creator once the submission is rejected", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const solutionHash = generateSolutionHashWithValue(0x74);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    await sleep(4000);
+
+    await ctx.program.methods
+      .rejectSolution()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        reputation: deriveReputationPda(ctx.program.programId, agent.publicKey)[0],
+        agent: agent.publicKey,
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          bountyId,
+          agent.publicKey
+        )[0],
+        stake: deriveStakePda(ctx.program.programId, bountyId, agent.publicKey)[0],
+        treasury: ctx.admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    await ctx.program.methods
+      .reclaimExpired()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: bountyTokenAccount,
+        rewardMint: ctx.usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ expired: {} });
+  });
+});
@@ -0,0 +1,209 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  deriveStakePda,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  expectedFee,
+  REWARD_SOL,
+  TEST_REFERRAL_BPS,
+  TestContext,
+} from "./helpers";
+
+describe("referral", () => {
+  let ctx: TestContext;
+  let referrer: Keypair;
+  let referrerReputationPda: anchor.web3.PublicKey;
+  const reward = LAMPORTS_PER_SOL / 10;
+  const solutionHash = generateSolutionHashWithValue(0x7a);
+
+  async function submitToNewBounty(
+    agent: Keypair,
+    referredBy: anchor.web3.PublicKey | null = null
+  ): Promise<anchor.web3.PublicKey> {
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Referral bounty", reward, {
+      rewardKind: REWARD_SOL,
+    });
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash, [], referredBy);
+    return bountyPda;
+  }
+
+  function claimReferralRewards() {
+    return ctx.program.methods
+      .claimReferralRewards()
+      .accountsPartial({
+        referrer: referrer.publicKey,
+        reputation: referrerReputationPda,
+      })
+      .signers([referrer])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+    await airdropSol(ctx.connection, ctx.creator.publicKey);
+
+    // the referrer needs a reputation account before it can be named
+    referrer = Keypair.generate();
+    await airdropSol(ctx.connection, referrer.publicKey);
+    await submitToNewBounty(referrer);
+    [referrerReputationPda] = deriveReputationPda(
+      ctx.program.programId,
+      referrer.publicKey
+    );
+  });
+
+  it("Records the referrer and credits a share of the fee on settlement", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const bountyPda = await submitToNewBounty(agent, referrer.publicKey);
+
+    const [agentReputationPda] = deriveReputationPda(
+      ctx.program.programId,
+      agent.publicKey
+    );
+    const agentReputation = await ctx.program.account.reputation.fetch(
+      agentReputationPda
+    );
+    expect(agentReputation.referrer.toString()).to.equal(
+      referrer.publicKey.toString()
+    );
+
+    const treasuryBalanceBefore = await ctx.connection.getBalance(
+      ctx.admin.publicKey
+    );
+
+    await settleAndFinalize(ctx, bountyPda, agent, null);
+
+    const fee = expectedFee(reward);
+    const referral = Math.floor((fee * TEST_REFERRAL_BPS) / 10_000);
+
+    const referrerReputation = await ctx.program.account.reputation.fetch(
+      referrerReputationPda
+    );
+    expect(referrerReputation.referralEarnings.toNumber()).to.equal(referral);
+
+    const treasuryBalanceAfter = await ctx.connection.getBalance(
+      ctx.admin.publicKey
+    );
+    expect(treasuryBalanceAfter - treasuryBalanceBefore).to.equal(fee - referral);
+  });
+
+  it("Shares the fee with the referrer on a partial settlement", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const bountyPda = await submitToNewBounty(agent, referrer.publicKey);
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const { referralEarnings } = await ctx.program.account.reputation.fetch(
+      referrerReputationPda
+    );
+
+    const amount = reward / 2;
+    await ctx.program.methods
+      .settlePartial(new anchor.BN(amount))
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        agent: agent.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        reputation: deriveReputationPda(ctx.program.programId, agent.publicKey)[0],
+        referrerReputation: referrerReputationPda,
+        stake: deriveStakePda(
+          ctx.program.programId,
+          bounty.id.toNumber(),
+          agent.publicKey
+        )[0],
+        agentTokenAccount: null,
+        creatorTokenAccount: null,
+        bountyTokenAccount: null,
+        treasuryTokenAccount: null,
+        treasury: ctx.admin.publicKey,
+        vesting: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: null,
+      })
+      .signers([ctx.creator, agent])
+      .rpc();
+
+    const referral = Math.floor((expectedFee(amount) * TEST_REFERRAL_BPS) / 10_000);
+    const referrerReputation = await ctx.program.account.reputation.fetch(
+      referrerReputationPda
+    );
+    expect(
+      referrerReputation.referralEarnings.toNumber() - referralEarnings.toNumber()
+    ).to.equal(referral);
+  });
+
+  it("Pays out accrued referral rewards on claim", async () => {
+    const { referralEarnings } = await ctx.program.account.reputation.fetch(
+      referrerReputationPda
+    );
+    const balanceBefore = await ctx.connection.getBalance(referrer.publicKey);
+
+    await claimReferralRewards();
+
+    const balanceAfter = await ctx.connection.getBalance(referrer.publicKey);
+    expect(balanceAfter - balanceBefore).to.be.greaterThan(
+      referralEarnings.toNumber() - 10_000
+    );
+
+    const reputation = await ctx.program.account.reputation.fetch(
+      referrerReputationPda
+    );
+    expect(reputation.referralEarnings.toNumber()).to.equal(0);
+  });
+
+  it("Fails to claim with nothing accrued", async () => {
+    try {
+      await claimReferralRewards();
+
+      expect.fail("Should have failed - nothing to claim");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("NoReferralEarnings");
+    }
+  });
+
+  it("Rejects an agent referring itself", async () => {
+    try {
+      await submitToNewBounty(referrer, referrer.publicKey);
+
+      expect.fail("Should have failed - self referral");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SelfReferral");
+    }
+  });
+
+  it("Rejects a referrer without a reputation account", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    try {
+      await submitToNewBounty(agent, Keypair.generate().publicKey);
+
+      expect.fail("Should have failed - referrer has no reputation");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AccountNotInitialized");
+    }
+  });
+});
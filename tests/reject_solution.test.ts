@@ -0,0 +1,423 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  deriveSubmissionPda,
+  deriveStakePda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  updateConfig,
+  TEST_MAX_REJECTIONS,
+  TEST_REJECTION_PENALTY,
+  TEST_REJECTION_PENALTY_STEP,
+  TEST_STAKE_AMOUNT,
+  TestContext,
+} from "./helpers";
+
+describe("reject_solution", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let reputationPda: anchor.web3.PublicKey;
+  let submissionPda: anchor.web3.PublicKey;
+  let stakePda: anchor.web3.PublicKey;
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(
+      ctx,
+      bountyId,
+      "Bounty to reject",
+      100 * 10 ** 6
+    );
+
+    const solutionHash = generateSolutionHashWithValue(0x61);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    [reputationPda] = deriveReputationPda(
+      ctx.program.programId,
+      agent.publicKey
+    );
+    [submissionPda] = deriveSubmissionPda(
+      ctx.program.programId,
+      bountyId,
+      agent.publicKey
+    );
+    [stakePda] = deriveStakePda(ctx.program.programId, bountyId, agent.publicKey);
+  });
+
+  it("Rejects a submitted solution and reopens the bounty", async () => {
+    const reputationBefore = await ctx.program.account.reputation.fetch(
+      reputationPda
+    );
+
+    await ctx.program.methods
+      .rejectSolution()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        reputation: reputationPda,
+        agent: agent.publicKey,
+        submission: submissionPda,
+        stake: stakePda,
+        treasury: ctx.admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ open: {} });
+
+    const submissionInfo = await ctx.connection.getAccountInfo(submissionPda);
+    expect(submissionInfo).to.be.null;
+    expect(bountyAccount.solutionHash).to.be.null;
+    expect(bountyAccount.agent).to.be.null;
+
+    const reputationAfter = await ctx.program.account.reputation.fetch(
+      reputationPda
+    );
+    expect(reputationAfter.failedBounties.toNumber()).to.equal(
+      reputationBefore.failedBounties.toNumber() + 1
+    );
+    expect(reputationAfter.score.toNumber()).to.equal(
+      Math.max(reputationBefore.score.toNumber() - 1, 0)
+    );
+  });
+
+  it("Slashes the agent's stake to the treasury", async () => {
+    const stakeAccount = await ctx.program.account.stake.fetch(stakePda);
+    expect(stakeAccount.amount.toNumber()).to.equal(TEST_STAKE_AMOUNT);
+
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("solutionRejected", (e) => resolve(e));
+    });
+
+    await ctx.program.methods
+      .rejectSolution()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        reputation: reputationPda,
+        agent: agent.publicKey,
+        submission: submissionPda,
+        stake: stakePda,
+        treasury: ctx.admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+
+    expect(emitted.slashed.toNumber()).to.equal(TEST_STAKE_AMOUNT);
+    expect(await ctx.connection.getAccountInfo(stakePda)).to.be.null;
+  });
+
+  it("Saturates score at zero on repeated rejections", async () => {
+    await ctx.program.methods
+      .rejectSolution()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        reputation: reputationPda,
+        agent: agent.publicKey,
+        submission: submissionPda,
+        stake: stakePda,
+        treasury: ctx.admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const solutionHash = generateSolutionHashWithValue(0x62);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    await ctx.program.methods
+      .rejectSolution()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        reputation: reputationPda,
+        agent: agent.publicKey,
+        submission: submissionPda,
+        stake: stakePda,
+        treasury: ctx.admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const reputation = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputation.failedBounties.toNumber()).to.equal(2);
+    expect(reputation.score.toNumber()).to.equal(0);
+  });
+
+  it("Escalates the penalty for an agent with earlier failures", async () => {
+    const step = 5;
+    await ctx.program.methods
+      .adjustReputation({
+        scoreDelta: new anchor.BN(100),
+        successfulBountiesDelta: new anchor.BN(0),
+        failedBountiesDelta: new anchor.BN(0),
+        reasonHash: Array(32).fill(0x87),
+      })
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        reputation: reputationPda,
+      })
+      .signers([ctx.admin])
+      .rpc();
+
+    const penalties: number[] = [];
+    await updateConfig(ctx, { rejectionPenaltyStep: new anchor.BN(step) });
+    try {
+      for (const [i, value] of [0x87, 0x88, 0x89].entries()) {
+        if (i > 0) {
+          const solutionHash = generateSolutionHashWithValue(value);
+          const attestationPda = await createAttestation(
+            ctx,
+            agent,
+            bountyId,
+            generateRandomId(),
+            solutionHash
+          );
+          await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+        }
+
+        const before = await ctx.program.account.reputation.fetch(reputationPda);
+        await ctx.program.methods
+          .rejectSolution()
+          .accountsPartial({
+            creator: ctx.creator.publicKey,
+            bounty: bountyPda,
+            reputation: reputationPda,
+            agent: agent.publicKey,
+            submission: submissionPda,
+            stake: stakePda,
+            treasury: ctx.admin.publicKey,
+            tokenProgram: TOKEN_PROGRAM_ID,
+          })
+          .signers([ctx.creator])
+          .rpc();
+        const after = await ctx.program.account.reputation.fetch(reputationPda);
+        penalties.push(before.score.toNumber() - after.score.toNumber());
+      }
+    } finally {
+      await updateConfig(ctx, {
+        rejectionPenaltyStep: new anchor.BN(TEST_REJECTION_PENALTY_STEP),
+      });
+    }
+
+    // a first failure costs the base penalty, each later one a step more
+    expect(penalties).to.deep.equal([
+      TEST_REJECTION_PENALTY,
+      TEST_REJECTION_PENALTY + step,
+      TEST_REJECTION_PENALTY + 2 * step,
+    ]);
+  });
+
+  it("Fails to resubmit the solution that was just rejected", async () => {
+    await ctx.program.methods
+      .rejectSolution()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        reputation: reputationPda,
+        agent: agent.publicKey,
+        submission: submissionPda,
+        stake: stakePda,
+        treasury: ctx.admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const rejectedHash = generateSolutionHashWithValue(0x61);
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(Buffer.from(bountyAccount.lastRejectedHash)).to.deep.equal(rejectedHash);
+
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      rejectedHash
+    );
+
+    try {
+      await submitSolution(ctx, agent, bountyPda, attestationPda, rejectedHash);
+
+      expect.fail("Should have failed - duplicate solution");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("DuplicateSolution");
+    }
+  });
+
+  it("Cancels the bounty and refunds the creator once the rejection limit is reached", async () => {
+    const reward = 100 * 10 ** 6;
+    const bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
+    const creatorBalanceBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+
+    await updateConfig(ctx, { maxRejections: 1 });
+    try {
+      await ctx.program.methods
+        .rejectSolution()
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: bountyPda,
+          reputation: reputationPda,
+          agent: agent.publicKey,
+          submission: submissionPda,
+          stake: stakePda,
+          treasury: ctx.admin.publicKey,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: bountyTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ctx.creator])
+        .rpc();
+    } finally {
+      await updateConfig(ctx, { maxRejections: TEST_MAX_REJECTIONS });
+    }
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ cancelled: {} });
+    expect(bountyAccount.rejectionCount).to.equal(1);
+    expect(bountyAccount.agent).to.be.null;
+
+    const creatorBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+    const bountyBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      bountyTokenAccount
+    );
+    expect(bountyBalanceAfter.value.amount).to.equal("0");
+    expect(creatorBalanceAfter.value.amount).to.equal(
+      (Number(creatorBalanceBefore.value.amount) + reward).toString()
+    );
+  });
+
+  it("Counts rejections without cancelling while the limit is disabled", async () => {
+    await ctx.program.methods
+      .rejectSolution()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        reputation: reputationPda,
+        agent: agent.publicKey,
+        submission: submissionPda,
+        stake: stakePda,
+        treasury: ctx.admin.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ open: {} });
+    expect(bountyAccount.rejectionCount).to.equal(1);
+  });
+
+  it("Fails when caller is not the bounty creator", async () => {
+    const wrongCreator = Keypair.generate();
+    await airdropSol(ctx.connection, wrongCreator.publicKey);
+
+    try {
+      await ctx.program.methods
+        .rejectSolution()
+        .accountsPartial({
+          creator: wrongCreator.publicKey,
+          bounty: bountyPda,
+          reputation: reputationPda,
+          agent: agent.publicKey,
+          submission: submissionPda,
+          stake: stakePda,
+          treasury: ctx.admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([wrongCreator])
+        .rpc();
+
+      expect.fail("Should have failed - unauthorized creator");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails when bounty is not in Submitted status", async () => {
+    const openBountyId = await nextBountyId(ctx);
+    const openBountyPda = await postBounty(
+      ctx,
+      openBountyId,
+      "Open bounty",
+      50 * 10 ** 6
+    );
+
+    try {
+      await ctx.program.methods
+        .rejectSolution()
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: openBountyPda,
+          reputation: reputationPda,
+          agent: agent.publicKey,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            openBountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            openBountyId,
+            agent.publicKey
+          )[0],
+          treasury: ctx.admin.publicKey,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed - bounty not submitted");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+});
@@ -0,0 +1,126 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveReputationPda,
+  deriveSubmissionPda,
+  deriveStakePda,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  sleep,
+  TEST_SUBMISSION_TIMEOUT,
+  TestContext,
+} from "./helpers";
+
+describe("reopen_bounty", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let reputationPda: anchor.web3.PublicKey;
+  let submissionPda: anchor.web3.PublicKey;
+  let stakePda: anchor.web3.PublicKey;
+
+  function reopenBounty(creator: Keypair) {
+    return ctx.program.methods
+      .reopenBounty()
+      .accountsPartial({
+        creator: creator.publicKey,
+        bounty: bountyPda,
+        reputation: reputationPda,
+        agent: agent.publicKey,
+        submission: submissionPda,
+        stake: stakePda,
+      })
+      .signers([creator])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Bounty left unreviewed", 100 * 10 ** 6);
+
+    const solutionHash = generateSolutionHashWithValue(0x71);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    [reputationPda] = deriveReputationPda(
+      ctx.program.programId,
+      agent.publicKey
+    );
+    [submissionPda] = deriveSubmissionPda(
+      ctx.program.programId,
+      bountyId,
+      agent.publicKey
+    );
+    [stakePda] = deriveStakePda(ctx.program.programId, bountyId, agent.publicKey);
+  });
+
+  it("Fails before the submission timeout has elapsed", async () => {
+    try {
+      await reopenBounty(ctx.creator);
+
+      expect.fail("Should have failed - submission timeout not elapsed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SubmissionTimeoutNotElapsed");
+    }
+  });
+
+  it("Reopens a stale submission and records a failure", async () => {
+    const reputationBefore = await ctx.program.account.reputation.fetch(
+      reputationPda
+    );
+
+    await sleep((TEST_SUBMISSION_TIMEOUT + 1) * 1000);
+    await reopenBounty(ctx.creator);
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ open: {} });
+    expect(bountyAccount.solutionHash).to.be.null;
+    expect(bountyAccount.agent).to.be.null;
+
+    const reputationAfter = await ctx.program.account.reputation.fetch(
+      reputationPda
+    );
+    expect(reputationAfter.failedBounties.toNumber()).to.equal(
+      reputationBefore.failedBounties.toNumber() + 1
+    );
+
+    expect(await ctx.connection.getAccountInfo(submissionPda)).to.be.null;
+    expect(await ctx.connection.getAccountInfo(stakePda)).to.be.null;
+  });
+
+  it("Fails when caller is not the bounty creator", async () => {
+    const wrongCreator = Keypair.generate();
+    await airdropSol(ctx.connection, wrongCreator.publicKey);
+
+    await sleep((TEST_SUBMISSION_TIMEOUT + 1) * 1000);
+
+    try {
+      await reopenBounty(wrongCreator);
+
+      expect.fail("Should have failed - unauthorized creator");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("UnauthorizedCreator");
+    }
+  });
+});
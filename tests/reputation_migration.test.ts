@@ -0,0 +1,126 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("reputation_migration", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let reputationPda: anchor.web3.PublicKey;
+
+  function exportReputation(reputation: anchor.web3.PublicKey) {
+    return ctx.program.methods
+      .exportReputation()
+      .accountsPartial({ reputation })
+      .view();
+  }
+
+  function importReputation(target: anchor.web3.PublicKey, snapshot: any) {
+    return ctx.program.methods
+      .importReputation(snapshot)
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        agent: target,
+        reputation: deriveReputationPda(ctx.program.programId, target)[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.admin])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      10 * 10 ** 6
+    );
+
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    [reputationPda] = deriveReputationPda(ctx.program.programId, agent.publicKey);
+
+    const solutionHash = generateSolutionHashWithValue(0x63);
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Migrated bounty", 10 * 10 ** 6);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  });
+
+  it("Round-trips a reputation through export and import", async () => {
+    const coldWallet = Keypair.generate().publicKey;
+    await ctx.program.methods
+      .addWithdrawalAddress(coldWallet)
+      .accountsPartial({ agent: agent.publicKey, reputation: reputationPda })
+      .signers([agent])
+      .rpc();
+
+    const snapshot = await exportReputation(reputationPda);
+    expect(snapshot.agent.toString()).to.equal(agent.publicKey.toString());
+
+    const successor = Keypair.generate();
+    await importReputation(successor.publicKey, {
+      ...snapshot,
+      agent: successor.publicKey,
+    });
+
+    const imported = await exportReputation(
+      deriveReputationPda(ctx.program.programId, successor.publicKey)[0]
+    );
+    expect(imported.score.toNumber()).to.equal(snapshot.score.toNumber());
+    expect(imported.lastActive.toNumber()).to.equal(snapshot.lastActive.toNumber());
+    expect(imported.lastSubmissionAt.toNumber()).to.equal(
+      snapshot.lastSubmissionAt.toNumber()
+    );
+    expect(imported.withdrawWhitelist.map((owner) => owner.toString())).to.deep.equal([
+      coldWallet.toString(),
+    ]);
+  });
+
+  it("Refuses to overwrite an existing reputation", async () => {
+    const snapshot = await exportReputation(reputationPda);
+
+    try {
+      await importReputation(agent.publicKey, snapshot);
+
+      expect.fail("Should have failed - reputation already exists");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ReputationAlreadyExists");
+    }
+  });
+
+  it("Fails when the snapshot belongs to another agent", async () => {
+    const snapshot = await exportReputation(reputationPda);
+
+    try {
+      await importReputation(Keypair.generate().publicKey, snapshot);
+
+      expect.fail("Should have failed - snapshot agent mismatch");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ReputationOwnerMismatch");
+    }
+  });
+});
@@ -0,0 +1,142 @@
+import * as anchor from "@coral-xyz/anchor";
+import {
+  createMint,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  nextBountyId,
+  TestContext,
+} from "./helpers";
+
+describe("rescue_tokens", () => {
+  let ctx: TestContext;
+  let bountyPda: anchor.web3.PublicKey;
+  let strayMint: anchor.web3.PublicKey;
+  let strayTokenAccount: anchor.web3.PublicKey;
+  let recipientTokenAccount: anchor.web3.PublicKey;
+  const strayAmount = 5 * 10 ** 6;
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      20 * 10 ** 6
+    );
+    bountyPda = await postBounty(ctx, await nextBountyId(ctx), "Rescue bounty", 10 * 10 ** 6);
+
+    strayMint = await createMint(
+      ctx.connection,
+      ctx.creator,
+      ctx.creator.publicKey,
+      null,
+      6
+    );
+    strayTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        ctx.connection,
+        ctx.creator,
+        strayMint,
+        bountyPda,
+        true
+      )
+    ).address;
+    await mintTo(
+      ctx.connection,
+      ctx.creator,
+      strayMint,
+      strayTokenAccount,
+      ctx.creator,
+      strayAmount
+    );
+
+    const recipient = Keypair.generate();
+    recipientTokenAccount = (
+      await getOrCreateAssociatedTokenAccount(
+        ctx.connection,
+        ctx.creator,
+        strayMint,
+        recipient.publicKey
+      )
+    ).address;
+  });
+
+  it("Moves stray tokens out of a bounty PDA", async () => {
+    await ctx.program.methods
+      .rescueTokens(new anchor.BN(strayAmount))
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        bounty: bountyPda,
+        sourceTokenAccount: strayTokenAccount,
+        recipientTokenAccount: recipientTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.admin])
+      .rpc();
+
+    const source = await getAccount(ctx.connection, strayTokenAccount);
+    const recipient = await getAccount(ctx.connection, recipientTokenAccount);
+    expect(Number(source.amount)).to.equal(0);
+    expect(Number(recipient.amount)).to.equal(strayAmount);
+  });
+
+  it("Fails to touch the reward escrow", async () => {
+    const escrow = await fetchEscrowTokenAccount(ctx, bountyPda);
+
+    try {
+      await ctx.program.methods
+        .rescueTokens(new anchor.BN(1))
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          bounty: bountyPda,
+          sourceTokenAccount: escrow,
+          recipientTokenAccount: ctx.creatorTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([ctx.admin])
+        .rpc();
+
+      expect.fail("Should have failed - reward mint");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("RewardMintNotRescuable");
+    }
+  });
+
+  it("Fails when the caller is not the admin", async () => {
+    const impostor = Keypair.generate();
+    await airdropSol(ctx.connection, impostor.publicKey);
+
+    try {
+      await ctx.program.methods
+        .rescueTokens(new anchor.BN(strayAmount))
+        .accountsPartial({
+          admin: impostor.publicKey,
+          bounty: bountyPda,
+          sourceTokenAccount: strayTokenAccount,
+          recipientTokenAccount: recipientTokenAccount,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([impostor])
+        .rpc();
+
+      expect.fail("Should have failed - unauthorized admin");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("UnauthorizedAdmin");
+    }
+  });
+});
@@ -0,0 +1,234 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  deriveStakePda,
+  deriveVestingPda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  expectedFee,
+  TEST_REJECTION_PENALTY,
+  TestContext,
+} from "./helpers";
+
+describe("resolve_dispute", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let agentTokenAccount: anchor.web3.PublicKey;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const reward = 60 * 10 ** 6;
+
+  async function resolveDispute(outcome: object, arbiter: Keypair = ctx.admin) {
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const vests = bounty.vestingSeconds.toNumber() > 0;
+
+    return ctx.program.methods
+      .resolveDispute(outcome as any)
+      .accountsPartial({
+        arbiter: arbiter.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        reputation: deriveReputationPda(
+          ctx.program.programId,
+          agent.publicKey
+        )[0],
+        referrerReputation: null,
+        agent: agent.publicKey,
+        creator: ctx.creator.publicKey,
+        stake: deriveStakePda(ctx.program.programId, bountyId, agent.publicKey)[0],
+        agentTokenAccount,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, bountyPda),
+        treasuryTokenAccount: ctx.treasuryTokenAccount,
+        treasury: ctx.admin.publicKey,
+        vesting: vests ? deriveVestingPda(ctx.program.programId, bountyId)[0] : null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: vests ? SystemProgram.programId : null,
+      })
+      .signers([arbiter])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      200 * 10 ** 6
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Contested bounty", reward);
+
+    const solutionHash = generateSolutionHashWithValue(0xd1);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+  });
+
+  async function raiseDispute() {
+    await ctx.program.methods
+      .raiseDispute()
+      .accountsPartial({
+        agent: agent.publicKey,
+        bounty: bountyPda,
+      })
+      .signers([agent])
+      .rpc();
+  }
+
+  it("Pays the agent when resolved in their favour", async () => {
+    await raiseDispute();
+
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("disputeResolved", (e) => resolve(e));
+    });
+
+    await resolveDispute({ payAgent: {} });
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+    expect(emitted.bountyId.toNumber()).to.equal(bountyId);
+    expect(emitted.outcome).to.deep.equal({ payAgent: {} });
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ settled: {} });
+
+    const agentBalance = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
+    expect(agentBalance.value.amount).to.equal(
+      (reward - expectedFee(reward)).toString()
+    );
+
+    const reputation = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agent.publicKey)[0]
+    );
+    expect(reputation.successfulBounties.toNumber()).to.equal(1);
+  });
+
+  it("Vests the payout when the bounty was posted with a vesting period", async () => {
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Contested vesting bounty", reward, {
+      vestingSeconds: 3600,
+    });
+    const solutionHash = generateSolutionHashWithValue(0xd2);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+    await raiseDispute();
+
+    await resolveDispute({ payAgent: {} });
+
+    const payout = reward - expectedFee(reward);
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ settled: {} });
+    expect(bounty.claimableAmount.toNumber()).to.equal(payout);
+
+    const vesting = await ctx.program.account.vesting.fetch(
+      deriveVestingPda(ctx.program.programId, bountyId)[0]
+    );
+    expect(vesting.total.toNumber()).to.equal(payout);
+
+    // nothing is pushed to the agent, the schedule releases it
+    const agentBalance = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
+    expect(agentBalance.value.amount).to.equal("0");
+  });
+
+  it("Refunds the creator when resolved in their favour", async () => {
+    await raiseDispute();
+
+    const [reputationPda] = deriveReputationPda(ctx.program.programId, agent.publicKey);
+    const reputationBefore = await ctx.program.account.reputation.fetch(reputationPda);
+    const creatorBalanceBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+
+    await resolveDispute({ refundCreator: {} });
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ cancelled: {} });
+
+    const creatorBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+    expect(
+      Number(creatorBalanceAfter.value.amount) -
+        Number(creatorBalanceBefore.value.amount)
+    ).to.equal(reward);
+
+    // penalized like a rejection, a first failure costing the base penalty
+    const reputation = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputation.failedBounties.toNumber()).to.equal(1);
+    expect(reputationBefore.score.toNumber() - reputation.score.toNumber()).to.equal(
+      TEST_REJECTION_PENALTY
+    );
+  });
+
+  it("Fails when the signer is not the arbiter", async () => {
+    await raiseDispute();
+
+    const notArbiter = Keypair.generate();
+    await airdropSol(ctx.connection, notArbiter.publicKey);
+
+    try {
+      await resolveDispute({ payAgent: {} }, notArbiter);
+
+      expect.fail("Should have failed - unauthorized arbiter");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("UnauthorizedArbiter");
+    }
+  });
+
+  it("Fails when the bounty is not disputed", async () => {
+    try {
+      await resolveDispute({ payAgent: {} });
+
+      expect.fail("Should have failed - bounty not disputed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotDisputed");
+    }
+  });
+});
@@ -0,0 +1,169 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveSubmissionPda,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("revise_solution", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const solutionHash = generateSolutionHashWithValue(0x81);
+  const revisedHash = generateSolutionHashWithValue(0x82);
+
+  function reviseSolution(
+    reviser: Keypair,
+    attestation: anchor.web3.PublicKey,
+    hash: Buffer,
+    kind = 0,
+    size = 0
+  ) {
+    return ctx.program.methods
+      .reviseSolution(Array.from(hash), kind, size)
+      .accountsPartial({
+        agent: reviser.publicKey,
+        bounty: bountyPda,
+        attestation,
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          bountyId,
+          reviser.publicKey
+        )[0],
+      })
+      .signers([reviser])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Bounty to revise", 50 * 10 ** 6);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  });
+
+  it("Replaces the solution hash and bumps the revision", async () => {
+    const revisedAttestation = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      revisedHash
+    );
+
+    await reviseSolution(agent, revisedAttestation, revisedHash, 3, 40);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(Buffer.from(bounty.solutionHash)).to.deep.equal(revisedHash);
+    expect(bounty.solutionKind).to.equal(3);
+    expect(bounty.solutionSize).to.equal(40);
+    expect(bounty.revision).to.equal(1);
+    expect(bounty.status).to.deep.equal({ submitted: {} });
+
+    const submission = await ctx.program.account.submission.fetch(
+      deriveSubmissionPda(ctx.program.programId, bountyId, agent.publicKey)[0]
+    );
+    expect(Buffer.from(submission.solutionHash)).to.deep.equal(revisedHash);
+    expect(submission.solutionKind).to.equal(3);
+  });
+
+  it("Fails when another agent tries to revise", async () => {
+    const other = Keypair.generate();
+    await airdropSol(ctx.connection, other.publicKey);
+    const otherAttestation = await createAttestation(
+      ctx,
+      other,
+      bountyId,
+      generateRandomId(),
+      revisedHash
+    );
+
+    try {
+      await reviseSolution(other, otherAttestation, revisedHash);
+
+      expect.fail("Should have failed - not the submitting agent");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("UnauthorizedRevision");
+    }
+  });
+
+  it("Fails without a verified attestation for the new hash", async () => {
+    const unverifiedAttestation = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      revisedHash,
+      false
+    );
+
+    try {
+      await reviseSolution(agent, unverifiedAttestation, revisedHash);
+
+      expect.fail("Should have failed - attestation not verified");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AttestationNotVerified");
+    }
+  });
+
+  it("Fails when the attestation is for a different hash", async () => {
+    const revisedAttestation = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      revisedHash
+    );
+
+    try {
+      await reviseSolution(agent, revisedAttestation, generateSolutionHashWithValue(0x83));
+
+      expect.fail("Should have failed - hash mismatch");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SolutionHashMismatch");
+    }
+  });
+
+  it("Fails once the creator has proposed settlement", async () => {
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+    const revisedAttestation = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      revisedHash
+    );
+
+    try {
+      await reviseSolution(agent, revisedAttestation, revisedHash);
+
+      expect.fail("Should have failed - settlement already proposed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotSubmitted");
+    }
+  });
+});
@@ -0,0 +1,117 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  verifyAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("revoke_attestation", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let attestationPda: anchor.web3.PublicKey;
+  const solutionHash = generateSolutionHashWithValue(0x50);
+
+  function revokeAttestation(signer: Keypair) {
+    return ctx.program.methods
+      .revokeAttestation()
+      .accountsPartial({
+        agent: signer.publicKey,
+        bounty: bountyPda,
+        attestation: attestationPda,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Revocation bounty", 10 * 10 ** 6);
+    attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+  });
+
+  it("Revokes an attestation and blocks submissions against it", async () => {
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("attestationRevoked", (e) => resolve(e));
+    });
+
+    await revokeAttestation(agent);
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+    expect(emitted.bountyId.toNumber()).to.equal(bountyId);
+
+    const attestation = await ctx.program.account.attestation.fetch(attestationPda);
+    expect(attestation.revoked).to.be.true;
+    expect(attestation.verified).to.be.false;
+
+    try {
+      await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+      expect.fail("Should have failed - attestation revoked");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AttestationNotVerified");
+    }
+  });
+
+  it("Prevents a revoked attestation from being verified again", async () => {
+    await revokeAttestation(agent);
+
+    try {
+      await verifyAttestation(ctx, attestationPda);
+
+      expect.fail("Should have failed - attestation revoked");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AttestationRevoked");
+    }
+  });
+
+  it("Fails when caller is not the attesting agent", async () => {
+    const outsider = Keypair.generate();
+    await airdropSol(ctx.connection, outsider.publicKey);
+
+    try {
+      await revokeAttestation(outsider);
+
+      expect.fail("Should have failed - not the attesting agent");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AttestationOwnerMismatch");
+    }
+  });
+
+  it("Fails once the bounty has been submitted", async () => {
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    try {
+      await revokeAttestation(agent);
+
+      expect.fail("Should have failed - bounty already submitted");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotOpen");
+    }
+  });
+});
@@ -0,0 +1,109 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveReputationPda,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  updateConfig,
+  TEST_SCORE_ON_SETTLE,
+  TEST_SCORE_ON_SUBMIT,
+  TestContext,
+} from "./helpers";
+
+describe("score_on_submit and score_on_settle", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyPda: anchor.web3.PublicKey;
+  const difficulty = 3;
+  const solutionHash = generateSolutionHashWithValue(0x51);
+
+  async function score(): Promise<number> {
+    const reputation = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agent.publicKey)[0]
+    );
+    return reputation.score.toNumber();
+  }
+
+  async function submit(): Promise<void> {
+    const bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Scored bounty", 10 * 10 ** 6, {
+      difficulty,
+    });
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  }
+
+  async function settle(): Promise<void> {
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      10 * 10 ** 6
+    );
+
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+  });
+
+  it("Awards score only on settlement by default", async () => {
+    await submit();
+    expect(await score()).to.equal(0);
+
+    await settle();
+    expect(await score()).to.equal(difficulty * TEST_SCORE_ON_SETTLE);
+  });
+
+  describe("when score is awarded on submission", () => {
+    before(async () => {
+      await updateConfig(ctx, {
+        scoreOnSubmit: new anchor.BN(2),
+        scoreOnSettle: new anchor.BN(0),
+      });
+    });
+
+    after(async () => {
+      await updateConfig(ctx, {
+        scoreOnSubmit: new anchor.BN(TEST_SCORE_ON_SUBMIT),
+        scoreOnSettle: new anchor.BN(TEST_SCORE_ON_SETTLE),
+      });
+    });
+
+    it("Awards score on reveal and nothing more on settlement", async () => {
+      await submit();
+      expect(await score()).to.equal(2 * difficulty);
+
+      await settle();
+      expect(await score()).to.equal(2 * difficulty);
+    });
+  });
+});
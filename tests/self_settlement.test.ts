@@ -0,0 +1,100 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveCreatorProfilePda,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("self_settlement", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const reward = 10 * 10 ** 6;
+
+  async function submit(submitter: Keypair, value: number): Promise<void> {
+    const solutionHash = generateSolutionHashWithValue(value);
+    const attestationPda = await createAttestation(
+      ctx,
+      submitter,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, submitter, bountyPda, attestationPda, solutionHash);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Self settlement bounty", reward);
+  });
+
+  it("Settles between a distinct creator and agent", async () => {
+    await submit(agent, 0x96);
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ pendingSettlement: {} });
+    expect(bounty.agent.toString()).to.equal(agent.publicKey.toString());
+  });
+
+  it("Fails when the creator submits to their own bounty", async () => {
+    try {
+      await submit(ctx.creator, 0x97);
+      expect.fail("Should have failed - creator is the agent");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SelfSettlementForbidden");
+    }
+  });
+
+  it("Fails to settle once the bounty was handed to its agent", async () => {
+    await submit(agent, 0x98);
+    await ctx.program.methods
+      .transferBountyOwnership()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        newCreator: agent.publicKey,
+        newCreatorProfile: deriveCreatorProfilePda(
+          ctx.program.programId,
+          agent.publicKey
+        )[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    try {
+      await settleBounty(ctx, bountyPda, agent.publicKey, agent);
+      expect.fail("Should have failed - agent owns the bounty");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SelfSettlementForbidden");
+    }
+  });
+});
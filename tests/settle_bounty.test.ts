@@ -1,20 +1,29 @@
 import * as anchor from "@coral-xyz/anchor";
-import { TOKEN_PROGRAM_ID, mintTo } from "@solana/spl-token";
-import { Keypair, SystemProgram } from "@solana/web3.js";
+import { TOKEN_PROGRAM_ID, createAccount } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
 import { expect } from "chai";
 import {
   setupTestContext,
-  deriveBountyPda,
   deriveReputationPda,
-  getAssociatedTokenAddressSync,
+  deriveStakePda,
+  fetchEscrowTokenAccount,
+  deriveVaultPda,
   airdropSol,
   createAgentTokenAccount,
   ensureCreatorBalance,
   postBounty,
   createAttestation,
   submitSolution,
+  settleBounty,
+  finalizeSettlement,
+  claimReward,
+  settleAndFinalize,
   generateRandomId,
+  nextBountyId,
   generateSolutionHashWithValue,
+  expectedFee,
+  sleep,
+  TEST_DISPUTE_WINDOW,
   TestContext,
 } from "./helpers";
 
@@ -52,7 +61,7 @@ describe("settle_bounty", () => {
       200 * 10 ** 6
     );
 
-    testBountyId = generateRandomId();
+    testBountyId = await nextBountyId(ctx);
     testBountyPda = await postBounty(
       ctx,
       testBountyId,
@@ -60,16 +69,14 @@ describe("settle_bounty", () => {
       100 * 10 ** 6
     );
 
-    testBountyTokenAccount = getAssociatedTokenAddressSync(
-      ctx.usdcMint,
-      testBountyPda
-    );
+    testBountyTokenAccount = await fetchEscrowTokenAccount(ctx, testBountyPda);
 
     solutionId = generateRandomId();
     solutionHash = generateSolutionHashWithValue(0xaa);
     attestationPda = await createAttestation(
       ctx,
       agent,
+      testBountyId,
       solutionId,
       solutionHash
     );
@@ -88,35 +95,41 @@ describe("settle_bounty", () => {
     );
   });
 
-  it("Settles a bounty successfully and transfers reward to agent", async () => {
-    const reward = 100 * 10 ** 6;
+  it("Moves the bounty into PendingSettlement without paying out", async () => {
+    const agentBalanceBefore = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
 
-    const bountyBalanceBefore = await ctx.connection.getTokenAccountBalance(
-      testBountyTokenAccount
+    await settleBounty(ctx, testBountyPda, agent.publicKey);
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(testBountyPda);
+    expect(bountyAccount.status).to.deep.equal({ pendingSettlement: {} });
+    expect(bountyAccount.settleUnlockAt.toNumber()).to.be.at.least(
+      Math.floor(Date.now() / 1000) + TEST_DISPUTE_WINDOW - 5
     );
-    const agentBalanceBefore = await ctx.connection.getTokenAccountBalance(
+
+    const agentBalanceAfter = await ctx.connection.getTokenAccountBalance(
       agentTokenAccount
     );
+    expect(agentBalanceAfter.value.amount).to.equal(
+      agentBalanceBefore.value.amount
+    );
+  });
 
+  it("Finalizes after the dispute window and splits the reward between agent and treasury", async () => {
+    const reward = 100 * 10 ** 6;
+
+    const agentBalanceBefore = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
+    const treasuryBalanceBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.treasuryTokenAccount
+    );
     const reputationBefore = await ctx.program.account.reputation.fetch(
       reputationPda
     );
 
-    await ctx.program.methods
-      .settleBounty()
-      .accountsPartial({
-        creator: ctx.creator.publicKey,
-        bounty: testBountyPda,
-        reputation: reputationPda,
-        agent: agent.publicKey,
-        agentTokenAccount: agentTokenAccount,
-        bountyTokenAccount: testBountyTokenAccount,
-        usdcMint: ctx.usdcMint,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([ctx.creator])
-      .rpc();
+    await settleAndFinalize(ctx, testBountyPda, agent, agentTokenAccount);
 
     const bountyAccount = await ctx.program.account.bounty.fetch(testBountyPda);
     expect(bountyAccount.status).to.deep.equal({ settled: {} });
@@ -127,10 +140,17 @@ describe("settle_bounty", () => {
     const agentBalanceAfter = await ctx.connection.getTokenAccountBalance(
       agentTokenAccount
     );
+    const treasuryBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.treasuryTokenAccount
+    );
+    const fee = expectedFee(reward);
 
     expect(bountyBalanceAfter.value.amount).to.equal("0");
     expect(agentBalanceAfter.value.amount).to.equal(
-      (Number(agentBalanceBefore.value.amount) + reward).toString()
+      (Number(agentBalanceBefore.value.amount) + reward - fee).toString()
+    );
+    expect(treasuryBalanceAfter.value.amount).to.equal(
+      (Number(treasuryBalanceBefore.value.amount) + fee).toString()
     );
 
     const reputationAfter = await ctx.program.account.reputation.fetch(
@@ -140,30 +160,33 @@ describe("settle_bounty", () => {
       reputationBefore.successfulBounties.toNumber() + 1
     );
     expect(reputationAfter.totalEarned.toNumber()).to.equal(
-      reputationBefore.totalEarned.toNumber() + reward
+      reputationBefore.totalEarned.toNumber() + reward - fee
     );
   });
 
+  it("Fails to finalize before the dispute window elapses", async () => {
+    await settleBounty(ctx, testBountyPda, agent.publicKey);
+
+    try {
+      await finalizeSettlement(
+        ctx,
+        testBountyPda,
+        agent.publicKey,
+        agentTokenAccount
+      );
+
+      expect.fail("Should have failed - dispute window still open");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
   it("Fails when creator is not the bounty creator", async () => {
     const wrongCreator = Keypair.generate();
     await airdropSol(ctx.connection, wrongCreator.publicKey);
 
     try {
-      await ctx.program.methods
-        .settleBounty()
-        .accountsPartial({
-          creator: wrongCreator.publicKey,
-          bounty: testBountyPda,
-          reputation: reputationPda,
-          agent: agent.publicKey,
-          agentTokenAccount: agentTokenAccount,
-          bountyTokenAccount: testBountyTokenAccount,
-          usdcMint: ctx.usdcMint,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([wrongCreator])
-        .rpc();
+      await settleBounty(ctx, testBountyPda, agent.publicKey, wrongCreator);
 
       expect.fail("Should have failed - unauthorized creator");
     } catch (err) {
@@ -171,29 +194,43 @@ describe("settle_bounty", () => {
     }
   });
 
+  it("Lets the settle delegate propose the settlement", async () => {
+    const delegate = Keypair.generate();
+    await airdropSol(ctx.connection, delegate.publicKey);
+
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Delegated bounty", 10 * 10 ** 6, {
+      settleDelegate: delegate.publicKey,
+    });
+    const delegatedHash = generateSolutionHashWithValue(0x64);
+    const delegatedAttestation = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      delegatedHash
+    );
+    await submitSolution(ctx, agent, bountyPda, delegatedAttestation, delegatedHash);
+
+    await settleBounty(ctx, bountyPda, agent.publicKey, delegate);
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.settleDelegate.toString()).to.equal(
+      delegate.publicKey.toString()
+    );
+    expect(bountyAccount.status).to.deep.equal({ pendingSettlement: {} });
+  });
+
   it("Fails when bounty is not in Submitted status", async () => {
-    const bountyId2 = generateRandomId();
-    const bountyPda2 = await postBounty(ctx, bountyId2, "Open bounty", 50 * 10 ** 6);
+    const bountyPda2 = await postBounty(
+      ctx,
+      await nextBountyId(ctx),
+      "Open bounty",
+      50 * 10 ** 6
+    );
 
     try {
-      await ctx.program.methods
-        .settleBounty()
-        .accountsPartial({
-          creator: ctx.creator.publicKey,
-          bounty: bountyPda2,
-          reputation: reputationPda,
-          agent: agent.publicKey,
-          agentTokenAccount: agentTokenAccount,
-          bountyTokenAccount: getAssociatedTokenAddressSync(
-            ctx.usdcMint,
-            bountyPda2
-          ),
-          usdcMint: ctx.usdcMint,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([ctx.creator])
-        .rpc();
+      await settleBounty(ctx, bountyPda2, agent.publicKey);
 
       expect.fail("Should have failed - bounty not in Submitted status");
     } catch (err) {
@@ -202,64 +239,35 @@ describe("settle_bounty", () => {
   });
 
   it("Fails when trying to settle already settled bounty", async () => {
-    await ctx.program.methods
-      .settleBounty()
-      .accountsPartial({
-        creator: ctx.creator.publicKey,
-        bounty: testBountyPda,
-        reputation: reputationPda,
-        agent: agent.publicKey,
-        agentTokenAccount: agentTokenAccount,
-        bountyTokenAccount: testBountyTokenAccount,
-        usdcMint: ctx.usdcMint,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([ctx.creator])
-      .rpc();
+    await settleAndFinalize(ctx, testBountyPda, agent, agentTokenAccount);
 
     try {
-      await ctx.program.methods
-        .settleBounty()
-        .accountsPartial({
-          creator: ctx.creator.publicKey,
-          bounty: testBountyPda,
-          reputation: reputationPda,
-          agent: agent.publicKey,
-          agentTokenAccount: agentTokenAccount,
-          bountyTokenAccount: testBountyTokenAccount,
-          usdcMint: ctx.usdcMint,
-          tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
-        })
-        .signers([ctx.creator])
-        .rpc();
+      await settleBounty(ctx, testBountyPda, agent.publicKey);
 
       expect.fail("Should have failed - bounty already settled");
     } catch (err) {
       expect(err).to.exist;
     }
+
+    try {
+      await finalizeSettlement(
+        ctx,
+        testBountyPda,
+        agent.publicKey,
+        agentTokenAccount
+      );
+
+      expect.fail("Should have failed - settlement already finalized");
+    } catch (err) {
+      expect(err).to.exist;
+    }
   });
 
   it("Updates reputation correctly for multiple settlements", async () => {
-    await ctx.program.methods
-      .settleBounty()
-      .accountsPartial({
-        creator: ctx.creator.publicKey,
-        bounty: testBountyPda,
-        reputation: reputationPda,
-        agent: agent.publicKey,
-        agentTokenAccount: agentTokenAccount,
-        bountyTokenAccount: testBountyTokenAccount,
-        usdcMint: ctx.usdcMint,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([ctx.creator])
-      .rpc();
-
-    const bountyId2 = generateRandomId();
+    await settleAndFinalize(ctx, testBountyPda, agent, agentTokenAccount);
+
     const reward2 = 75 * 10 ** 6;
+    const bountyId2 = await nextBountyId(ctx);
     const bountyPda2 = await postBounty(
       ctx,
       bountyId2,
@@ -267,12 +275,12 @@ describe("settle_bounty", () => {
       reward2
     );
 
-    const solutionId2 = generateRandomId();
     const solutionHash2 = generateSolutionHashWithValue(0xbb);
     const attestationPda2 = await createAttestation(
       ctx,
       agent,
-      solutionId2,
+      bountyId2,
+      generateRandomId(),
       solutionHash2
     );
 
@@ -282,24 +290,7 @@ describe("settle_bounty", () => {
       reputationPda
     );
 
-    await ctx.program.methods
-      .settleBounty()
-      .accountsPartial({
-        creator: ctx.creator.publicKey,
-        bounty: bountyPda2,
-        reputation: reputationPda,
-        agent: agent.publicKey,
-        agentTokenAccount: agentTokenAccount,
-        bountyTokenAccount: getAssociatedTokenAddressSync(
-          ctx.usdcMint,
-          bountyPda2
-        ),
-        usdcMint: ctx.usdcMint,
-        tokenProgram: TOKEN_PROGRAM_ID,
-        systemProgram: SystemProgram.programId,
-      })
-      .signers([ctx.creator])
-      .rpc();
+    await settleAndFinalize(ctx, bountyPda2, agent, agentTokenAccount);
 
     const reputationAfter = await ctx.program.account.reputation.fetch(
       reputationPda
@@ -308,11 +299,24 @@ describe("settle_bounty", () => {
       reputationBefore.successfulBounties.toNumber() + 1
     );
     expect(reputationAfter.totalEarned.toNumber()).to.equal(
-      reputationBefore.totalEarned.toNumber() + reward2
+      reputationBefore.totalEarned.toNumber() + reward2 - expectedFee(reward2)
     );
   });
 
-  it("Fails when reputation doesn't belong to agent", async () => {
+  it("Fails when a different agent is named as the winner", async () => {
+    const differentAgent = Keypair.generate();
+    await airdropSol(ctx.connection, differentAgent.publicKey);
+
+    try {
+      await settleBounty(ctx, testBountyPda, differentAgent.publicKey);
+
+      expect.fail("Should have failed - agent did not submit");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails to finalize when reputation doesn't belong to agent", async () => {
     const differentAgent = Keypair.generate();
     await airdropSol(ctx.connection, differentAgent.publicKey);
 
@@ -321,21 +325,27 @@ describe("settle_bounty", () => {
       differentAgent.publicKey
     );
 
+    await settleBounty(ctx, testBountyPda, agent.publicKey);
+    await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+
     try {
       await ctx.program.methods
-        .settleBounty()
+        .finalizeSettlement()
         .accountsPartial({
-          creator: ctx.creator.publicKey,
           bounty: testBountyPda,
           reputation: wrongReputationPda,
           agent: agent.publicKey,
-          agentTokenAccount: agentTokenAccount,
+          stake: deriveStakePda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          treasury: ctx.admin.publicKey,
           bountyTokenAccount: testBountyTokenAccount,
-          usdcMint: ctx.usdcMint,
+          treasuryTokenAccount: ctx.treasuryTokenAccount,
+          rewardMint: ctx.usdcMint,
           tokenProgram: TOKEN_PROGRAM_ID,
-          systemProgram: SystemProgram.programId,
         })
-        .signers([ctx.creator])
         .rpc();
 
       expect.fail("Should have failed - reputation owner mismatch");
@@ -343,5 +353,178 @@ describe("settle_bounty", () => {
       expect(err).to.exist;
     }
   });
-});
 
+  it("Fails to finalize from an escrow holding less than the reward", async () => {
+    // a second, empty token account owned by the vault stands in for a drained escrow
+    const emptyEscrow = await createAccount(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      deriveVaultPda(ctx.program.programId, testBountyId)[0],
+      Keypair.generate()
+    );
+
+    await settleBounty(ctx, testBountyPda, agent.publicKey);
+    await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+
+    try {
+      await ctx.program.methods
+        .finalizeSettlement()
+        .accountsPartial({
+          bounty: testBountyPda,
+          reputation: reputationPda,
+          agent: agent.publicKey,
+          stake: deriveStakePda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          treasury: ctx.admin.publicKey,
+          bountyTokenAccount: emptyEscrow,
+          treasuryTokenAccount: ctx.treasuryTokenAccount,
+          rewardMint: ctx.usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+      expect.fail("Should have failed - escrow short of the reward");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InsufficientEscrow");
+    }
+  });
+
+  describe("claim_reward", () => {
+    const reward = 100 * 10 ** 6;
+
+    beforeEach(async () => {
+      await settleBounty(ctx, testBountyPda, agent.publicKey);
+      await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+      await finalizeSettlement(ctx, testBountyPda, agent.publicKey, agentTokenAccount);
+    });
+
+    it("Holds the payout in escrow until the agent claims it", async () => {
+      const fee = expectedFee(reward);
+
+      const bountyAccount = await ctx.program.account.bounty.fetch(testBountyPda);
+      expect(bountyAccount.status).to.deep.equal({ settled: {} });
+      expect(bountyAccount.claimableAmount.toNumber()).to.equal(reward - fee);
+
+      const escrow = await ctx.connection.getTokenAccountBalance(testBountyTokenAccount);
+      expect(escrow.value.amount).to.equal((reward - fee).toString());
+
+      const agentBalanceBefore = await ctx.connection.getTokenAccountBalance(
+        agentTokenAccount
+      );
+
+      await claimReward(ctx, testBountyPda, agent, agentTokenAccount);
+
+      const agentBalanceAfter = await ctx.connection.getTokenAccountBalance(
+        agentTokenAccount
+      );
+      expect(
+        Number(agentBalanceAfter.value.amount) - Number(agentBalanceBefore.value.amount)
+      ).to.equal(reward - fee);
+
+      const claimed = await ctx.program.account.bounty.fetch(testBountyPda);
+      expect(claimed.claimableAmount.toNumber()).to.equal(0);
+    });
+
+    it("Pays into any token account the agent picks", async () => {
+      const payoutAccount = await createAccount(
+        ctx.connection,
+        agent,
+        ctx.usdcMint,
+        Keypair.generate().publicKey,
+        Keypair.generate()
+      );
+
+      await claimReward(ctx, testBountyPda, agent, payoutAccount);
+
+      const balance = await ctx.connection.getTokenAccountBalance(payoutAccount);
+      expect(Number(balance.value.amount)).to.equal(reward - expectedFee(reward));
+    });
+
+    it("Fails to claim twice", async () => {
+      await claimReward(ctx, testBountyPda, agent, agentTokenAccount);
+
+      try {
+        await claimReward(ctx, testBountyPda, agent, agentTokenAccount);
+
+        expect.fail("Should have failed - reward already claimed");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("NothingToClaim");
+      }
+    });
+
+    it("Fails when someone other than the agent claims", async () => {
+      const impostor = Keypair.generate();
+      await airdropSol(ctx.connection, impostor.publicKey);
+      const impostorTokenAccount = await createAgentTokenAccount(
+        ctx.connection,
+        impostor,
+        ctx.usdcMint
+      );
+
+      try {
+        await claimReward(ctx, testBountyPda, impostor, impostorTokenAccount);
+
+        expect.fail("Should have failed - not the settled agent");
+      } catch (err) {
+        expect(err.error.errorCode.code).to.equal("AgentMismatch");
+      }
+    });
+  });
+
+  describe("raise_dispute", () => {
+    it("Freezes finalization when the agent disputes inside the window", async () => {
+      await settleBounty(ctx, testBountyPda, agent.publicKey);
+
+      await ctx.program.methods
+        .raiseDispute()
+        .accountsPartial({
+          agent: agent.publicKey,
+          bounty: testBountyPda,
+        })
+        .signers([agent])
+        .rpc();
+
+      const bountyAccount = await ctx.program.account.bounty.fetch(testBountyPda);
+      expect(bountyAccount.status).to.deep.equal({ disputed: {} });
+
+      await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+
+      try {
+        await finalizeSettlement(
+          ctx,
+          testBountyPda,
+          agent.publicKey,
+          agentTokenAccount
+        );
+
+        expect.fail("Should have failed - bounty is disputed");
+      } catch (err) {
+        expect(err).to.exist;
+      }
+    });
+
+    it("Fails once the dispute window has closed", async () => {
+      await settleBounty(ctx, testBountyPda, agent.publicKey);
+      await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+
+      try {
+        await ctx.program.methods
+          .raiseDispute()
+          .accountsPartial({
+            agent: agent.publicKey,
+            bounty: testBountyPda,
+          })
+          .signers([agent])
+          .rpc();
+
+        expect.fail("Should have failed - dispute window closed");
+      } catch (err) {
+        expect(err).to.exist;
+      }
+    });
+  });
+});
@@ -0,0 +1,253 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveCreatorProfilePda,
+  deriveReputationPda,
+  deriveSubmissionPda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  updateConfig,
+  expectedFee,
+  TEST_FEE_BPS_FOR_TIER,
+  TestContext,
+} from "./helpers";
+
+describe("settle_multi", () => {
+  let ctx: TestContext;
+  let agentA: Keypair;
+  let agentB: Keypair;
+  let tokenAccountA: anchor.web3.PublicKey;
+  let tokenAccountB: anchor.web3.PublicKey;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const reward = 100 * 10 ** 6;
+
+  function winner(agent: Keypair, agentTokenAccount: anchor.web3.PublicKey) {
+    return [
+      { pubkey: agent.publicKey, isWritable: true, isSigner: false },
+      { pubkey: agentTokenAccount, isWritable: true, isSigner: false },
+      {
+        pubkey: deriveReputationPda(ctx.program.programId, agent.publicKey)[0],
+        isWritable: true,
+        isSigner: false,
+      },
+      {
+        pubkey: deriveSubmissionPda(ctx.program.programId, bountyId, agent.publicKey)[0],
+        isWritable: true,
+        isSigner: false,
+      },
+    ];
+  }
+
+  async function settleMulti(weights: number[], remainingAccounts: anchor.web3.AccountMeta[]) {
+    return ctx.program.methods
+      .settleMulti(weights)
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        creatorProfile: deriveCreatorProfilePda(
+          ctx.program.programId,
+          ctx.creator.publicKey
+        )[0],
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, bountyPda),
+        treasuryTokenAccount: ctx.treasuryTokenAccount,
+        treasury: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([ctx.creator])
+      .rpc();
+  }
+
+  async function submit(agent: Keypair, value: number): Promise<void> {
+    const solutionHash = generateSolutionHashWithValue(value);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      2 * reward
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Research bounty", reward, {
+      competition: true,
+    });
+
+    agentA = Keypair.generate();
+    agentB = Keypair.generate();
+    await airdropSol(ctx.connection, agentA.publicKey);
+    await airdropSol(ctx.connection, agentB.publicKey);
+    tokenAccountA = await createAgentTokenAccount(ctx.connection, agentA, ctx.usdcMint);
+    tokenAccountB = await createAgentTokenAccount(ctx.connection, agentB, ctx.usdcMint);
+
+    await submit(agentA, 0x5a);
+    await submit(agentB, 0x5b);
+  });
+
+  it("Splits the reward between winners by weight", async () => {
+    await settleMulti(
+      [6_000, 4_000],
+      [...winner(agentA, tokenAccountA), ...winner(agentB, tokenAccountB)]
+    );
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ settled: {} });
+
+    const sliceA = Math.floor((reward * 6_000) / 10_000);
+    const sliceB = reward - sliceA;
+    const shareA = sliceA - expectedFee(sliceA);
+    const shareB = sliceB - expectedFee(sliceB);
+    const balanceA = await ctx.connection.getTokenAccountBalance(tokenAccountA);
+    const balanceB = await ctx.connection.getTokenAccountBalance(tokenAccountB);
+    expect(balanceA.value.amount).to.equal(shareA.toString());
+    expect(balanceB.value.amount).to.equal(shareB.toString());
+
+    const reputationB = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agentB.publicKey)[0]
+    );
+    expect(reputationB.successfulBounties.toNumber()).to.equal(1);
+    expect(reputationB.totalEarned.toNumber()).to.equal(shareB);
+  });
+
+  it("Charges each winner the fee for the tier it submitted at", async () => {
+    // Novice, Verified, Expert, Elite
+    const feeBpsForTier = [250, 200, 100, 0];
+    await updateConfig(ctx, { feeBpsForTier });
+
+    try {
+      // agentA already has a reputation from the beforeEach submission
+      await ctx.program.methods
+        .adjustReputation({
+          scoreDelta: new anchor.BN(500),
+          successfulBountiesDelta: new anchor.BN(60),
+          failedBountiesDelta: new anchor.BN(0),
+          reasonHash: Array(32).fill(0x71),
+        })
+        .accountsPartial({
+          admin: ctx.admin.publicKey,
+          config: deriveConfigPda(ctx.program.programId)[0],
+          reputation: deriveReputationPda(ctx.program.programId, agentA.publicKey)[0],
+        })
+        .signers([ctx.admin])
+        .rpc();
+
+      bountyId = await nextBountyId(ctx);
+      bountyPda = await postBounty(ctx, bountyId, "Tiered research bounty", reward, {
+        competition: true,
+      });
+      await submit(agentA, 0x6a);
+      await submit(agentB, 0x6b);
+
+      const submissionA = await ctx.program.account.submission.fetch(
+        deriveSubmissionPda(ctx.program.programId, bountyId, agentA.publicKey)[0]
+      );
+      expect(submissionA.tierAtSubmit).to.deep.equal({ elite: {} });
+
+      const treasuryBefore = await ctx.connection.getTokenAccountBalance(
+        ctx.treasuryTokenAccount
+      );
+      await settleMulti(
+        [5_000, 5_000],
+        [...winner(agentA, tokenAccountA), ...winner(agentB, tokenAccountB)]
+      );
+
+      const slice = reward / 2;
+      const feeB = (slice * feeBpsForTier[0]) / 10_000;
+      const balanceA = await ctx.connection.getTokenAccountBalance(tokenAccountA);
+      const balanceB = await ctx.connection.getTokenAccountBalance(tokenAccountB);
+      expect(balanceA.value.amount).to.equal(slice.toString());
+      expect(balanceB.value.amount).to.equal((slice - feeB).toString());
+
+      const treasuryAfter = await ctx.connection.getTokenAccountBalance(
+        ctx.treasuryTokenAccount
+      );
+      expect(
+        Number(treasuryAfter.value.amount) - Number(treasuryBefore.value.amount)
+      ).to.equal(feeB);
+    } finally {
+      await updateConfig(ctx, { feeBpsForTier: TEST_FEE_BPS_FOR_TIER });
+    }
+  });
+
+  it("Fails when the weights do not sum to 10000 bps", async () => {
+    try {
+      await settleMulti(
+        [6_000, 3_000],
+        [...winner(agentA, tokenAccountA), ...winner(agentB, tokenAccountB)]
+      );
+
+      expect.fail("Should have failed - weights do not sum to 10000");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidWinnerWeights");
+    }
+  });
+
+  it("Fails when the same agent is listed twice", async () => {
+    try {
+      await settleMulti(
+        [5_000, 5_000],
+        [...winner(agentA, tokenAccountA), ...winner(agentA, tokenAccountA)]
+      );
+
+      expect.fail("Should have failed - duplicate winner");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("DuplicateWinner");
+    }
+
+    const balanceA = await ctx.connection.getTokenAccountBalance(tokenAccountA);
+    expect(balanceA.value.amount).to.equal("0");
+  });
+
+  it("Fails for an agent that did not submit", async () => {
+    const outsider = Keypair.generate();
+    await airdropSol(ctx.connection, outsider.publicKey);
+    const outsiderTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      outsider,
+      ctx.usdcMint
+    );
+
+    try {
+      await settleMulti(
+        [5_000, 5_000],
+        [...winner(agentA, tokenAccountA), ...winner(outsider, outsiderTokenAccount)]
+      );
+
+      expect.fail("Should have failed - outsider has no submission");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ open: {} });
+  });
+});
@@ -0,0 +1,230 @@
+import * as anchor from "@coral-xyz/anchor";
+import { freezeAccount, thawAccount, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  deriveStakePda,
+  deriveSubmissionPda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  claimReward,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  expectedFee,
+  TestContext,
+} from "./helpers";
+
+describe("settle_partial", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let agentTokenAccount: anchor.web3.PublicKey;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const reward = 100 * 10 ** 6;
+
+  async function settlePartial(amount: number, signers: Keypair[] = [ctx.creator, agent]) {
+    return ctx.program.methods
+      .settlePartial(new anchor.BN(amount))
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        agent: agent.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        reputation: deriveReputationPda(ctx.program.programId, agent.publicKey)[0],
+        referrerReputation: null,
+        stake: deriveStakePda(ctx.program.programId, bountyId, agent.publicKey)[0],
+        agentTokenAccount,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, bountyPda),
+        treasuryTokenAccount: ctx.treasuryTokenAccount,
+        treasury: null,
+        vesting: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: null,
+      })
+      .signers(signers)
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      2 * reward
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Partially done bounty", reward);
+
+    const solutionHash = generateSolutionHashWithValue(0x9c);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  });
+
+  it("Pays the agreed amount and refunds the rest to the creator", async () => {
+    const amount = 40 * 10 ** 6;
+    const creatorBalanceBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+
+    await settlePartial(amount);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ settled: {} });
+
+    const payout = amount - expectedFee(amount);
+    const agentBalance = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
+    expect(agentBalance.value.amount).to.equal(payout.toString());
+
+    const creatorBalanceAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.creatorTokenAccount
+    );
+    expect(
+      Number(creatorBalanceAfter.value.amount) -
+        Number(creatorBalanceBefore.value.amount)
+    ).to.equal(reward - amount);
+
+    const reputation = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agent.publicKey)[0]
+    );
+    expect(reputation.successfulBounties.toNumber()).to.equal(1);
+    expect(reputation.totalEarned.toNumber()).to.equal(payout);
+  });
+
+  it("Holds the payout for claim_reward when the agent's token account is frozen", async () => {
+    const amount = 40 * 10 ** 6;
+    await freezeAccount(
+      ctx.connection,
+      ctx.creator,
+      agentTokenAccount,
+      ctx.usdcMint,
+      ctx.creator,
+      [],
+      undefined,
+      ctx.tokenProgram
+    );
+
+    await settlePartial(amount);
+
+    const payout = amount - expectedFee(amount);
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ settled: {} });
+    expect(bounty.claimableAmount.toNumber()).to.equal(payout);
+    const escrowBalance = await ctx.connection.getTokenAccountBalance(
+      await fetchEscrowTokenAccount(ctx, bountyPda)
+    );
+    expect(escrowBalance.value.amount).to.equal(payout.toString());
+
+    await thawAccount(
+      ctx.connection,
+      ctx.creator,
+      agentTokenAccount,
+      ctx.usdcMint,
+      ctx.creator,
+      [],
+      undefined,
+      ctx.tokenProgram
+    );
+    await claimReward(ctx, bountyPda, agent, agentTokenAccount);
+
+    const agentBalance = await ctx.connection.getTokenAccountBalance(
+      agentTokenAccount
+    );
+    expect(agentBalance.value.amount).to.equal(payout.toString());
+  });
+
+  it("Fails below the minimum payout the agent set", async () => {
+    // a second submission by the same agent, this time with a floor of half the reward
+    const minPayout = reward / 2;
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Guarded bounty", reward);
+    const solutionHash = generateSolutionHashWithValue(0x9d);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(
+      ctx,
+      agent,
+      bountyPda,
+      attestationPda,
+      solutionHash,
+      [],
+      null,
+      [],
+      minPayout
+    );
+
+    const submission = await ctx.program.account.submission.fetch(
+      deriveSubmissionPda(ctx.program.programId, bountyId, agent.publicKey)[0]
+    );
+    expect(submission.minPayout.toNumber()).to.equal(minPayout);
+
+    try {
+      // exactly the floor before fees nets the agent less than it
+      await settlePartial(minPayout);
+
+      expect.fail("Should have failed - payout below the agent's minimum");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("PayoutBelowMinimum");
+    }
+
+    await settlePartial(reward);
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ settled: {} });
+  });
+
+  it("Fails when the amount exceeds the reward", async () => {
+    try {
+      await settlePartial(reward + 1);
+
+      expect.fail("Should have failed - amount exceeds reward");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AmountExceedsReward");
+    }
+  });
+
+  it("Fails without the agent's signature", async () => {
+    try {
+      await settlePartial(10 * 10 ** 6, [ctx.creator]);
+
+      expect.fail("Should have failed - agent did not sign");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+});
@@ -0,0 +1,92 @@
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  finalizeSettlement,
+  updateConfig,
+  sleep,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TEST_DISPUTE_WINDOW,
+  TEST_ENABLE_MEMOS,
+  TestContext,
+} from "./helpers";
+
+describe("settlement_memo", () => {
+  let ctx: TestContext;
+
+  async function settleAndReadLogs(): Promise<{ bountyId: number; logs: string[] }> {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Memo bounty", 10 * 10 ** 6);
+    const solutionHash = generateSolutionHashWithValue(0x69);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+    await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+
+    const signature = await finalizeSettlement(
+      ctx,
+      bountyPda,
+      agent.publicKey,
+      agentTokenAccount
+    );
+    const tx = await ctx.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+
+    return { bountyId, logs: tx.meta.logMessages };
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      50 * 10 ** 6
+    );
+  });
+
+  it("Tags the settlement payout with the bounty id when memos are enabled", async () => {
+    await updateConfig(ctx, { enableMemos: true });
+    try {
+      const { bountyId, logs } = await settleAndReadLogs();
+
+      expect(logs.some((line) => line.includes(`bf:settle:${bountyId}`))).to.be
+        .true;
+    } finally {
+      await updateConfig(ctx, { enableMemos: TEST_ENABLE_MEMOS });
+    }
+  });
+
+  it("Skips the memo while memos are disabled", async () => {
+    const { logs } = await settleAndReadLogs();
+
+    expect(logs.some((line) => line.includes("bf:settle:"))).to.be.false;
+  });
+});
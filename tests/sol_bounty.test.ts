@@ -0,0 +1,154 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, LAMPORTS_PER_SOL } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  defaultDeadline,
+  expectedFee,
+  sleep,
+  REWARD_SOL,
+  TEST_STAKE_AMOUNT,
+  TestContext,
+} from "./helpers";
+
+describe("SOL bounties", () => {
+  let ctx: TestContext;
+  const reward = LAMPORTS_PER_SOL / 10;
+
+  before(async () => {
+    ctx = await setupTestContext();
+    await airdropSol(ctx.connection, ctx.creator.publicKey);
+  });
+
+  it("Escrows lamports on the bounty PDA", async () => {
+    const bountyPda = await postBounty(
+      ctx,
+      await nextBountyId(ctx),
+      "SOL bounty",
+      reward,
+      { rewardKind: REWARD_SOL }
+    );
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.rewardKind).to.deep.equal({ sol: {} });
+    expect(bountyAccount.rewardDecimals).to.equal(9);
+
+    const bountyInfo = await ctx.connection.getAccountInfo(bountyPda);
+    const rentExempt =
+      await ctx.connection.getMinimumBalanceForRentExemption(
+        bountyInfo!.data.length
+      );
+    expect(bountyInfo!.lamports).to.equal(rentExempt + reward);
+  });
+
+  it("Pays the agent in lamports on settlement", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const solutionHash = generateSolutionHashWithValue(0x50);
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(
+      ctx,
+      bountyId,
+      "SOL bounty to settle",
+      reward,
+      { rewardKind: REWARD_SOL }
+    );
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    const agentBalanceBefore = await ctx.connection.getBalance(agent.publicKey);
+    const treasuryBalanceBefore = await ctx.connection.getBalance(
+      ctx.admin.publicKey
+    );
+
+    await settleAndFinalize(ctx, bountyPda, agent, null);
+
+    const fee = expectedFee(reward);
+    const agentBalanceAfter = await ctx.connection.getBalance(agent.publicKey);
+    // the stake locked at submission comes back with the payout
+    expect(agentBalanceAfter).to.equal(
+      agentBalanceBefore + reward - fee + TEST_STAKE_AMOUNT
+    );
+
+    // the treasury also pays the finalize transaction fee as provider wallet
+    const treasuryBalanceAfter = await ctx.connection.getBalance(
+      ctx.admin.publicKey
+    );
+    expect(treasuryBalanceAfter).to.be.greaterThan(
+      treasuryBalanceBefore + fee - 10_000
+    );
+
+    const bountyInfo = await ctx.connection.getAccountInfo(bountyPda);
+    const rentExempt =
+      await ctx.connection.getMinimumBalanceForRentExemption(
+        bountyInfo!.data.length
+      );
+    expect(bountyInfo!.lamports).to.equal(rentExempt);
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ settled: {} });
+  });
+
+  it("Refunds lamports to the creator once expired", async () => {
+    const bountyPda = await postBounty(
+      ctx,
+      await nextBountyId(ctx),
+      "Short-lived SOL bounty",
+      reward,
+      { rewardKind: REWARD_SOL, deadline: defaultDeadline(2) }
+    );
+
+    await sleep(4000);
+
+    const creatorBalanceBefore = await ctx.connection.getBalance(
+      ctx.creator.publicKey
+    );
+
+    await ctx.program.methods
+      .reclaimExpired()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        creatorTokenAccount: null,
+        bountyTokenAccount: null,
+        rewardMint: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bountyAccount.status).to.deep.equal({ expired: {} });
+
+    // the creator pays the transaction fee out of the refund
+    const creatorBalanceAfter = await ctx.connection.getBalance(
+      ctx.creator.publicKey
+    );
+    expect(creatorBalanceAfter).to.be.greaterThan(
+      creatorBalanceBefore + reward - 10_000
+    );
+
+    const bountyInfo = await ctx.connection.getAccountInfo(bountyPda);
+    const rentExempt =
+      await ctx.connection.getMinimumBalanceForRentExemption(
+        bountyInfo!.data.length
+      );
+    expect(bountyInfo!.lamports).to.equal(rentExempt);
+  });
+});
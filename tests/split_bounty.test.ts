@@ -0,0 +1,259 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, LAMPORTS_PER_SOL, SystemProgram, Transaction } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveEscrowTokenAccount,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  createBountyTokenAccount,
+  submitSolution,
+  deriveBountyPda,
+  deriveHistoryPda,
+  deriveCreatorProfilePda,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  REWARD_SOL,
+  TestContext,
+} from "./helpers";
+
+describe("split_bounty", () => {
+  let ctx: TestContext;
+  const reward = 90 * 10 ** 6;
+
+  before(async () => {
+    ctx = await setupTestContext();
+    await airdropSol(ctx.connection, ctx.creator.publicKey);
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      200 * 10 ** 6
+    );
+  });
+
+  // Children land on the ids the counter hands out next, so their accounts
+  // are derived after the parent is posted.
+  async function splitBounty(
+    bountyPda: anchor.web3.PublicKey,
+    shares: number[],
+    sol = false
+  ): Promise<anchor.web3.PublicKey[]> {
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const firstChildId = await nextBountyId(ctx);
+    const children = shares.map(
+      (_, i) => deriveBountyPda(ctx.program.programId, firstChildId + i)[0]
+    );
+
+    const remainingAccounts = [];
+    for (const [i, child] of children.entries()) {
+      remainingAccounts.push(
+        { pubkey: child, isWritable: true, isSigner: false },
+        {
+          pubkey: deriveHistoryPda(ctx.program.programId, firstChildId + i)[0],
+          isWritable: true,
+          isSigner: false,
+        }
+      );
+      if (!sol) {
+        await createBountyTokenAccount(
+          ctx.connection,
+          ctx.creator,
+          ctx.usdcMint,
+          ctx.program.programId,
+          firstChildId + i,
+          ctx.tokenProgram
+        );
+        remainingAccounts.push({
+          pubkey: deriveEscrowTokenAccount(
+            ctx.program.programId,
+            firstChildId + i,
+            ctx.usdcMint,
+            ctx.tokenProgram
+          ),
+          isWritable: true,
+          isSigner: false,
+        });
+      }
+    }
+
+    await ctx.program.methods
+      .splitBounty(shares.map((share) => new anchor.BN(share)))
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: bountyPda,
+        history: deriveHistoryPda(ctx.program.programId, bounty.id.toNumber())[0],
+        creatorProfile: deriveCreatorProfilePda(
+          ctx.program.programId,
+          ctx.creator.publicKey
+        )[0],
+        bountyTokenAccount: sol
+          ? null
+          : deriveEscrowTokenAccount(
+              ctx.program.programId,
+              bounty.id.toNumber(),
+              ctx.usdcMint,
+              ctx.tokenProgram
+            ),
+        tokenProgram: ctx.tokenProgram,
+        systemProgram: SystemProgram.programId,
+      })
+      .remainingAccounts(remainingAccounts)
+      .signers([ctx.creator])
+      .rpc();
+
+    return children;
+  }
+
+  it("Moves the escrow into child bounties and cancels the parent", async () => {
+    const bountyPda = await postBounty(
+      ctx,
+      await nextBountyId(ctx),
+      "Bounty to split",
+      reward
+    );
+    const firstChildId = await nextBountyId(ctx);
+
+    const children = await splitBounty(bountyPda, [60 * 10 ** 6, 30 * 10 ** 6]);
+
+    const parent = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(parent.status).to.deep.equal({ cancelled: {} });
+    const parentBalance = await ctx.connection.getTokenAccountBalance(
+      await fetchEscrowTokenAccount(ctx, bountyPda)
+    );
+    expect(parentBalance.value.amount).to.equal("0");
+
+    for (const [i, share] of [60 * 10 ** 6, 30 * 10 ** 6].entries()) {
+      const child = await ctx.program.account.bounty.fetch(children[i]);
+      expect(child.id.toNumber()).to.equal(firstChildId + i);
+      expect(child.reward.toNumber()).to.equal(share);
+      expect(child.status).to.deep.equal({ open: {} });
+      expect(child.creator.toString()).to.equal(ctx.creator.publicKey.toString());
+      expect(child.title).to.equal(parent.title);
+
+      const balance = await ctx.connection.getTokenAccountBalance(
+        await fetchEscrowTokenAccount(ctx, children[i])
+      );
+      expect(balance.value.amount).to.equal(share.toString());
+
+      const history = await ctx.program.account.bountyHistory.fetch(
+        deriveHistoryPda(ctx.program.programId, firstChildId + i)[0]
+      );
+      expect(history.entries[0].status).to.deep.equal({ open: {} });
+    }
+    expect(await nextBountyId(ctx)).to.equal(firstChildId + 2);
+  });
+
+  it("Splits a SOL bounty's lamports", async () => {
+    const solReward = LAMPORTS_PER_SOL / 10;
+    const bountyPda = await postBounty(
+      ctx,
+      await nextBountyId(ctx),
+      "SOL bounty to split",
+      solReward,
+      { rewardKind: REWARD_SOL }
+    );
+
+    const shares = [solReward / 2, solReward / 2];
+    const children = await splitBounty(bountyPda, shares, true);
+
+    for (const child of children) {
+      const info = await ctx.connection.getAccountInfo(child);
+      const rentExempt = await ctx.connection.getMinimumBalanceForRentExemption(
+        info!.data.length
+      );
+      expect(info!.lamports).to.equal(rentExempt + solReward / 2);
+    }
+
+    const parentInfo = await ctx.connection.getAccountInfo(bountyPda);
+    const parentRent = await ctx.connection.getMinimumBalanceForRentExemption(
+      parentInfo!.data.length
+    );
+    expect(parentInfo!.lamports).to.equal(parentRent);
+  });
+
+  it("Splits even when someone already sent lamports to a child address", async () => {
+    const bountyPda = await postBounty(
+      ctx,
+      await nextBountyId(ctx),
+      "Bounty with a griefed child",
+      reward
+    );
+    const firstChildId = await nextBountyId(ctx);
+    const [griefed] = deriveBountyPda(ctx.program.programId, firstChildId);
+    const [griefedHistory] = deriveHistoryPda(ctx.program.programId, firstChildId);
+    // a few lamports are enough to make create_account refuse the address
+    await anchor.web3.sendAndConfirmTransaction(
+      ctx.connection,
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: ctx.creator.publicKey,
+          toPubkey: griefed,
+          lamports: 1_000,
+        }),
+        SystemProgram.transfer({
+          fromPubkey: ctx.creator.publicKey,
+          toPubkey: griefedHistory,
+          lamports: 1_000,
+        })
+      ),
+      [ctx.creator]
+    );
+
+    const children = await splitBounty(bountyPda, [60 * 10 ** 6, 30 * 10 ** 6]);
+
+    const child = await ctx.program.account.bounty.fetch(children[0]);
+    expect(child.id.toNumber()).to.equal(firstChildId);
+    expect(child.reward.toNumber()).to.equal(60 * 10 ** 6);
+    const info = await ctx.connection.getAccountInfo(griefed);
+    expect(info!.owner.toString()).to.equal(ctx.program.programId.toString());
+  });
+
+  it("Fails when the shares do not add up to the reward", async () => {
+    const bountyPda = await postBounty(
+      ctx,
+      await nextBountyId(ctx),
+      "Mis-split bounty",
+      reward
+    );
+
+    try {
+      await splitBounty(bountyPda, [60 * 10 ** 6, 20 * 10 ** 6]);
+      expect.fail("Should have failed - shares do not sum to the reward");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SplitSharesMismatch");
+    }
+  });
+
+  it("Fails once a solution was submitted", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Claimed bounty", reward);
+    const solutionHash = generateSolutionHashWithValue(0x86);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    try {
+      await splitBounty(bountyPda, [60 * 10 ** 6, 30 * 10 ** 6]);
+      expect.fail("Should have failed - bounty has a submission");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotOpen");
+    }
+  });
+});
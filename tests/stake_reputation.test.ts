@@ -0,0 +1,168 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  deriveSubmissionPda,
+  airdropSol,
+  ensureCreatorBalance,
+  initializeReputation,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  sleep,
+  TestContext,
+} from "./helpers";
+
+describe("stake_reputation", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+
+  function stakeReputation(staker: Keypair, duration: number) {
+    return ctx.program.methods
+      .stakeReputation(new anchor.BN(duration))
+      .accountsPartial({
+        agent: staker.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        reputation: deriveReputationPda(ctx.program.programId, staker.publicKey)[0],
+      })
+      .signers([staker])
+      .rpc();
+  }
+
+  function unstakeReputation(staker: Keypair) {
+    return ctx.program.methods
+      .unstakeReputation()
+      .accountsPartial({
+        agent: staker.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        reputation: deriveReputationPda(ctx.program.programId, staker.publicKey)[0],
+      })
+      .signers([staker])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    await initializeReputation(ctx, agent);
+  });
+
+  it("Locks the reputation until the end of the duration", async () => {
+    const before = Math.floor(Date.now() / 1000);
+    await stakeReputation(agent, 60 * 60);
+
+    const reputation = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agent.publicKey)[0]
+    );
+    expect(reputation.lockedUntil.toNumber()).to.be.at.least(before + 60 * 60 - 5);
+  });
+
+  it("Fails to shorten a running lock", async () => {
+    await stakeReputation(agent, 60 * 60);
+
+    try {
+      await stakeReputation(agent, 60);
+      expect.fail("Should have failed - lock would end earlier");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidLockDuration");
+    }
+  });
+
+  it("Fails to unstake before the lock ends", async () => {
+    await stakeReputation(agent, 60 * 60);
+
+    try {
+      await unstakeReputation(agent);
+      expect.fail("Should have failed - reputation still locked");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ReputationLocked");
+    }
+  });
+
+  it("Unstakes once the lock has ended", async () => {
+    await stakeReputation(agent, 1);
+    await sleep(2000);
+
+    await unstakeReputation(agent);
+
+    const reputation = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agent.publicKey)[0]
+    );
+    expect(reputation.lockedUntil.toNumber()).to.equal(0);
+  });
+
+  it("Fails to unstake a reputation that was never staked", async () => {
+    try {
+      await unstakeReputation(agent);
+      expect.fail("Should have failed - nothing staked");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("ReputationNotStaked");
+    }
+  });
+
+  it("Breaks auto_settle ties in favour of the staked agent", async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      100 * 10 ** 6
+    );
+    const staker = Keypair.generate();
+    await airdropSol(ctx.connection, staker.publicKey);
+    await initializeReputation(ctx, staker);
+    await stakeReputation(staker, 60 * 60);
+
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Staked tie bounty", 100 * 10 ** 6, {
+      competition: true,
+    });
+    // both agents start from the same score, the unstaked one submits first
+    for (const [submitter, value] of [
+      [agent, 0x94],
+      [staker, 0x95],
+    ] as [Keypair, number][]) {
+      const solutionHash = generateSolutionHashWithValue(value);
+      const attestationPda = await createAttestation(
+        ctx,
+        submitter,
+        bountyId,
+        generateRandomId(),
+        solutionHash
+      );
+      await submitSolution(ctx, submitter, bountyPda, attestationPda, solutionHash);
+    }
+
+    const submissions = [agent, staker].map((submitter) => ({
+      pubkey: deriveSubmissionPda(ctx.program.programId, bountyId, submitter.publicKey)[0],
+      isWritable: false,
+      isSigner: false,
+    }));
+    const staked = await ctx.program.account.submission.fetch(submissions[1].pubkey);
+    expect(staked.stakedAtSubmit).to.equal(true);
+
+    await ctx.program.methods
+      .autoSettle()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+      })
+      .remainingAccounts(submissions)
+      .signers([ctx.creator])
+      .rpc();
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.agent.toString()).to.equal(staker.publicKey.toString());
+  });
+});
@@ -0,0 +1,131 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  deriveStakePda,
+  deriveSubmissionPda,
+  airdropSol,
+  createAgentTokenAccount,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  updateConfig,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TEST_MAX_CONCURRENT_SUBMISSIONS,
+  TestContext,
+} from "./helpers";
+
+describe("submission_cap", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let reputationPda: anchor.web3.PublicKey;
+
+  async function submitToNewBounty(
+    value: number
+  ): Promise<{ bountyId: number; bountyPda: anchor.web3.PublicKey }> {
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Capped bounty", 10 * 10 ** 6);
+    const solutionHash = generateSolutionHashWithValue(value);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+
+    return { bountyId, bountyPda };
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+    await updateConfig(ctx, { maxConcurrentSubmissions: 1 });
+  });
+
+  after(async () => {
+    await updateConfig(ctx, {
+      maxConcurrentSubmissions: TEST_MAX_CONCURRENT_SUBMISSIONS,
+    });
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    [reputationPda] = deriveReputationPda(ctx.program.programId, agent.publicKey);
+  });
+
+  it("Counts each open submission on the agent's reputation", async () => {
+    await submitToNewBounty(0x74);
+
+    const reputation = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputation.activeSubmissions).to.equal(1);
+  });
+
+  it("Fails to submit beyond the cap", async () => {
+    await submitToNewBounty(0x75);
+
+    try {
+      await submitToNewBounty(0x76);
+
+      expect.fail("Should have failed - too many active submissions");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("TooManyActiveSubmissions");
+    }
+  });
+
+  it("Frees the slot once the agent forfeits", async () => {
+    const { bountyId, bountyPda } = await submitToNewBounty(0x77);
+
+    await ctx.program.methods
+      .forfeitSubmission()
+      .accountsPartial({
+        agent: agent.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        reputation: reputationPda,
+        submission: deriveSubmissionPda(ctx.program.programId, bountyId, agent.publicKey)[0],
+        stake: deriveStakePda(ctx.program.programId, bountyId, agent.publicKey)[0],
+      })
+      .signers([agent])
+      .rpc();
+
+    await submitToNewBounty(0x78);
+
+    const reputation = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputation.activeSubmissions).to.equal(1);
+  });
+
+  it("Frees the slot once the submission is settled, and only once", async () => {
+    const { bountyId, bountyPda } = await submitToNewBounty(0x79);
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+
+    await submitToNewBounty(0x7a);
+
+    // closing the won submission leaves the new one counted
+    await ctx.program.methods
+      .closeSubmission()
+      .accountsPartial({
+        agent: agent.publicKey,
+        bounty: bountyPda,
+        submission: deriveSubmissionPda(ctx.program.programId, bountyId, agent.publicKey)[0],
+        stake: deriveStakePda(ctx.program.programId, bountyId, agent.publicKey)[0],
+      })
+      .signers([agent])
+      .rpc();
+
+    const reputation = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputation.activeSubmissions).to.equal(1);
+  });
+});
@@ -0,0 +1,72 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  sleep,
+  updateConfig,
+  TEST_SUBMISSION_COOLDOWN,
+  TestContext,
+} from "./helpers";
+
+describe("submission_cooldown", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  const cooldown = 3;
+  const solutionHash = generateSolutionHashWithValue(0xc0);
+
+  async function submitToNewBounty() {
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Cooldown bounty", 10 * 10 ** 6);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+    await updateConfig(ctx, { submissionCooldown: new anchor.BN(cooldown) });
+  });
+
+  after(async () => {
+    await updateConfig(ctx, {
+      submissionCooldown: new anchor.BN(TEST_SUBMISSION_COOLDOWN),
+    });
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+  });
+
+  it("Rejects a second submission inside the cooldown", async () => {
+    await submitToNewBounty();
+
+    try {
+      await submitToNewBounty();
+
+      expect.fail("Should have failed - cooldown active");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("SubmissionCooldownActive");
+    }
+  });
+
+  it("Accepts a second submission once the cooldown has passed", async () => {
+    await submitToNewBounty();
+    await sleep((cooldown + 1) * 1000);
+
+    await submitToNewBounty();
+  });
+});
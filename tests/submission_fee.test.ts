@@ -0,0 +1,103 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  updateConfig,
+  TEST_STAKE_AMOUNT,
+  TEST_SUBMISSION_FEE,
+  TestContext,
+} from "./helpers";
+
+describe("submission_fee", () => {
+  let ctx: TestContext;
+  let agentA: Keypair;
+  let agentB: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const submissionFee = 5_000_000; // 0.005 SOL
+
+  async function submit(agent: Keypair, value: number): Promise<void> {
+    const solutionHash = generateSolutionHashWithValue(value);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+    await updateConfig(ctx, { submissionFee: new anchor.BN(submissionFee) });
+  });
+
+  after(async () => {
+    await updateConfig(ctx, { submissionFee: new anchor.BN(TEST_SUBMISSION_FEE) });
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      100 * 10 ** 6
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Fee bounty", 100 * 10 ** 6, {
+      competition: true,
+    });
+
+    agentA = Keypair.generate();
+    agentB = Keypair.generate();
+    await airdropSol(ctx.connection, agentA.publicKey);
+    await airdropSol(ctx.connection, agentB.publicKey);
+  });
+
+  it("Collects the fee from every submitting agent into the bounty", async () => {
+    const lamportsBefore = await ctx.connection.getBalance(bountyPda);
+
+    await submit(agentA, 0x53);
+    await submit(agentB, 0x54);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.submissionFees.toNumber()).to.equal(2 * submissionFee);
+    expect(await ctx.connection.getBalance(bountyPda)).to.equal(
+      lamportsBefore + 2 * submissionFee
+    );
+  });
+
+  it("Pays the whole fee pool to the winner on settlement", async () => {
+    await submit(agentA, 0x53);
+    await submit(agentB, 0x54);
+
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agentB,
+      ctx.usdcMint
+    );
+    const balanceBefore = await ctx.connection.getBalance(agentB.publicKey);
+
+    await settleAndFinalize(ctx, bountyPda, agentB, agentTokenAccount);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.submissionFees.toNumber()).to.equal(0);
+    expect(await ctx.connection.getBalance(agentB.publicKey)).to.equal(
+      balanceBefore + TEST_STAKE_AMOUNT + 2 * submissionFee
+    );
+  });
+});
@@ -1,14 +1,23 @@
 import * as anchor from "@coral-xyz/anchor";
-import { Keypair, SystemProgram } from "@solana/web3.js";
+import { Keypair, PublicKey, SystemProgram } from "@solana/web3.js";
 import { expect } from "chai";
 import {
   setupTestContext,
+  deriveSubmissionPda,
+  deriveStakePda,
+  deriveCommitmentPda,
   deriveReputationPda,
   airdropSol,
   postBounty,
   createAttestation,
+  commitSolution,
+  verifyAttestation,
   generateRandomId,
+  nextBountyId,
+  generateSolutionHash,
   generateSolutionHashWithValue,
+  TIER_VERIFIED,
+  TEST_SCORE_ON_SUBMIT,
   TestContext,
 } from "./helpers";
 
@@ -21,6 +30,7 @@ describe("submit_solution", () => {
   let attestationPda: anchor.web3.PublicKey;
   let solutionHash: Buffer;
   let reputationPda: anchor.web3.PublicKey;
+  let nonce: Buffer;
 
   before(async () => {
     ctx = await setupTestContext();
@@ -30,7 +40,7 @@ describe("submit_solution", () => {
     agent = Keypair.generate();
     await airdropSol(ctx.connection, agent.publicKey);
 
-    testBountyId = generateRandomId();
+    testBountyId = await nextBountyId(ctx);
     testBountyPda = await postBounty(
       ctx,
       testBountyId,
@@ -43,6 +53,7 @@ describe("submit_solution", () => {
     attestationPda = await createAttestation(
       ctx,
       agent,
+      testBountyId,
       solutionId,
       solutionHash
     );
@@ -51,15 +62,33 @@ describe("submit_solution", () => {
       ctx.program.programId,
       agent.publicKey
     );
+    nonce = generateSolutionHash();
   });
 
   it("Submits a solution successfully and updates bounty and reputation", async () => {
+    await commitSolution(ctx, agent, testBountyPda, solutionHash, nonce);
+
     await ctx.program.methods
-      .submitSolution(Array.from(solutionHash))
+      .revealSolution(Array.from(solutionHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
       .accountsPartial({
         agent: agent.publicKey,
         bounty: testBountyPda,
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
+        stake: deriveStakePda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
         attestation: attestationPda,
+        commitment: deriveCommitmentPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
         reputation: reputationPda,
         systemProgram: SystemProgram.programId,
       })
@@ -76,26 +105,121 @@ describe("submit_solution", () => {
     expect(reputationAccount.agent.toString()).to.equal(
       agent.publicKey.toString()
     );
-    expect(reputationAccount.score.toNumber()).to.equal(1);
+    expect(reputationAccount.score.toNumber()).to.equal(TEST_SCORE_ON_SUBMIT);
     expect(reputationAccount.successfulBounties.toNumber()).to.equal(0);
     expect(reputationAccount.failedBounties.toNumber()).to.equal(0);
     expect(reputationAccount.totalEarned.toNumber()).to.equal(0);
   });
 
-  it("Increments reputation score for existing reputation", async () => {
+  it("Records the kind and size of the off-chain artifact", async () => {
+    await commitSolution(ctx, agent, testBountyPda, solutionHash, nonce);
+
+    await ctx.program.methods
+      .revealSolution(Array.from(solutionHash), 2, 4096, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
+      .accountsPartial({
+        agent: agent.publicKey,
+        bounty: testBountyPda,
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
+        stake: deriveStakePda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
+        attestation: attestationPda,
+        commitment: deriveCommitmentPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
+        reputation: reputationPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent])
+      .rpc();
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(testBountyPda);
+    expect(bountyAccount.solutionKind).to.equal(2);
+    expect(bountyAccount.solutionSize).to.equal(4096);
+
+    const submission = await ctx.program.account.submission.fetch(
+      deriveSubmissionPda(ctx.program.programId, testBountyId, agent.publicKey)[0]
+    );
+    expect(submission.solutionKind).to.equal(2);
+    expect(submission.solutionSize).to.equal(4096);
+  });
+
+  it("Fails when the solution kind is not a known format", async () => {
+    await commitSolution(ctx, agent, testBountyPda, solutionHash, nonce);
+
+    try {
+      await ctx.program.methods
+        .revealSolution(Array.from(solutionHash), 4, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
+        .accountsPartial({
+          agent: agent.publicKey,
+          bounty: testBountyPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          attestation: attestationPda,
+          commitment: deriveCommitmentPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          reputation: reputationPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+      expect.fail("Should have failed - unknown solution kind");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidSolutionKind");
+    }
+  });
+
+  it("Accumulates submit score for existing reputation", async () => {
+    await commitSolution(ctx, agent, testBountyPda, solutionHash, nonce);
+
     await ctx.program.methods
-      .submitSolution(Array.from(solutionHash))
+      .revealSolution(Array.from(solutionHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
       .accountsPartial({
         agent: agent.publicKey,
         bounty: testBountyPda,
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
+        stake: deriveStakePda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
         attestation: attestationPda,
+        commitment: deriveCommitmentPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
         reputation: reputationPda,
         systemProgram: SystemProgram.programId,
       })
       .signers([agent])
       .rpc();
 
-    const bountyId2 = generateRandomId();
+    const bountyId2 = await nextBountyId(ctx);
     const bountyPda2 = await postBounty(ctx, bountyId2, "Second bounty", 50 * 10 ** 6);
 
     const solutionId2 = generateRandomId();
@@ -103,16 +227,34 @@ describe("submit_solution", () => {
     const attestationPda2 = await createAttestation(
       ctx,
       agent,
+      bountyId2,
       solutionId2,
       solutionHash2
     );
 
+    await commitSolution(ctx, agent, bountyPda2, solutionHash2, nonce);
+
     await ctx.program.methods
-      .submitSolution(Array.from(solutionHash2))
+      .revealSolution(Array.from(solutionHash2), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
       .accountsPartial({
         agent: agent.publicKey,
         bounty: bountyPda2,
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          bountyId2,
+          agent.publicKey
+        )[0],
+        stake: deriveStakePda(
+          ctx.program.programId,
+          bountyId2,
+          agent.publicKey
+        )[0],
         attestation: attestationPda2,
+        commitment: deriveCommitmentPda(
+          ctx.program.programId,
+          bountyId2,
+          agent.publicKey
+        )[0],
         reputation: reputationPda,
         systemProgram: SystemProgram.programId,
       })
@@ -122,16 +264,33 @@ describe("submit_solution", () => {
     const reputationAccount = await ctx.program.account.reputation.fetch(
       reputationPda
     );
-    expect(reputationAccount.score.toNumber()).to.equal(2);
+    expect(reputationAccount.score.toNumber()).to.equal(2 * TEST_SCORE_ON_SUBMIT);
   });
 
   it("Fails when bounty is not in Open status", async () => {
+    await commitSolution(ctx, agent, testBountyPda, solutionHash, nonce);
+
     await ctx.program.methods
-      .submitSolution(Array.from(solutionHash))
+      .revealSolution(Array.from(solutionHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
       .accountsPartial({
         agent: agent.publicKey,
         bounty: testBountyPda,
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
+        stake: deriveStakePda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
         attestation: attestationPda,
+        commitment: deriveCommitmentPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
         reputation: reputationPda,
         systemProgram: SystemProgram.programId,
       })
@@ -143,17 +302,35 @@ describe("submit_solution", () => {
     const attestationPda2 = await createAttestation(
       ctx,
       agent,
+      testBountyId,
       solutionId2,
       solutionHash2
     );
 
     try {
+      await commitSolution(ctx, agent, testBountyPda, solutionHash2, nonce);
+
       await ctx.program.methods
-        .submitSolution(Array.from(solutionHash2))
+        .revealSolution(Array.from(solutionHash2), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
         .accountsPartial({
           agent: agent.publicKey,
           bounty: testBountyPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
           attestation: attestationPda2,
+          commitment: deriveCommitmentPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
           reputation: reputationPda,
           systemProgram: SystemProgram.programId,
         })
@@ -170,12 +347,29 @@ describe("submit_solution", () => {
     const wrongHash = generateSolutionHashWithValue(0xff);
 
     try {
+      await commitSolution(ctx, agent, testBountyPda, wrongHash, nonce);
+
       await ctx.program.methods
-        .submitSolution(Array.from(wrongHash))
+        .revealSolution(Array.from(wrongHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
         .accountsPartial({
           agent: agent.publicKey,
           bounty: testBountyPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
           attestation: attestationPda,
+          commitment: deriveCommitmentPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
           reputation: reputationPda,
           systemProgram: SystemProgram.programId,
         })
@@ -188,6 +382,45 @@ describe("submit_solution", () => {
     }
   });
 
+  it("Fails when the revealed solution hash is all zeros", async () => {
+    const zeroHash = Buffer.alloc(32);
+
+    try {
+      await commitSolution(ctx, agent, testBountyPda, zeroHash, nonce);
+
+      await ctx.program.methods
+        .revealSolution(Array.from(zeroHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
+        .accountsPartial({
+          agent: agent.publicKey,
+          bounty: testBountyPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          attestation: attestationPda,
+          commitment: deriveCommitmentPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          reputation: reputationPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+      expect.fail("Should have failed - zero solution hash");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidSolutionHash");
+    }
+  });
+
   it("Fails when attestation doesn't belong to agent", async () => {
     const differentAgent = Keypair.generate();
     await airdropSol(ctx.connection, differentAgent.publicKey);
@@ -197,17 +430,35 @@ describe("submit_solution", () => {
     const attestationPda2 = await createAttestation(
       ctx,
       differentAgent,
+      testBountyId,
       solutionId2,
       solutionHash2
     );
 
     try {
+      await commitSolution(ctx, agent, testBountyPda, solutionHash2, nonce);
+
       await ctx.program.methods
-        .submitSolution(Array.from(solutionHash2))
+        .revealSolution(Array.from(solutionHash2), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
         .accountsPartial({
           agent: agent.publicKey,
           bounty: testBountyPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
           attestation: attestationPda2,
+          commitment: deriveCommitmentPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
           reputation: reputationPda,
           systemProgram: SystemProgram.programId,
         })
@@ -220,19 +471,289 @@ describe("submit_solution", () => {
     }
   });
 
+  it("Fails when attestation is not verified", async () => {
+    const unverifiedHash = generateSolutionHashWithValue(0x33);
+    const unverifiedPda = await createAttestation(
+      ctx,
+      agent,
+      testBountyId,
+      generateRandomId(),
+      unverifiedHash,
+      false
+    );
+
+    const attestationAccount = await ctx.program.account.attestation.fetch(
+      unverifiedPda
+    );
+    expect(attestationAccount.verified).to.equal(false);
+
+    try {
+      await commitSolution(ctx, agent, testBountyPda, unverifiedHash, nonce);
+
+      await ctx.program.methods
+        .revealSolution(Array.from(unverifiedHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
+        .accountsPartial({
+          agent: agent.publicKey,
+          bounty: testBountyPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          attestation: unverifiedPda,
+          commitment: deriveCommitmentPda(
+            ctx.program.programId,
+            testBountyId,
+            agent.publicKey
+          )[0],
+          reputation: reputationPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+      expect.fail("Should have failed - attestation not verified");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails when the attestation was made for a different bounty", async () => {
+    const otherBountyId = await nextBountyId(ctx);
+    const otherBountyPda = await postBounty(
+      ctx,
+      otherBountyId,
+      "Unrelated bounty",
+      100 * 10 ** 6
+    );
+
+    try {
+      await commitSolution(ctx, agent, otherBountyPda, solutionHash, nonce);
+
+      await ctx.program.methods
+        .revealSolution(Array.from(solutionHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
+        .accountsPartial({
+          agent: agent.publicKey,
+          bounty: otherBountyPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            otherBountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            otherBountyId,
+            agent.publicKey
+          )[0],
+          attestation: attestationPda,
+          commitment: deriveCommitmentPda(
+            ctx.program.programId,
+            otherBountyId,
+            agent.publicKey
+          )[0],
+          reputation: reputationPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+      expect.fail("Should have failed - attestation bound to another bounty");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AttestationBountyMismatch");
+    }
+  });
+
+  it("Fails when the agent is below the bounty's minimum tier", async () => {
+    const gatedBountyId = await nextBountyId(ctx);
+    const gatedBountyPda = await postBounty(
+      ctx,
+      gatedBountyId,
+      "Verified agents only",
+      100 * 10 ** 6,
+      { minTier: TIER_VERIFIED }
+    );
+    const gatedAttestationPda = await createAttestation(
+      ctx,
+      agent,
+      gatedBountyId,
+      generateRandomId(),
+      solutionHash
+    );
+
+    try {
+      await commitSolution(ctx, agent, gatedBountyPda, solutionHash, nonce);
+
+      await ctx.program.methods
+        .revealSolution(Array.from(solutionHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
+        .accountsPartial({
+          agent: agent.publicKey,
+          bounty: gatedBountyPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            gatedBountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            gatedBountyId,
+            agent.publicKey
+          )[0],
+          attestation: gatedAttestationPda,
+          commitment: deriveCommitmentPda(
+            ctx.program.programId,
+            gatedBountyId,
+            agent.publicKey
+          )[0],
+          reputation: reputationPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+      expect.fail("Should have failed - reputation tier too low");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Rejects a new agent when a minimum reputation score is set", async () => {
+    const gatedBountyId = await nextBountyId(ctx);
+    const gatedBountyPda = await postBounty(
+      ctx,
+      gatedBountyId,
+      "Experienced agents only",
+      100 * 10 ** 6,
+      { minReputationScore: 5 }
+    );
+    const gatedAttestationPda = await createAttestation(
+      ctx,
+      agent,
+      gatedBountyId,
+      generateRandomId(),
+      solutionHash
+    );
+
+    try {
+      await commitSolution(ctx, agent, gatedBountyPda, solutionHash, nonce);
+
+      await ctx.program.methods
+        .revealSolution(Array.from(solutionHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
+        .accountsPartial({
+          agent: agent.publicKey,
+          bounty: gatedBountyPda,
+          submission: deriveSubmissionPda(
+            ctx.program.programId,
+            gatedBountyId,
+            agent.publicKey
+          )[0],
+          stake: deriveStakePda(
+            ctx.program.programId,
+            gatedBountyId,
+            agent.publicKey
+          )[0],
+          attestation: gatedAttestationPda,
+          commitment: deriveCommitmentPda(
+            ctx.program.programId,
+            gatedBountyId,
+            agent.publicKey
+          )[0],
+          reputation: reputationPda,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([agent])
+        .rpc();
+
+      expect.fail("Should have failed - insufficient reputation");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+
+    const reputation = await ctx.program.account.reputation.fetch(reputationPda);
+    expect(reputation.score.toNumber()).to.equal(0);
+    expect(reputation.lastSubmissionAt.toNumber()).to.equal(0);
+  });
+
+  it("Succeeds once the attestation is verified", async () => {
+    const pendingHash = generateSolutionHashWithValue(0x44);
+    const pendingPda = await createAttestation(
+      ctx,
+      agent,
+      testBountyId,
+      generateRandomId(),
+      pendingHash,
+      false
+    );
+
+    await verifyAttestation(ctx, pendingPda);
+
+    await commitSolution(ctx, agent, testBountyPda, pendingHash, nonce);
+
+    await ctx.program.methods
+      .revealSolution(Array.from(pendingHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
+      .accountsPartial({
+        agent: agent.publicKey,
+        bounty: testBountyPda,
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
+        stake: deriveStakePda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
+        attestation: pendingPda,
+        commitment: deriveCommitmentPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
+        reputation: reputationPda,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([agent])
+      .rpc();
+
+    const bountyAccount = await ctx.program.account.bounty.fetch(testBountyPda);
+    expect(bountyAccount.status).to.deep.equal({ submitted: {} });
+  });
+
   it("Allows different agents to submit solutions to different bounties", async () => {
     const agent2 = Keypair.generate();
     await airdropSol(ctx.connection, agent2.publicKey);
 
-    const bountyId2 = generateRandomId();
+    const bountyId2 = await nextBountyId(ctx);
     const bountyPda2 = await postBounty(ctx, bountyId2, "Second bounty", 75 * 10 ** 6);
 
+    await commitSolution(ctx, agent, testBountyPda, solutionHash, nonce);
+
     await ctx.program.methods
-      .submitSolution(Array.from(solutionHash))
+      .revealSolution(Array.from(solutionHash), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
       .accountsPartial({
         agent: agent.publicKey,
         bounty: testBountyPda,
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
+        stake: deriveStakePda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
         attestation: attestationPda,
+        commitment: deriveCommitmentPda(
+          ctx.program.programId,
+          testBountyId,
+          agent.publicKey
+        )[0],
         reputation: reputationPda,
         systemProgram: SystemProgram.programId,
       })
@@ -244,6 +765,7 @@ describe("submit_solution", () => {
     const attestationPda2 = await createAttestation(
       ctx,
       agent2,
+      bountyId2,
       solutionId2,
       solutionHash2
     );
@@ -253,12 +775,29 @@ describe("submit_solution", () => {
       agent2.publicKey
     );
 
+    await commitSolution(ctx, agent2, bountyPda2, solutionHash2, nonce);
+
     await ctx.program.methods
-      .submitSolution(Array.from(solutionHash2))
+      .revealSolution(Array.from(solutionHash2), 0, 0, new anchor.BN(0), PublicKey.default, Array.from(nonce), [])
       .accountsPartial({
         agent: agent2.publicKey,
         bounty: bountyPda2,
+        submission: deriveSubmissionPda(
+          ctx.program.programId,
+          bountyId2,
+          agent2.publicKey
+        )[0],
+        stake: deriveStakePda(
+          ctx.program.programId,
+          bountyId2,
+          agent2.publicKey
+        )[0],
         attestation: attestationPda2,
+        commitment: deriveCommitmentPda(
+          ctx.program.programId,
+          bountyId2,
+          agent2.publicKey
+        )[0],
         reputation: reputationPda2,
         systemProgram: SystemProgram.programId,
       })
@@ -276,8 +815,8 @@ describe("submit_solution", () => {
 
     expect(rep1.agent.toString()).to.equal(agent.publicKey.toString());
     expect(rep2.agent.toString()).to.equal(agent2.publicKey.toString());
-    expect(rep1.score.toNumber()).to.equal(1);
-    expect(rep2.score.toNumber()).to.equal(1);
+    expect(rep1.score.toNumber()).to.equal(TEST_SCORE_ON_SUBMIT);
+    expect(rep2.score.toNumber()).to.equal(TEST_SCORE_ON_SUBMIT);
   });
 });
 
@@ -0,0 +1,148 @@
+import * as anchor from "@coral-xyz/anchor";
+import { mintTo, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  finalizeSettlement,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  sleep,
+  TEST_DISPUTE_WINDOW,
+  TestContext,
+} from "./helpers";
+
+describe("sweep_escrow", () => {
+  let ctx: TestContext;
+  let bountyPda: anchor.web3.PublicKey;
+  let bountyTokenAccount: anchor.web3.PublicKey;
+  const dust = 5;
+
+  function sweepEscrow(close: boolean) {
+    return ctx.program.methods
+      .sweepEscrow(close)
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        bountyTokenAccount,
+        treasuryTokenAccount: ctx.treasuryTokenAccount,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      10 * 10 ** 6
+    );
+
+    const bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Dusty bounty", 10 * 10 ** 6);
+    bountyTokenAccount = await fetchEscrowTokenAccount(ctx, bountyPda);
+  });
+
+  it("Sweeps residual tokens to the treasury and closes the escrow", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const solutionHash = generateSolutionHashWithValue(0x52);
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bounty.id.toNumber(),
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+
+    await mintTo(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      bountyTokenAccount,
+      ctx.creator,
+      dust
+    );
+    const treasuryBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.treasuryTokenAccount
+    );
+
+    await sweepEscrow(true);
+
+    const treasuryAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.treasuryTokenAccount
+    );
+    expect(
+      Number(treasuryAfter.value.amount) - Number(treasuryBefore.value.amount)
+    ).to.equal(dust);
+    expect(await ctx.connection.getAccountInfo(bountyTokenAccount)).to.be.null;
+  });
+
+  it("Fails while the agent has yet to claim the reward", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const solutionHash = generateSolutionHashWithValue(0x53);
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bounty.id.toNumber(),
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+    await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+    await finalizeSettlement(ctx, bountyPda, agent.publicKey, agentTokenAccount);
+
+    try {
+      await sweepEscrow(false);
+
+      expect.fail("Should have failed - reward not claimed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("RewardUnclaimed");
+    }
+  });
+
+  it("Fails while the bounty is still open", async () => {
+    try {
+      await sweepEscrow(false);
+
+      expect.fail("Should have failed - bounty not finalized");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyNotFinalized");
+    }
+  });
+});
@@ -0,0 +1,150 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleAndFinalize,
+  updateConfig,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TEST_FEE_BPS_FOR_TIER,
+  TestContext,
+} from "./helpers";
+
+describe("tier_fees", () => {
+  let ctx: TestContext;
+  const reward = 10 * 10 ** 6;
+  // Novice, Verified, Expert, Elite
+  const feeBpsForTier = [250, 200, 100, 0];
+
+  async function seedEliteReputation(agent: anchor.web3.PublicKey) {
+    await ctx.program.methods
+      .importReputation({
+        version: 1,
+        agent,
+        score: new anchor.BN(500),
+        successfulBounties: new anchor.BN(60),
+        failedBounties: new anchor.BN(0),
+        totalEarned: new anchor.BN(0),
+        lastActive: new anchor.BN(Math.floor(Date.now() / 1000)),
+        badgesMinted: 0,
+        referrer: null,
+        referralEarnings: new anchor.BN(0),
+        lastSubmissionAt: new anchor.BN(0),
+      })
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        agent,
+        reputation: deriveReputationPda(ctx.program.programId, agent)[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.admin])
+      .rpc();
+  }
+
+  async function promoteToElite(agent: anchor.web3.PublicKey) {
+    await ctx.program.methods
+      .adjustReputation({
+        scoreDelta: new anchor.BN(500),
+        successfulBountiesDelta: new anchor.BN(60),
+        failedBountiesDelta: new anchor.BN(0),
+        reasonHash: Array(32).fill(0x79),
+      })
+      .accountsPartial({
+        admin: ctx.admin.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        reputation: deriveReputationPda(ctx.program.programId, agent)[0],
+      })
+      .signers([ctx.admin])
+      .rpc();
+  }
+
+  async function settleFor(
+    agent: Keypair,
+    beforeSettle: () => Promise<void> = async () => {}
+  ): Promise<number> {
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+    const bountyId = await nextBountyId(ctx);
+    const bountyPda = await postBounty(ctx, bountyId, "Tiered fee bounty", reward);
+    const solutionHash = generateSolutionHashWithValue(0x71);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    await beforeSettle();
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+
+    const balance = await ctx.connection.getTokenAccountBalance(agentTokenAccount);
+    return Number(balance.value.amount);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+    await updateConfig(ctx, { feeBpsForTier });
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      3 * reward
+    );
+  });
+
+  after(async () => {
+    await updateConfig(ctx, { feeBpsForTier: TEST_FEE_BPS_FOR_TIER });
+  });
+
+  it("Charges a novice agent the novice rate", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const payout = await settleFor(agent);
+    expect(payout).to.equal(reward - (reward * feeBpsForTier[0]) / 10_000);
+  });
+
+  it("Waives the fee for an elite agent", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    await seedEliteReputation(agent.publicKey);
+
+    const payout = await settleFor(agent);
+    expect(payout).to.equal(reward);
+  });
+
+  it("Charges the tier the agent held when submitting", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    const payout = await settleFor(agent, () => promoteToElite(agent.publicKey));
+    expect(payout).to.equal(reward - (reward * feeBpsForTier[0]) / 10_000);
+  });
+
+  it("Fails to set a tier fee above 100%", async () => {
+    try {
+      await updateConfig(ctx, { feeBpsForTier: [250, 200, 100, 10_001] });
+
+      expect.fail("Should have failed - fee too high");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("FeeTooHigh");
+    }
+  });
+});
@@ -0,0 +1,105 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_2022_PROGRAM_ID, TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  finalizeSettlement,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  expectedFee,
+  sleep,
+  TEST_DISPUTE_WINDOW,
+  TestContext,
+} from "./helpers";
+
+describe("token_2022", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let agentTokenAccount: anchor.web3.PublicKey;
+  let bountyPda: anchor.web3.PublicKey;
+  const reward = 100 * 10 ** 6;
+
+  before(async () => {
+    ctx = await setupTestContext(TOKEN_2022_PROGRAM_ID);
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint,
+      TOKEN_2022_PROGRAM_ID
+    );
+
+    const bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Token-2022 bounty", reward);
+
+    const solutionHash = generateSolutionHashWithValue(0x57);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+  });
+
+  it("Escrows a Token-2022 reward on posting", async () => {
+    const escrow = await ctx.connection.getTokenAccountBalance(
+      await fetchEscrowTokenAccount(ctx, bountyPda)
+    );
+    expect(Number(escrow.value.amount)).to.equal(reward);
+  });
+
+  it("Settles a Token-2022 bounty end to end", async () => {
+    const treasuryBefore = await ctx.connection.getTokenAccountBalance(
+      ctx.treasuryTokenAccount
+    );
+
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+
+    const agentBalance = await ctx.connection.getTokenAccountBalance(agentTokenAccount);
+    expect(Number(agentBalance.value.amount)).to.equal(reward - expectedFee(reward));
+
+    const treasuryAfter = await ctx.connection.getTokenAccountBalance(
+      ctx.treasuryTokenAccount
+    );
+    expect(
+      Number(treasuryAfter.value.amount) - Number(treasuryBefore.value.amount)
+    ).to.equal(expectedFee(reward));
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ settled: {} });
+  });
+
+  it("Fails to settle through the classic token program", async () => {
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+    await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+
+    try {
+      await finalizeSettlement(
+        { ...ctx, tokenProgram: TOKEN_PROGRAM_ID },
+        bountyPda,
+        agent.publicKey,
+        agentTokenAccount
+      );
+
+      expect.fail("Should have failed - token program does not own the mint");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+});
@@ -0,0 +1,113 @@
+import * as anchor from "@coral-xyz/anchor";
+import { TOKEN_PROGRAM_ID } from "@solana/spl-token";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleAndFinalize,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("total_escrowed", () => {
+  let ctx: TestContext;
+
+  async function totalEscrowed() {
+    const config = await ctx.program.account.config.fetch(
+      deriveConfigPda(ctx.program.programId)[0]
+    );
+    return config.totalEscrowed.toNumber();
+  }
+
+  async function escrowBalance(bountyPda: anchor.web3.PublicKey) {
+    const balance = await ctx.connection.getTokenAccountBalance(
+      await fetchEscrowTokenAccount(ctx, bountyPda)
+    );
+    return Number(balance.value.amount);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      100 * 10 ** 6
+    );
+  });
+
+  it("Matches the sum of escrows across several bounties", async () => {
+    const before = await totalEscrowed();
+
+    const kept = await postBounty(ctx, await nextBountyId(ctx), "Kept bounty", 10 * 10 ** 6);
+    const toppedUp = await postBounty(ctx, await nextBountyId(ctx), "Topped up bounty", 20 * 10 ** 6);
+    const cancelled = await postBounty(ctx, await nextBountyId(ctx), "Cancelled bounty", 5 * 10 ** 6);
+    const settledId = await nextBountyId(ctx);
+    const settled = await postBounty(ctx, settledId, "Settled bounty", 15 * 10 ** 6);
+
+    expect(await totalEscrowed()).to.equal(before + 50 * 10 ** 6);
+
+    await ctx.program.methods
+      .increaseReward(new anchor.BN(3 * 10 ** 6))
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: toppedUp,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, toppedUp),
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    await ctx.program.methods
+      .cancelBounty()
+      .accountsPartial({
+        creator: ctx.creator.publicKey,
+        bounty: cancelled,
+        creatorTokenAccount: ctx.creatorTokenAccount,
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, cancelled),
+        rewardMint: ctx.usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([ctx.creator])
+      .rpc();
+
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+    const solutionHash = generateSolutionHashWithValue(0x65);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      settledId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, settled, attestationPda, solutionHash);
+    await settleAndFinalize(ctx, settled, agent, agentTokenAccount);
+
+    let escrowed = 0;
+    for (const bountyPda of [kept, toppedUp, cancelled, settled]) {
+      escrowed += await escrowBalance(bountyPda);
+    }
+    expect(escrowed).to.equal(33 * 10 ** 6);
+    expect(await totalEscrowed()).to.equal(before + escrowed);
+  });
+});
@@ -0,0 +1,119 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, SystemProgram } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveCreatorProfilePda,
+  airdropSol,
+  ensureCreatorBalance,
+  postBounty,
+  settleAndFinalize,
+  createAgentTokenAccount,
+  createAttestation,
+  submitSolution,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("transfer_bounty_ownership", () => {
+  let ctx: TestContext;
+  let newCreator: Keypair;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+
+  function transferOwnership(signer: Keypair, to: anchor.web3.PublicKey) {
+    return ctx.program.methods
+      .transferBountyOwnership()
+      .accountsPartial({
+        creator: signer.publicKey,
+        bounty: bountyPda,
+        newCreator: to,
+        newCreatorProfile: deriveCreatorProfilePda(ctx.program.programId, to)[0],
+        systemProgram: SystemProgram.programId,
+      })
+      .signers([signer])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      200 * 10 ** 6
+    );
+
+    newCreator = Keypair.generate();
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Handed over bounty", 100 * 10 ** 6);
+  });
+
+  it("Transfers the bounty to a new creator", async () => {
+    let listener: number | null = null;
+    const event = new Promise<any>((resolve) => {
+      listener = ctx.program.addEventListener("ownershipTransferred", (e) => resolve(e));
+    });
+
+    await transferOwnership(ctx.creator, newCreator.publicKey);
+
+    const emitted = await event;
+    await ctx.program.removeEventListener(listener!);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.creator.toString()).to.equal(newCreator.publicKey.toString());
+    expect(emitted.previousCreator.toString()).to.equal(ctx.creator.publicKey.toString());
+
+    const profile = await ctx.program.account.creatorProfile.fetch(
+      deriveCreatorProfilePda(ctx.program.programId, newCreator.publicKey)[0]
+    );
+    expect(profile.creator.toString()).to.equal(newCreator.publicKey.toString());
+  });
+
+  it("Fails when caller is not the current creator", async () => {
+    const outsider = Keypair.generate();
+    await airdropSol(ctx.connection, outsider.publicKey);
+
+    try {
+      await transferOwnership(outsider, outsider.publicKey);
+
+      expect.fail("Should have failed - unauthorized creator");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("UnauthorizedCreator");
+    }
+  });
+
+  it("Fails once the bounty is settled", async () => {
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const solutionHash = generateSolutionHashWithValue(0x47);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    const agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+    await settleAndFinalize(ctx, bountyPda, agent, agentTokenAccount);
+
+    try {
+      await transferOwnership(ctx.creator, newCreator.publicKey);
+
+      expect.fail("Should have failed - bounty already settled");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("OwnershipTransferLocked");
+    }
+  });
+});
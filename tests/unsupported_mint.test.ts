@@ -0,0 +1,100 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair, SystemProgram, Transaction } from "@solana/web3.js";
+import {
+  ExtensionType,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
+  getMintLen,
+} from "@solana/spl-token";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveBountyPda,
+  deriveCreatorProfilePda,
+  createBountyTokenAccount,
+  deriveEscrowTokenAccount,
+  postBountyArgs,
+  nextBountyId,
+  TestContext,
+} from "./helpers";
+
+describe("unsupported_mint", () => {
+  let ctx: TestContext;
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  async function createTransferFeeMint(): Promise<anchor.web3.PublicKey> {
+    const mint = Keypair.generate();
+    const space = getMintLen([ExtensionType.TransferFeeConfig]);
+    const lamports = await ctx.connection.getMinimumBalanceForRentExemption(space);
+
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: ctx.creator.publicKey,
+        newAccountPubkey: mint.publicKey,
+        space,
+        lamports,
+        programId: TOKEN_2022_PROGRAM_ID,
+      }),
+      createInitializeTransferFeeConfigInstruction(
+        mint.publicKey,
+        ctx.creator.publicKey,
+        ctx.creator.publicKey,
+        100,
+        BigInt(1_000_000),
+        TOKEN_2022_PROGRAM_ID
+      ),
+      createInitializeMintInstruction(
+        mint.publicKey,
+        6,
+        ctx.creator.publicKey,
+        null,
+        TOKEN_2022_PROGRAM_ID
+      )
+    );
+    await anchor.web3.sendAndConfirmTransaction(ctx.connection, tx, [ctx.creator, mint]);
+
+    return mint.publicKey;
+  }
+
+  it("Rejects a Token-2022 mint with a transfer fee", async () => {
+    const feeMint = await createTransferFeeMint();
+    const bountyId = await nextBountyId(ctx);
+    const [bountyPda] = deriveBountyPda(ctx.program.programId, bountyId);
+    await createBountyTokenAccount(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.program.programId,
+      bountyId
+    );
+
+    try {
+      await ctx.program.methods
+        .postBounty(postBountyArgs("Taxed bounty", 10 * 10 ** 6))
+        .accountsPartial({
+          creator: ctx.creator.publicKey,
+          bounty: bountyPda,
+          creatorProfile: deriveCreatorProfilePda(
+            ctx.program.programId,
+            ctx.creator.publicKey
+          )[0],
+          rewardMint: feeMint,
+          creatorTokenAccount: ctx.creatorTokenAccount,
+          bountyTokenAccount: deriveEscrowTokenAccount(ctx.program.programId, bountyId, ctx.usdcMint),
+          tokenProgram: TOKEN_PROGRAM_ID,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([ctx.creator])
+        .rpc();
+
+      expect.fail("Should have failed - mint charges a transfer fee");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("UnsupportedMint");
+    }
+  });
+});
@@ -0,0 +1,151 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  airdropSol,
+  TEST_FEE_BPS,
+  TestContext,
+  nextBountyId,
+} from "./helpers";
+
+describe("update_config", () => {
+  let ctx: TestContext;
+  let configPda: anchor.web3.PublicKey;
+
+  const noChanges = {
+    admin: null,
+    verifier: null,
+    arbiter: null,
+    treasury: null,
+    feeBps: null,
+    feeBpsForTier: null,
+    referralBps: null,
+    disputeWindow: null,
+    reputationHalfLife: null,
+    stakeAmount: null,
+    minReward: null,
+    maxReward: null,
+    revealDelay: null,
+    submissionTimeout: null,
+    gracePeriod: null,
+    submissionCooldown: null,
+    minAttestationAge: null,
+    scoreOnSubmit: null,
+    scoreOnSettle: null,
+    submissionFee: null,
+    rejectionPenalty: null,
+    rejectionPenaltyStep: null,
+    maxRejectionPenalty: null,
+    forfeitPenalty: null,
+    maxRejections: null,
+    enableMemos: null,
+    maxConcurrentSubmissions: null,
+    maxPriceAge: null,
+    maxPriceConfBps: null,
+    promptSettleWindow: null,
+    promptSettleBonusBps: null,
+    paused: null,
+    bountyCounter: null,
+  };
+
+  before(async () => {
+    ctx = await setupTestContext();
+    [configPda] = deriveConfigPda(ctx.program.programId);
+  });
+
+  it("Lets the admin change a single field", async () => {
+    await ctx.program.methods
+      .updateConfig({ ...noChanges, feeBps: 500 })
+      .accountsPartial({ admin: ctx.admin.publicKey, config: configPda })
+      .signers([ctx.admin])
+      .rpc();
+
+    let config = await ctx.program.account.config.fetch(configPda);
+    expect(config.feeBps).to.equal(500);
+    expect(config.admin.toString()).to.equal(ctx.admin.publicKey.toString());
+    expect(config.paused).to.equal(false);
+
+    await ctx.program.methods
+      .updateConfig({ ...noChanges, feeBps: TEST_FEE_BPS })
+      .accountsPartial({ admin: ctx.admin.publicKey, config: configPda })
+      .signers([ctx.admin])
+      .rpc();
+
+    config = await ctx.program.account.config.fetch(configPda);
+    expect(config.feeBps).to.equal(TEST_FEE_BPS);
+  });
+
+  it("Fails when signer is not the admin", async () => {
+    const notAdmin = Keypair.generate();
+    await airdropSol(ctx.connection, notAdmin.publicKey);
+
+    try {
+      await ctx.program.methods
+        .updateConfig({ ...noChanges, paused: true })
+        .accountsPartial({ admin: notAdmin.publicKey, config: configPda })
+        .signers([notAdmin])
+        .rpc();
+
+      expect.fail("Should have failed - unauthorized admin");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("UnauthorizedAdmin");
+    }
+  });
+
+  it("Rejects a fee above 100%", async () => {
+    try {
+      await ctx.program.methods
+        .updateConfig({ ...noChanges, feeBps: 10_001 })
+        .accountsPartial({ admin: ctx.admin.publicKey, config: configPda })
+        .signers([ctx.admin])
+        .rpc();
+
+      expect.fail("Should have failed - fee too high");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("FeeTooHigh");
+    }
+  });
+
+  it("Rejects a minimum reward above the maximum", async () => {
+    try {
+      await ctx.program.methods
+        .updateConfig({
+          ...noChanges,
+          minReward: new anchor.BN(2),
+          maxReward: new anchor.BN(1),
+        })
+        .accountsPartial({ admin: ctx.admin.publicKey, config: configPda })
+        .signers([ctx.admin])
+        .rpc();
+
+      expect.fail("Should have failed - min reward above max");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidRewardBounds");
+    }
+  });
+
+  it("Raises the bounty counter but refuses to rewind it", async () => {
+    const current = await nextBountyId(ctx);
+
+    await ctx.program.methods
+      .updateConfig({ ...noChanges, bountyCounter: new anchor.BN(current + 10) })
+      .accountsPartial({ admin: ctx.admin.publicKey, config: configPda })
+      .signers([ctx.admin])
+      .rpc();
+    expect(await nextBountyId(ctx)).to.equal(current + 10);
+
+    try {
+      await ctx.program.methods
+        .updateConfig({ ...noChanges, bountyCounter: new anchor.BN(current) })
+        .accountsPartial({ admin: ctx.admin.publicKey, config: configPda })
+        .signers([ctx.admin])
+        .rpc();
+
+      expect.fail("Should have failed - counter rewound");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("BountyCounterRewound");
+    }
+  });
+});
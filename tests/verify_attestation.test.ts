@@ -0,0 +1,89 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  airdropSol,
+  createAttestation,
+  verifyAttestation,
+  generateRandomId,
+  generateSolutionHash,
+  TestContext,
+} from "./helpers";
+
+describe("verify_attestation", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let attestationPda: anchor.web3.PublicKey;
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+
+    attestationPda = await createAttestation(
+      ctx,
+      agent,
+      generateRandomId(),
+      generateRandomId(),
+      generateSolutionHash(),
+      false
+    );
+  });
+
+  it("Verifies an attestation and stamps verified_at", async () => {
+    const beforeTimestamp = Math.floor(Date.now() / 1000);
+
+    await verifyAttestation(ctx, attestationPda);
+
+    const attestationAccount = await ctx.program.account.attestation.fetch(
+      attestationPda
+    );
+    expect(attestationAccount.verified).to.be.true;
+    expect(attestationAccount.verifiedAt.toNumber()).to.be.at.least(
+      beforeTimestamp - 5
+    );
+  });
+
+  it("Fails when attestation is already verified", async () => {
+    await verifyAttestation(ctx, attestationPda);
+
+    try {
+      await verifyAttestation(ctx, attestationPda);
+
+      expect.fail("Should have failed - already verified");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+  });
+
+  it("Fails when signer is not the authorized verifier", async () => {
+    const impostor = Keypair.generate();
+    await airdropSol(ctx.connection, impostor.publicKey);
+
+    try {
+      await ctx.program.methods
+        .verifyAttestation()
+        .accountsPartial({
+          verifier: impostor.publicKey,
+          config: deriveConfigPda(ctx.program.programId)[0],
+          attestation: attestationPda,
+        })
+        .signers([impostor])
+        .rpc();
+
+      expect.fail("Should have failed - unauthorized verifier");
+    } catch (err) {
+      expect(err).to.exist;
+    }
+
+    const attestationAccount = await ctx.program.account.attestation.fetch(
+      attestationPda
+    );
+    expect(attestationAccount.verified).to.be.false;
+  });
+});
@@ -0,0 +1,202 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveConfigPda,
+  deriveReputationPda,
+  deriveVestingPda,
+  fetchEscrowTokenAccount,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  finalizeSettlement,
+  claimReward,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  expectedFee,
+  sleep,
+  TEST_DISPUTE_WINDOW,
+  TestContext,
+} from "./helpers";
+
+describe("vesting", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let agentTokenAccount: anchor.web3.PublicKey;
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  const reward = 100 * 10 ** 6;
+  const payout = reward - expectedFee(reward);
+  const vestingSeconds = 20;
+
+  async function claimVested(): Promise<string> {
+    return ctx.program.methods
+      .claimVested()
+      .accountsPartial({
+        agent: agent.publicKey,
+        config: deriveConfigPda(ctx.program.programId)[0],
+        bounty: bountyPda,
+        vesting: deriveVestingPda(ctx.program.programId, bountyId)[0],
+        reputation: deriveReputationPda(ctx.program.programId, agent.publicKey)[0],
+        recipient: null,
+        recipientTokenAccount: agentTokenAccount,
+        bountyTokenAccount: await fetchEscrowTokenAccount(ctx, bountyPda),
+        tokenProgram: ctx.tokenProgram,
+      })
+      .signers([agent])
+      .rpc();
+  }
+
+  async function agentBalance(): Promise<number> {
+    const balance = await ctx.connection.getTokenAccountBalance(agentTokenAccount);
+    return Number(balance.value.amount);
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    agentTokenAccount = await createAgentTokenAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint
+    );
+
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Vesting bounty", reward, {
+      vestingSeconds,
+    });
+
+    const solutionHash = generateSolutionHashWithValue(0x95);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+    await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+    await finalizeSettlement(ctx, bountyPda, agent.publicKey, agentTokenAccount);
+  });
+
+  it("Opens a vesting schedule for the payout at settlement", async () => {
+    const vesting = await ctx.program.account.vesting.fetch(
+      deriveVestingPda(ctx.program.programId, bountyId)[0]
+    );
+    expect(vesting.agent.toString()).to.equal(agent.publicKey.toString());
+    expect(vesting.total.toNumber()).to.equal(payout);
+    expect(vesting.claimed.toNumber()).to.equal(0);
+    expect(vesting.duration.toNumber()).to.equal(vestingSeconds);
+    expect(vesting.completed).to.equal(false);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ settled: {} });
+    expect(bounty.claimableAmount.toNumber()).to.equal(payout);
+  });
+
+  it("Fails to claim the whole reward at once", async () => {
+    try {
+      await claimReward(ctx, bountyPda, agent, agentTokenAccount);
+      expect.fail("Should have failed - reward vests");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("RewardVesting");
+    }
+  });
+
+  it("Releases the payout gradually and completes at the end", async () => {
+    await sleep((vestingSeconds / 4) * 1000);
+    await claimVested();
+
+    const partial = await agentBalance();
+    expect(partial).to.be.greaterThan(0);
+    expect(partial).to.be.lessThan(payout);
+
+    const vesting = await ctx.program.account.vesting.fetch(
+      deriveVestingPda(ctx.program.programId, bountyId)[0]
+    );
+    expect(vesting.claimed.toNumber()).to.equal(partial);
+    expect(vesting.completed).to.equal(false);
+
+    await sleep(vestingSeconds * 1000);
+    await claimVested();
+
+    expect(await agentBalance()).to.equal(payout);
+    const completed = await ctx.program.account.vesting.fetch(
+      deriveVestingPda(ctx.program.programId, bountyId)[0]
+    );
+    expect(completed.claimed.toNumber()).to.equal(payout);
+    expect(completed.completed).to.equal(true);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.claimableAmount.toNumber()).to.equal(0);
+    const escrow = await ctx.connection.getTokenAccountBalance(
+      await fetchEscrowTokenAccount(ctx, bountyPda)
+    );
+    expect(escrow.value.amount).to.equal("0");
+
+    try {
+      await claimVested();
+      expect.fail("Should have failed - schedule completed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("NothingToClaim");
+    }
+  });
+
+  it("Fails to post a vesting bounty paid in milestones", async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+
+    try {
+      await postBounty(ctx, await nextBountyId(ctx), "Vesting milestones", reward, {
+        vestingSeconds,
+        milestones: [reward / 2, reward / 2],
+      });
+      expect.fail("Should have failed - milestones cannot vest");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("VestingNotSupported");
+    }
+  });
+
+  it("Fails to post a bounty vesting over more than four years", async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+
+    try {
+      await postBounty(ctx, await nextBountyId(ctx), "Overlong vesting", reward, {
+        vestingSeconds: 5 * 365 * 24 * 60 * 60,
+      });
+      expect.fail("Should have failed - vesting period too long");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("InvalidVestingPeriod");
+    }
+  });
+});
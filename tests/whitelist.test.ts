@@ -0,0 +1,111 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Keypair } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  airdropSol,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  buildWhitelist,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  TestContext,
+} from "./helpers";
+
+describe("whitelist", () => {
+  let ctx: TestContext;
+  let invited: Keypair[];
+  let bountyId: number;
+  let bountyPda: anchor.web3.PublicKey;
+  let proofs: number[][][];
+  const solutionHash = generateSolutionHashWithValue(0x3e);
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    invited = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+    for (const agent of invited) {
+      await airdropSol(ctx.connection, agent.publicKey);
+    }
+
+    const whitelist = buildWhitelist(invited.map((agent) => agent.publicKey));
+    proofs = whitelist.proofs;
+
+    bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Invite-only bounty", 50 * 10 ** 6, {
+      whitelistRoot: whitelist.root,
+    });
+  });
+
+  async function attest(agent: Keypair) {
+    return createAttestation(ctx, agent, bountyId, generateRandomId(), solutionHash);
+  }
+
+  it("Stores the whitelist root on the bounty", async () => {
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.whitelistRoot).to.deep.equal(
+      buildWhitelist(invited.map((agent) => agent.publicKey)).root
+    );
+  });
+
+  it("Accepts a submission from a whitelisted agent", async () => {
+    const agent = invited[2];
+    const attestationPda = await attest(agent);
+
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash, proofs[2]);
+
+    const bounty = await ctx.program.account.bounty.fetch(bountyPda);
+    expect(bounty.status).to.deep.equal({ submitted: {} });
+    expect(bounty.agent.toString()).to.equal(agent.publicKey.toString());
+  });
+
+  it("Rejects an agent outside the whitelist", async () => {
+    const outsider = Keypair.generate();
+    await airdropSol(ctx.connection, outsider.publicKey);
+    const attestationPda = await attest(outsider);
+
+    try {
+      await submitSolution(ctx, outsider, bountyPda, attestationPda, solutionHash, proofs[0]);
+
+      expect.fail("Should have failed - agent not whitelisted");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AgentNotWhitelisted");
+    }
+  });
+
+  it("Rejects a whitelisted agent presenting another agent's proof", async () => {
+    const agent = invited[0];
+    const attestationPda = await attest(agent);
+
+    try {
+      await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash, proofs[1]);
+
+      expect.fail("Should have failed - proof belongs to another agent");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("AgentNotWhitelisted");
+    }
+  });
+
+  it("Lets anyone submit when the root is zeroed", async () => {
+    const openBountyId = await nextBountyId(ctx);
+    const openBountyPda = await postBounty(ctx, openBountyId, "Open bounty", 50 * 10 ** 6);
+    const agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      openBountyId,
+      generateRandomId(),
+      solutionHash
+    );
+
+    await submitSolution(ctx, agent, openBountyPda, attestationPda, solutionHash);
+
+    const bounty = await ctx.program.account.bounty.fetch(openBountyPda);
+    expect(bounty.status).to.deep.equal({ submitted: {} });
+  });
+});
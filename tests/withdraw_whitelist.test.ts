@@ -0,0 +1,180 @@
+import * as anchor from "@coral-xyz/anchor";
+import { createAccount } from "@solana/spl-token";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import {
+  setupTestContext,
+  deriveReputationPda,
+  airdropSol,
+  createAgentTokenAccount,
+  ensureCreatorBalance,
+  postBounty,
+  createAttestation,
+  submitSolution,
+  settleBounty,
+  finalizeSettlement,
+  claimReward,
+  releaseMilestone,
+  generateRandomId,
+  nextBountyId,
+  generateSolutionHashWithValue,
+  expectedFee,
+  sleep,
+  TEST_DISPUTE_WINDOW,
+  TestContext,
+} from "./helpers";
+
+describe("withdraw_whitelist", () => {
+  let ctx: TestContext;
+  let agent: Keypair;
+  let agentTokenAccount: PublicKey;
+  let bountyPda: PublicKey;
+  const reward = 10 * 10 ** 6;
+  const coldWallet = Keypair.generate().publicKey;
+
+  function addWithdrawalAddress(address: PublicKey) {
+    return ctx.program.methods
+      .addWithdrawalAddress(address)
+      .accountsPartial({
+        agent: agent.publicKey,
+        reputation: deriveReputationPda(ctx.program.programId, agent.publicKey)[0],
+      })
+      .signers([agent])
+      .rpc();
+  }
+
+  function removeWithdrawalAddress(address: PublicKey) {
+    return ctx.program.methods
+      .removeWithdrawalAddress(address)
+      .accountsPartial({
+        agent: agent.publicKey,
+        reputation: deriveReputationPda(ctx.program.programId, agent.publicKey)[0],
+      })
+      .signers([agent])
+      .rpc();
+  }
+
+  before(async () => {
+    ctx = await setupTestContext();
+  });
+
+  beforeEach(async () => {
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+
+    agent = Keypair.generate();
+    await airdropSol(ctx.connection, agent.publicKey);
+    agentTokenAccount = await createAgentTokenAccount(ctx.connection, agent, ctx.usdcMint);
+
+    const bountyId = await nextBountyId(ctx);
+    bountyPda = await postBounty(ctx, bountyId, "Whitelisted payout", reward);
+    const solutionHash = generateSolutionHashWithValue(0x82);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, bountyPda, attestationPda, solutionHash);
+    await settleBounty(ctx, bountyPda, agent.publicKey);
+    await sleep((TEST_DISPUTE_WINDOW + 1) * 1000);
+    await finalizeSettlement(ctx, bountyPda, agent.publicKey, agentTokenAccount);
+  });
+
+  it("Only pays out to whitelisted owners once the list has an entry", async () => {
+    await addWithdrawalAddress(coldWallet);
+
+    try {
+      await claimReward(ctx, bountyPda, agent, agentTokenAccount);
+
+      expect.fail("Should have failed - agent wallet not whitelisted");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("WithdrawalAddressNotAllowed");
+    }
+
+    const coldTokenAccount = await createAccount(
+      ctx.connection,
+      agent,
+      ctx.usdcMint,
+      coldWallet,
+      Keypair.generate()
+    );
+    await claimReward(ctx, bountyPda, agent, coldTokenAccount);
+
+    const balance = await ctx.connection.getTokenAccountBalance(coldTokenAccount);
+    expect(Number(balance.value.amount)).to.equal(reward - expectedFee(reward));
+  });
+
+  it("Refuses to push a milestone payout to a wallet off the whitelist", async () => {
+    await addWithdrawalAddress(coldWallet);
+    await ensureCreatorBalance(
+      ctx.connection,
+      ctx.creator,
+      ctx.usdcMint,
+      ctx.creatorTokenAccount,
+      reward
+    );
+
+    const bountyId = await nextBountyId(ctx);
+    const milestonePda = await postBounty(ctx, bountyId, "Whitelisted milestones", reward, {
+      milestones: [reward / 2, reward / 2],
+    });
+    const solutionHash = generateSolutionHashWithValue(0x83);
+    const attestationPda = await createAttestation(
+      ctx,
+      agent,
+      bountyId,
+      generateRandomId(),
+      solutionHash
+    );
+    await submitSolution(ctx, agent, milestonePda, attestationPda, solutionHash);
+
+    try {
+      await releaseMilestone(ctx, milestonePda, agent.publicKey, agentTokenAccount, 0);
+
+      expect.fail("Should have failed - agent wallet not whitelisted");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("WithdrawalAddressNotAllowed");
+    }
+  });
+
+  it("Lifts the restriction once the last address is removed", async () => {
+    await addWithdrawalAddress(coldWallet);
+    await removeWithdrawalAddress(coldWallet);
+
+    const reputation = await ctx.program.account.reputation.fetch(
+      deriveReputationPda(ctx.program.programId, agent.publicKey)[0]
+    );
+    expect(reputation.withdrawWhitelist).to.be.empty;
+
+    await claimReward(ctx, bountyPda, agent, agentTokenAccount);
+  });
+
+  it("Fails to add the same address twice", async () => {
+    await addWithdrawalAddress(coldWallet);
+
+    try {
+      await addWithdrawalAddress(coldWallet);
+
+      expect.fail("Should have failed - address already listed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("WithdrawalAddressAlreadyListed");
+    }
+  });
+
+  it("Fails to remove an address that is not listed", async () => {
+    try {
+      await removeWithdrawalAddress(coldWallet);
+
+      expect.fail("Should have failed - address not listed");
+    } catch (err) {
+      expect(err.error.errorCode.code).to.equal("WithdrawalAddressNotListed");
+    }
+  });
+});